@@ -0,0 +1,176 @@
+//! Per character annotation of a text, meant to drive text editors.
+
+use super::chunks::*;
+use super::export::push_json_str;
+use super::kind::*;
+use super::util::hiragana_to_katakana;
+use super::validate::*;
+
+/// Fields reported by [annotations_to_json], in the order of their bits.
+const FIELDS: [KanaField; 4] = [
+	KanaField::ModernHiragana,
+	KanaField::KatakanaWithLongMark,
+	KanaField::SmallKana,
+	KanaField::HistoricKana,
+];
+
+/// Annotation for a single character of the text, returned by [annotate].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CharAnnotation {
+	/// The annotated character.
+	pub chr: char,
+	/// Byte offset of the character in the text.
+	pub offset: usize,
+	/// Kind of the character.
+	pub kind: CharKind,
+	/// Suggested conversion for the character and the ones following it.
+	///
+	/// Romaji is converted to hiragana, hiragana to katakana and katakana to
+	/// hiragana. Romaji syllables span multiple characters (e.g. `sha`), in
+	/// which case only the first character has the conversion.
+	///
+	/// This is `None` if there is no conversion or if the character is part
+	/// of a previous conversion.
+	pub conversion: Option<String>,
+}
+
+impl CharAnnotation {
+	/// Returns true if the character is valid for the given field.
+	pub fn is_valid_for(&self, field: KanaField) -> bool {
+		field.accepts(self.chr)
+	}
+}
+
+/// Returns the annotation for each character in the text.
+///
+/// ```
+/// let annotations = kana::annotate("shaカ");
+/// let conversions = annotations
+///     .iter()
+///     .map(|x| x.conversion.as_deref())
+///     .collect::<Vec<_>>();
+/// assert_eq!(conversions, vec![Some("しゃ"), None, None, Some("か")]);
+/// ```
+pub fn annotate<S: AsRef<str>>(text: S) -> Vec<CharAnnotation> {
+	let text = text.as_ref();
+	let mut out = Vec::with_capacity(text.len());
+	for chunk in HiraganaChunks::new(text) {
+		let src = &text[chunk.src.clone()];
+		for (index, (offset, chr)) in src.char_indices().enumerate() {
+			let kind = get_kind(chr);
+			let conversion = if index > 0 {
+				None
+			} else if kind == CharKind::Hiragana {
+				Some(chunk.text.chars().map(hiragana_to_katakana).collect())
+			} else if chunk.text != src {
+				Some(chunk.text.to_string())
+			} else {
+				None
+			};
+			out.push(CharAnnotation {
+				chr,
+				offset: chunk.src.start + offset,
+				kind,
+				conversion,
+			});
+		}
+	}
+	out
+}
+
+/// Returns the annotations as a JSON array, with offsets in UTF-16 code units
+/// for use in JavaScript:
+///
+/// ```text
+/// [{"char":"か","offset":0,"kind":"Hiragana","conversion":"カ","valid":5},...]
+/// ```
+///
+/// The `conversion` is `null` when there is none, and `valid` has one bit set
+/// for each [KanaField] that accepts the character, in declaration order.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+pub(crate) fn annotations_to_json(text: &str, annotations: &[CharAnnotation]) -> String {
+	let mut out = String::from("[");
+	let mut offset = 0;
+	let mut last = 0;
+	for (index, it) in annotations.iter().enumerate() {
+		offset += text[last..it.offset].encode_utf16().count();
+		last = it.offset;
+		if index > 0 {
+			out.push(',');
+		}
+		out.push_str("{\"char\":");
+		push_json_str(&mut out, it.chr.encode_utf8(&mut [0; 4]));
+		out.push_str(&format!(
+			",\"offset\":{},\"kind\":\"{:?}\",\"conversion\":",
+			offset, it.kind
+		));
+		match it.conversion {
+			Some(ref conversion) => push_json_str(&mut out, conversion),
+			None => out.push_str("null"),
+		}
+		let valid = FIELDS
+			.iter()
+			.enumerate()
+			.filter(|(_, field)| it.is_valid_for(**field))
+			.fold(0, |acc, (bit, _)| acc | (1 << bit));
+		out.push_str(&format!(",\"valid\":{}}}", valid));
+	}
+	out.push(']');
+	out
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_annotate() {
+		assert!(annotate("").is_empty());
+
+		let annotations = annotate("kyぁ漢");
+		assert_eq!(
+			annotations,
+			vec![
+				CharAnnotation {
+					chr: 'k',
+					offset: 0,
+					kind: CharKind::Romaji,
+					conversion: None,
+				},
+				CharAnnotation {
+					chr: 'y',
+					offset: 1,
+					kind: CharKind::Romaji,
+					conversion: None,
+				},
+				CharAnnotation {
+					chr: 'ぁ',
+					offset: 2,
+					kind: CharKind::Hiragana,
+					conversion: Some("ァ".to_string()),
+				},
+				CharAnnotation {
+					chr: '漢',
+					offset: 5,
+					kind: CharKind::Kanji,
+					conversion: None,
+				},
+			]
+		);
+		assert!(annotations[2].is_valid_for(KanaField::SmallKana));
+		assert!(!annotations[3].is_valid_for(KanaField::SmallKana));
+
+		let text = "𠀀ka\"";
+		assert_eq!(
+			annotations_to_json(text, &annotate(text)),
+			concat!(
+				r#"[{"char":"𠀀","offset":0,"kind":"Kanji","conversion":null,"valid":0},"#,
+				r#"{"char":"k","offset":2,"kind":"Romaji","conversion":"か","valid":0},"#,
+				r#"{"char":"a","offset":3,"kind":"Romaji","conversion":null,"valid":0},"#,
+				r#"{"char":"\"","offset":4,"kind":"PunctuationASCII","conversion":null,"valid":0}]"#,
+			)
+		);
+	}
+}
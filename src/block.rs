@@ -0,0 +1,181 @@
+//! Unicode blocks relevant to Japanese text.
+
+/// Unicode block of a character, returned by [get_block].
+///
+/// Only the blocks relevant to Japanese text are listed, with any other
+/// character being `Other`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnicodeBlock {
+	/// Basic Latin (U+0000 to U+007F), which is ASCII.
+	BasicLatin,
+	/// Latin-1 Supplement (U+0080 to U+00FF).
+	Latin1Supplement,
+	/// Latin Extended-A (U+0100 to U+017F), which has the vowels with a
+	/// macron used in romaji (e.g. `ō`).
+	LatinExtendedA,
+	/// CJK Radicals Supplement (U+2E80 to U+2EFF).
+	CJKRadicalsSupplement,
+	/// Kangxi Radicals (U+2F00 to U+2FDF).
+	KangxiRadicals,
+	/// CJK Symbols and Punctuation (U+3000 to U+303F).
+	CJKSymbolsAndPunctuation,
+	/// Hiragana (U+3040 to U+309F).
+	Hiragana,
+	/// Katakana (U+30A0 to U+30FF).
+	Katakana,
+	/// Kanbun (U+3190 to U+319F).
+	Kanbun,
+	/// CJK Strokes (U+31C0 to U+31EF).
+	CJKStrokes,
+	/// Katakana Phonetic Extensions (U+31F0 to U+31FF), the small katakana
+	/// used for Ainu (e.g. `ㇰ`).
+	KatakanaPhoneticExtensions,
+	/// Enclosed CJK Letters and Months (U+3200 to U+32FF).
+	EnclosedCJKLettersAndMonths,
+	/// CJK Compatibility (U+3300 to U+33FF), which has the squared units.
+	CJKCompatibility,
+	/// CJK Unified Ideographs Extension A (U+3400 to U+4DBF).
+	CJKUnifiedIdeographsExtensionA,
+	/// CJK Unified Ideographs (U+4E00 to U+9FFF).
+	CJKUnifiedIdeographs,
+	/// CJK Compatibility Ideographs (U+F900 to U+FAFF).
+	CJKCompatibilityIdeographs,
+	/// Vertical Forms (U+FE10 to U+FE1F).
+	VerticalForms,
+	/// CJK Compatibility Forms (U+FE30 to U+FE4F).
+	CJKCompatibilityForms,
+	/// Halfwidth and Fullwidth Forms (U+FF00 to U+FFEF), which has the
+	/// halfwidth katakana and the full-width ASCII.
+	HalfwidthAndFullwidthForms,
+	/// Kana Extended-B (U+1AFF0 to U+1AFFF).
+	KanaExtendedB,
+	/// Kana Supplement (U+1B000 to U+1B0FF), mostly hentaigana.
+	KanaSupplement,
+	/// Kana Extended-A (U+1B100 to U+1B12F).
+	KanaExtendedA,
+	/// Small Kana Extension (U+1B130 to U+1B16F).
+	SmallKanaExtension,
+	/// CJK Unified Ideographs Extension B (U+20000 to U+2A6DF).
+	CJKUnifiedIdeographsExtensionB,
+	/// CJK Unified Ideographs Extension C (U+2A700 to U+2B73F).
+	CJKUnifiedIdeographsExtensionC,
+	/// CJK Unified Ideographs Extension D (U+2B740 to U+2B81F).
+	CJKUnifiedIdeographsExtensionD,
+	/// CJK Unified Ideographs Extension E (U+2B820 to U+2CEAF).
+	CJKUnifiedIdeographsExtensionE,
+	/// CJK Unified Ideographs Extension F (U+2CEB0 to U+2EBEF).
+	CJKUnifiedIdeographsExtensionF,
+	/// CJK Unified Ideographs Extension I (U+2EBF0 to U+2EE5F).
+	CJKUnifiedIdeographsExtensionI,
+	/// CJK Compatibility Ideographs Supplement (U+2F800 to U+2FA1F).
+	CJKCompatibilityIdeographsSupplement,
+	/// CJK Unified Ideographs Extension G (U+30000 to U+3134F).
+	CJKUnifiedIdeographsExtensionG,
+	/// CJK Unified Ideographs Extension H (U+31350 to U+323AF).
+	CJKUnifiedIdeographsExtensionH,
+	/// Any block not listed above.
+	Other,
+}
+
+/// Returns the Unicode block of the character.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(get_block('か'), UnicodeBlock::Hiragana);
+/// assert_eq!(get_block('ｶ'), UnicodeBlock::HalfwidthAndFullwidthForms);
+/// assert_eq!(get_block('漢'), UnicodeBlock::CJKUnifiedIdeographs);
+/// assert_eq!(get_block('한'), UnicodeBlock::Other);
+/// ```
+pub fn get_block(chr: char) -> UnicodeBlock {
+	match chr {
+		'\u{0000}'..='\u{007F}' => UnicodeBlock::BasicLatin,
+		'\u{0080}'..='\u{00FF}' => UnicodeBlock::Latin1Supplement,
+		'\u{0100}'..='\u{017F}' => UnicodeBlock::LatinExtendedA,
+		'\u{2E80}'..='\u{2EFF}' => UnicodeBlock::CJKRadicalsSupplement,
+		'\u{2F00}'..='\u{2FDF}' => UnicodeBlock::KangxiRadicals,
+		'\u{3000}'..='\u{303F}' => UnicodeBlock::CJKSymbolsAndPunctuation,
+		'\u{3040}'..='\u{309F}' => UnicodeBlock::Hiragana,
+		'\u{30A0}'..='\u{30FF}' => UnicodeBlock::Katakana,
+		'\u{3190}'..='\u{319F}' => UnicodeBlock::Kanbun,
+		'\u{31C0}'..='\u{31EF}' => UnicodeBlock::CJKStrokes,
+		'\u{31F0}'..='\u{31FF}' => UnicodeBlock::KatakanaPhoneticExtensions,
+		'\u{3200}'..='\u{32FF}' => UnicodeBlock::EnclosedCJKLettersAndMonths,
+		'\u{3300}'..='\u{33FF}' => UnicodeBlock::CJKCompatibility,
+		'\u{3400}'..='\u{4DBF}' => UnicodeBlock::CJKUnifiedIdeographsExtensionA,
+		'\u{4E00}'..='\u{9FFF}' => UnicodeBlock::CJKUnifiedIdeographs,
+		'\u{F900}'..='\u{FAFF}' => UnicodeBlock::CJKCompatibilityIdeographs,
+		'\u{FE10}'..='\u{FE1F}' => UnicodeBlock::VerticalForms,
+		'\u{FE30}'..='\u{FE4F}' => UnicodeBlock::CJKCompatibilityForms,
+		'\u{FF00}'..='\u{FFEF}' => UnicodeBlock::HalfwidthAndFullwidthForms,
+		'\u{1AFF0}'..='\u{1AFFF}' => UnicodeBlock::KanaExtendedB,
+		'\u{1B000}'..='\u{1B0FF}' => UnicodeBlock::KanaSupplement,
+		'\u{1B100}'..='\u{1B12F}' => UnicodeBlock::KanaExtendedA,
+		'\u{1B130}'..='\u{1B16F}' => UnicodeBlock::SmallKanaExtension,
+		'\u{20000}'..='\u{2A6DF}' => UnicodeBlock::CJKUnifiedIdeographsExtensionB,
+		'\u{2A700}'..='\u{2B73F}' => UnicodeBlock::CJKUnifiedIdeographsExtensionC,
+		'\u{2B740}'..='\u{2B81F}' => UnicodeBlock::CJKUnifiedIdeographsExtensionD,
+		'\u{2B820}'..='\u{2CEAF}' => UnicodeBlock::CJKUnifiedIdeographsExtensionE,
+		'\u{2CEB0}'..='\u{2EBEF}' => UnicodeBlock::CJKUnifiedIdeographsExtensionF,
+		'\u{2EBF0}'..='\u{2EE5F}' => UnicodeBlock::CJKUnifiedIdeographsExtensionI,
+		'\u{2F800}'..='\u{2FA1F}' => UnicodeBlock::CJKCompatibilityIdeographsSupplement,
+		'\u{30000}'..='\u{3134F}' => UnicodeBlock::CJKUnifiedIdeographsExtensionG,
+		'\u{31350}'..='\u{323AF}' => UnicodeBlock::CJKUnifiedIdeographsExtensionH,
+		_ => UnicodeBlock::Other,
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_get_block() {
+		let check = |input: &str, block: UnicodeBlock| {
+			for chr in input.chars() {
+				assert_eq!(get_block(chr), block, "{} (U+{:04X})", chr, chr as u32);
+			}
+		};
+		check("az09 ~\u{0}\u{7F}", UnicodeBlock::BasicLatin);
+		check("°´\u{A0}ÿ", UnicodeBlock::Latin1Supplement);
+		check("āīūēōĀŌ", UnicodeBlock::LatinExtendedA);
+		check("⺀⻳", UnicodeBlock::CJKRadicalsSupplement);
+		check("⼀⿕", UnicodeBlock::KangxiRadicals);
+		check(
+			"　、。々〆〇「」〜〻",
+			UnicodeBlock::CJKSymbolsAndPunctuation,
+		);
+		check("ぁかんゔゝゞゟ\u{3099}゛", UnicodeBlock::Hiragana);
+		check("゠ァカンヴヶ・ーヽヾヿ", UnicodeBlock::Katakana);
+		check("㆐㆟", UnicodeBlock::Kanbun);
+		check("㇀㇣", UnicodeBlock::CJKStrokes);
+		check("ㇰㇿ", UnicodeBlock::KatakanaPhoneticExtensions);
+		check("㈱㉑㊤㋐", UnicodeBlock::EnclosedCJKLettersAndMonths);
+		check("㌔㍻㎏", UnicodeBlock::CJKCompatibility);
+		check("㐀䶵", UnicodeBlock::CJKUnifiedIdeographsExtensionA);
+		check("一漢字龯\u{9FFF}", UnicodeBlock::CJKUnifiedIdeographs);
+		check("\u{F900}\u{FAFF}", UnicodeBlock::CJKCompatibilityIdeographs);
+		check("︐︙", UnicodeBlock::VerticalForms);
+		check("︵﹏", UnicodeBlock::CJKCompatibilityForms);
+		check("ＡａＺ！｡ｶﾞｰ￥", UnicodeBlock::HalfwidthAndFullwidthForms);
+		check("\u{1AFF0}", UnicodeBlock::KanaExtendedB);
+		check("𛀀𛀁", UnicodeBlock::KanaSupplement);
+		check("\u{1B100}", UnicodeBlock::KanaExtendedA);
+		check("\u{1B150}\u{1B164}", UnicodeBlock::SmallKanaExtension);
+		check("𠀀𪛖", UnicodeBlock::CJKUnifiedIdeographsExtensionB);
+		check("𪜀", UnicodeBlock::CJKUnifiedIdeographsExtensionC);
+		check("𫝀", UnicodeBlock::CJKUnifiedIdeographsExtensionD);
+		check("\u{2B820}", UnicodeBlock::CJKUnifiedIdeographsExtensionE);
+		check("\u{2CEB0}", UnicodeBlock::CJKUnifiedIdeographsExtensionF);
+		check("\u{2EBF0}", UnicodeBlock::CJKUnifiedIdeographsExtensionI);
+		check("\u{30000}", UnicodeBlock::CJKUnifiedIdeographsExtensionG);
+		check("\u{31350}", UnicodeBlock::CJKUnifiedIdeographsExtensionH);
+		check(
+			"\u{2F800}",
+			UnicodeBlock::CJKCompatibilityIdeographsSupplement,
+		);
+		check("한ㄅ😀╯\u{FFFF}", UnicodeBlock::Other);
+	}
+}
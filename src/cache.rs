@@ -0,0 +1,242 @@
+//! Memoization cache for the conversion functions.
+//!
+//! Only available with the `cache` feature.
+
+use std::collections::BTreeMap;
+
+use fnv::FnvHashMap;
+
+use super::options::*;
+use super::to::*;
+
+/// Key for a cached conversion.
+type CacheKey = (ConversionTarget, ConversionOptions, String);
+
+/// Cached conversion result and the tick of its last use.
+struct CacheEntry {
+	result: Result<String, ConversionError>,
+	tick: u64,
+}
+
+/// Statistics for a [ConversionCache], useful to size the cache for a given
+/// workload.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+	/// Number of conversions returned from the cache.
+	pub hits: u64,
+	/// Number of conversions that were not in the cache.
+	pub misses: u64,
+	/// Number of entries evicted from the cache to make space for new ones.
+	pub evictions: u64,
+	/// Current number of entries in the cache.
+	pub len: usize,
+	/// Maximum number of entries in the cache.
+	pub capacity: usize,
+}
+
+impl CacheStats {
+	/// Ratio of conversions returned from the cache, from `0.0` to `1.0`.
+	pub fn hit_ratio(&self) -> f64 {
+		let total = self.hits + self.misses;
+		if total == 0 {
+			0.0
+		} else {
+			self.hits as f64 / total as f64
+		}
+	}
+}
+
+/// Least recently used (LRU) cache for the conversion functions.
+///
+/// This is useful for workloads that convert the same short strings
+/// repeatedly, such as chat messages and subtitles. Conversions are cached
+/// by input, target and [ConversionOptions].
+///
+/// ```
+/// use kana::*;
+///
+/// let mut cache = ConversionCache::new(100);
+/// assert_eq!(cache.to_romaji("ひらがな"), "hiragana");
+/// assert_eq!(cache.to_romaji("ひらがな"), "hiragana");
+///
+/// let stats = cache.stats();
+/// assert_eq!((stats.hits, stats.misses), (1, 1));
+/// ```
+pub struct ConversionCache {
+	capacity: usize,
+	entries: FnvHashMap<CacheKey, CacheEntry>,
+	by_tick: BTreeMap<u64, CacheKey>,
+	tick: u64,
+	stats: CacheStats,
+}
+
+impl ConversionCache {
+	/// Creates a new cache holding at most `capacity` conversions.
+	///
+	/// A cache with zero capacity does not cache anything.
+	pub fn new(capacity: usize) -> ConversionCache {
+		ConversionCache {
+			capacity,
+			entries: FnvHashMap::default(),
+			by_tick: BTreeMap::new(),
+			tick: 0,
+			stats: CacheStats {
+				capacity,
+				..Default::default()
+			},
+		}
+	}
+
+	/// Same as [to_hiragana] but using the cache.
+	pub fn to_hiragana(&mut self, input: &str) -> String {
+		self.convert(input, ConversionTarget::Hiragana, &infallible_options())
+			.unwrap_or_else(|_| unreachable!())
+	}
+
+	/// Same as [to_katakana] but using the cache.
+	pub fn to_katakana(&mut self, input: &str) -> String {
+		self.convert(input, ConversionTarget::Katakana, &infallible_options())
+			.unwrap_or_else(|_| unreachable!())
+	}
+
+	/// Same as [to_romaji] but using the cache.
+	pub fn to_romaji(&mut self, input: &str) -> String {
+		self.convert(input, ConversionTarget::Romaji, &infallible_options())
+			.unwrap_or_else(|_| unreachable!())
+	}
+
+	/// Converts the input to the given target using the options, returning
+	/// the cached result if available.
+	///
+	/// Errors are also cached.
+	pub fn convert(
+		&mut self,
+		input: &str,
+		target: ConversionTarget,
+		options: &ConversionOptions,
+	) -> Result<String, ConversionError> {
+		let key = (target, *options, input.to_string());
+		self.tick += 1;
+		if let Some(entry) = self.entries.get_mut(&key) {
+			self.stats.hits += 1;
+			let key = self.by_tick.remove(&entry.tick).unwrap();
+			self.by_tick.insert(self.tick, key);
+			entry.tick = self.tick;
+			return entry.result.clone();
+		}
+
+		self.stats.misses += 1;
+		let result = match target {
+			ConversionTarget::Hiragana => to_hiragana_with(input, options),
+			ConversionTarget::Katakana => to_katakana_with(input, options),
+			ConversionTarget::Romaji => to_romaji_with(input, options),
+		};
+		if self.capacity == 0 {
+			return result;
+		}
+
+		if self.entries.len() >= self.capacity {
+			let oldest = *self.by_tick.keys().next().unwrap();
+			let key = self.by_tick.remove(&oldest).unwrap();
+			self.entries.remove(&key);
+			self.stats.evictions += 1;
+		}
+
+		let entry = CacheEntry {
+			result: result.clone(),
+			tick: self.tick,
+		};
+		self.by_tick.insert(self.tick, key.clone());
+		self.entries.insert(key, entry);
+		result
+	}
+
+	/// Returns the statistics for the cache.
+	pub fn stats(&self) -> CacheStats {
+		CacheStats {
+			len: self.entries.len(),
+			..self.stats
+		}
+	}
+
+	/// Removes all entries from the cache and resets the statistics.
+	pub fn clear(&mut self) {
+		self.entries.clear();
+		self.by_tick.clear();
+		self.stats = CacheStats {
+			capacity: self.capacity,
+			..Default::default()
+		};
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_conversion_cache() {
+		let mut cache = ConversionCache::new(2);
+		assert_eq!(cache.to_hiragana("kana"), "かな");
+		assert_eq!(cache.to_katakana("kana"), "カナ");
+		assert_eq!(cache.to_hiragana("kana"), "かな");
+		assert_eq!(
+			cache.stats(),
+			CacheStats {
+				hits: 1,
+				misses: 2,
+				evictions: 0,
+				len: 2,
+				capacity: 2,
+			}
+		);
+
+		// evicts the katakana entry, which is the least recently used
+		assert_eq!(cache.to_romaji("かな"), "kana");
+		assert_eq!(cache.to_hiragana("kana"), "かな");
+		assert_eq!(cache.to_katakana("kana"), "カナ");
+		let stats = cache.stats();
+		assert_eq!((stats.hits, stats.misses, stats.evictions), (2, 4, 2));
+		assert_eq!(stats.len, 2);
+		assert!((stats.hit_ratio() - 1.0 / 3.0).abs() < 1e-9);
+
+		let options = ConversionOptions {
+			on_unknown: OnUnknown::Error,
+			..ConversionOptions::default()
+		};
+		let err = cache.convert("漢字", ConversionTarget::Hiragana, &options);
+		assert_eq!(
+			err,
+			Err(ConversionError {
+				chr: '漢',
+				offset: 0
+			})
+		);
+		assert_eq!(
+			cache.convert("漢字", ConversionTarget::Hiragana, &options),
+			err
+		);
+		assert_eq!(
+			cache.convert("漢字", ConversionTarget::Hiragana, &Default::default()),
+			Ok("漢字".to_string())
+		);
+		assert_eq!(cache.stats().hits, 3);
+
+		cache.clear();
+		assert_eq!(
+			cache.stats(),
+			CacheStats {
+				capacity: 2,
+				..Default::default()
+			}
+		);
+
+		let mut cache = ConversionCache::new(0);
+		assert_eq!(cache.to_romaji("かな"), "kana");
+		assert_eq!(cache.to_romaji("かな"), "kana");
+		assert_eq!(cache.stats().misses, 2);
+		assert_eq!(cache.stats().len, 0);
+	}
+}
@@ -0,0 +1,143 @@
+//! Multiple candidate conversions for ambiguous romaji.
+
+use super::chunks::*;
+
+/// Returns the alternative kana for an ambiguous table key, ordered from the
+/// most to the least common.
+fn alternatives(key: &str) -> Option<&'static [&'static str]> {
+	let list: &'static [&'static str] = match key.to_lowercase().as_str() {
+		"zu" => &["ず", "づ"],
+		"ji" => &["じ", "ぢ"],
+		"ja" => &["じゃ", "ぢゃ"],
+		"ju" => &["じゅ", "ぢゅ"],
+		"jo" => &["じょ", "ぢょ"],
+		_ => return None,
+	};
+	Some(list)
+}
+
+/// Returns the vowel for a long vowel with a macron or circumflex.
+fn long_vowel(chr: char) -> Option<char> {
+	let vowel = match chr {
+		'ā' | 'â' => 'a',
+		'ī' | 'î' => 'i',
+		'ū' | 'û' => 'u',
+		'ē' | 'ê' => 'e',
+		'ō' | 'ô' => 'o',
+		'Ā' | 'Â' => 'A',
+		'Ī' | 'Î' => 'I',
+		'Ū' | 'Û' => 'U',
+		'Ē' | 'Ê' => 'E',
+		'Ō' | 'Ô' => 'O',
+		_ => return None,
+	};
+	Some(vowel)
+}
+
+/// Returns the alternatives for the second half of a long vowel, ordered from
+/// the most to the least common.
+fn long_vowel_alternatives(vowel: char) -> &'static [&'static str] {
+	match vowel.to_ascii_lowercase() {
+		'a' => &["あ", "ー"],
+		'i' => &["い", "ー"],
+		'u' => &["う", "ー"],
+		'e' => &["い", "え", "ー"],
+		_ => &["う", "お", "ー"],
+	}
+}
+
+/// Converts the input into hiragana, returning the plausible candidates for
+/// ambiguous romaji, up to the given limit.
+///
+/// Ambiguous romaji are the long vowels with a macron or circumflex (e.g. `ō`
+/// can be `おう` or `おお`) and the syllables that have the same reading
+/// (`zu` can be `ず` or `づ`, `ji` can be `じ` or `ぢ`).
+///
+/// Candidates are ranked by how common each alternative is, so the first
+/// candidate is the most likely. Note that this may differ from
+/// [to_hiragana](fn.to_hiragana.html), which keeps long vowels as `ー`.
+///
+/// ```
+/// let candidates = kana::to_hiragana_candidates("tōkyō", 3);
+/// assert_eq!(candidates, vec!["とうきょう", "とうきょお", "とおきょう"]);
+/// ```
+pub fn to_hiragana_candidates<S: AsRef<str>>(input: S, limit: usize) -> Vec<String> {
+	if limit == 0 {
+		return Vec::new();
+	}
+
+	// Long vowels are expanded to the vowel followed by a `-`, keeping the
+	// position of the `-` to generate the alternatives for it.
+	let mut expanded = String::with_capacity(input.as_ref().len());
+	let mut long_vowels = Vec::new();
+	for chr in input.as_ref().chars() {
+		if let Some(vowel) = long_vowel(chr) {
+			expanded.push(vowel);
+			long_vowels.push((expanded.len(), vowel));
+			expanded.push('-');
+		} else {
+			expanded.push(chr);
+		}
+	}
+
+	// Candidates with their score, which is the sum of the index of each
+	// alternative used. Keeping only the best candidates at each step is
+	// enough, since the score can only increase.
+	let mut candidates = vec![(String::new(), 0)];
+	for chunk in HiraganaChunks::new(&expanded) {
+		let long_vowel = long_vowels.iter().find(|x| x.0 == chunk.src.start);
+		let list = match (long_vowel, chunk.kind) {
+			(Some(&(_, vowel)), _) => Some(long_vowel_alternatives(vowel)),
+			(None, ChunkKind::Table(key)) => alternatives(key),
+			_ => None,
+		};
+		match list {
+			Some(list) => {
+				let mut next = Vec::with_capacity(candidates.len() * list.len());
+				for (text, score) in candidates.iter() {
+					for (index, kana) in list.iter().enumerate() {
+						next.push((format!("{}{}", text, kana), score + index));
+					}
+				}
+				next.sort_by_key(|x| x.1);
+				next.truncate(limit);
+				candidates = next;
+			}
+			None => {
+				for (text, _) in candidates.iter_mut() {
+					text.push_str(chunk.text);
+				}
+			}
+		}
+	}
+	candidates.into_iter().map(|x| x.0).collect()
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_to_hiragana_candidates() {
+		assert_eq!(to_hiragana_candidates("", 5), vec![""]);
+		assert_eq!(to_hiragana_candidates("kana", 5), vec!["かな"]);
+		assert!(to_hiragana_candidates("kana", 0).is_empty());
+		assert_eq!(to_hiragana_candidates("Ō", 5), vec!["おう", "おお", "おー"]);
+		assert_eq!(
+			to_hiragana_candidates("tsuzuku", 5),
+			vec!["つずく", "つづく"]
+		);
+		assert_eq!(
+			to_hiragana_candidates("hanaji", 5),
+			vec!["はなじ", "はなぢ"]
+		);
+		assert_eq!(
+			to_hiragana_candidates("zuzu", 10),
+			vec!["ずず", "ずづ", "づず", "づづ"]
+		);
+		assert_eq!(to_hiragana_candidates("sensē", 1), vec!["せんせい"]);
+		assert_eq!(to_hiragana_candidates("ōzumō", 100).len(), 18);
+	}
+}
@@ -0,0 +1,611 @@
+//! Chunk-level iterators for the kana conversions.
+//!
+//! The conversion functions (e.g. `to_romaji`) are implemented by collecting
+//! the chunks generated by the iterators in this module. Each chunk maps a
+//! range of the input to its converted output.
+//!
+//! The iterators are lazy, so they can be used to process the output
+//! incrementally or to stop the conversion early.
+//!
+//! Grapheme clusters are never split. Characters that extend a cluster, such
+//! as variation selectors and combining marks (other than the voiced marks
+//! composed with a kana), are included in a passthrough chunk for their base
+//! character. After a converted chunk, they are kept as a separate chunk and
+//! do not affect the conversion of the following chunk (e.g. a `っ` followed
+//! by a variation selector still doubles the next consonant).
+
+use std::ops::Range;
+
+use super::constants::*;
+use super::ligature::*;
+use super::table::*;
+use super::util::*;
+
+/// Representation for a `っ` that is not a double consonant.
+const SMALL_TSU_REPR: &str = "'";
+
+/// Representation for an invalid iteration mark.
+const INVALID_ITERATION_MARK: &str = "?";
+
+/// Explicit separator between romaji syllables (e.g. `n|ya` for `んや`).
+const ROMAJI_SEPARATOR: char = '|';
+
+/// Describes how a [Chunk] was generated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChunkKind {
+	/// Chunk converted using the table entry with the given key.
+	Table(&'static str),
+	/// Chunk converted by a special conversion rule (e.g. double consonants
+	/// and iteration marks).
+	Rule,
+	/// Input passed through unchanged.
+	Passthrough,
+}
+
+/// A converted chunk of the input.
+///
+/// Chunks dereference to their converted text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chunk<'a> {
+	/// Byte range of the chunk in the input string.
+	pub src: Range<usize>,
+	/// Converted output for the chunk.
+	pub text: &'a str,
+	/// How the chunk was converted.
+	pub kind: ChunkKind,
+}
+
+/// Returns a lazy iterator over the chunks for the hiragana conversion of the
+/// input.
+///
+/// Concatenating the text of all chunks is the same as [to_hiragana](fn.to_hiragana.html).
+pub fn hiragana_chunks<'a>(input: &'a str) -> HiraganaChunks<'a> {
+	HiraganaChunks::new(input)
+}
+
+/// Returns a lazy iterator over the chunks for the katakana conversion of the
+/// input.
+///
+/// Concatenating the text of all chunks is the same as [to_katakana](fn.to_katakana.html).
+pub fn katakana_chunks<'a>(input: &'a str) -> KatakanaChunks<'a> {
+	KatakanaChunks::new(input)
+}
+
+/// Returns a lazy iterator over the chunks for the romaji conversion of the
+/// input.
+///
+/// Concatenating the text of all chunks is the same as [to_romaji](fn.to_romaji.html).
+pub fn romaji_chunks<'a>(input: &'a str) -> RomajiChunks<'a> {
+	RomajiChunks::new(input)
+}
+
+/// Maximum number of bytes following a chunk that can affect its conversion.
+///
+/// A chunk that ends at least this many bytes before the end of the input
+/// will not change if more input is appended.
+pub(crate) fn max_lookahead() -> usize {
+	(std::cmp::max(*TO_HIRAGANA_MAX_CHUNK, *TO_ROMAJI_MAX_CHUNK) + 1) * 4
+}
+
+/// Returns the full-width Katakana for the Halfwidth Katakana at the start of
+/// the input, combining it with a following voiced or semi-voiced mark, and
+/// the length of the input used.
+fn half_width_chunk(src: &str) -> Option<(&'static str, usize)> {
+	let mut chars = src.chars();
+	let next = chars.next()?;
+	let katakana = half_width_to_katakana(next)?;
+	let voiced = chars
+		.next()
+		.and_then(|mark| half_width_voiced_to_katakana(next, mark));
+	match voiced {
+		Some(voiced) => Some((voiced, next.len_utf8() * 2)),
+		None => Some((katakana, next.len_utf8())),
+	}
+}
+
+/// Returns the kana at the start of the input composed with a following
+/// combining voiced or semi-voiced mark, and the length of the input used.
+fn composed_chunk(src: &str) -> Option<(&'static str, usize)> {
+	let mut chars = src.chars();
+	let next = chars.next()?;
+	let mark = chars.next()?;
+	let kana = compose_voiced(next, mark)?;
+	Some((kana, next.len_utf8() + mark.len_utf8()))
+}
+
+/// Returns the chunk for the grapheme extenders at the given position, which
+/// follow a converted chunk, if any.
+///
+/// Extenders at the start of the input have no base character, so they are
+/// left for the normal passthrough handling.
+fn extenders_chunk<'a>(input: &'a str, pos: usize) -> Option<Chunk<'a>> {
+	let len = grapheme_extend_len(&input[pos..]);
+	if pos == 0 || len == 0 {
+		return None;
+	}
+	Some(Chunk {
+		src: pos..pos + len,
+		text: &input[pos..pos + len],
+		kind: ChunkKind::Rule,
+	})
+}
+
+/// Extends a passthrough chunk to include the grapheme extenders following
+/// it, so that the cluster is kept together.
+fn extend_passthrough<'a>(input: &'a str, chunk: Chunk<'a>) -> Chunk<'a> {
+	let len = grapheme_extend_len(&input[chunk.src.end..]);
+	if chunk.kind != ChunkKind::Passthrough || len == 0 {
+		return chunk;
+	}
+	let src = chunk.src.start..chunk.src.end + len;
+	Chunk {
+		text: &input[src.clone()],
+		src,
+		kind: chunk.kind,
+	}
+}
+
+impl<'a> std::ops::Deref for Chunk<'a> {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		self.text
+	}
+}
+
+/// Iterator over the chunks for the hiragana conversion of a string.
+pub struct HiraganaChunks<'a> {
+	input: &'a str,
+	pos: usize,
+}
+
+impl<'a> HiraganaChunks<'a> {
+	pub fn new(input: &'a str) -> HiraganaChunks<'a> {
+		HiraganaChunks { input, pos: 0 }
+	}
+
+	/// Resumes a conversion from the given position of the input.
+	pub(crate) fn resume(input: &'a str, pos: usize) -> HiraganaChunks<'a> {
+		HiraganaChunks { input, pos }
+	}
+}
+
+impl<'a> Iterator for HiraganaChunks<'a> {
+	type Item = Chunk<'a>;
+
+	fn next(&mut self) -> Option<Chunk<'a>> {
+		let pos = self.pos;
+		let src = &self.input[pos..];
+		let next = src.chars().next()?;
+		let size = next.len_utf8();
+
+		let chunk = if let Some(chunk) = extenders_chunk(self.input, pos) {
+			chunk
+		} else if let Some((kana, len)) = composed_chunk(src) {
+			// Kana followed by a combining voiced mark
+			let chr = kana.chars().next().unwrap();
+			let text = if char_in_range(chr, KATAKANA_START, KATAKANA_TO_HIRAGANA_END) {
+				let index = ((chr as u32) - KATAKANA_START) as usize * KANA_CHAR_LEN;
+				&HIRAGANA_CHARS[index..index + KANA_CHAR_LEN]
+			} else if chr == 'ヾ' {
+				"ゞ"
+			} else {
+				TO_HIRAGANA.get(kana).cloned().unwrap_or(kana)
+			};
+			Chunk {
+				src: pos..pos + len,
+				text,
+				kind: ChunkKind::Rule,
+			}
+		} else if char_in_range(next, KATAKANA_START, KATAKANA_TO_HIRAGANA_END) {
+			// For katakana we can convert directly just by offseting the code
+			let index = ((next as u32) - KATAKANA_START) as usize * KANA_CHAR_LEN;
+			Chunk {
+				src: pos..pos + size,
+				text: &HIRAGANA_CHARS[index..index + KANA_CHAR_LEN],
+				kind: ChunkKind::Rule,
+			}
+		} else if is_kana_ligature(next) {
+			Chunk {
+				src: pos..pos + size,
+				text: ligature_hiragana(next),
+				kind: ChunkKind::Rule,
+			}
+		} else if let Some((text, len)) = ainu_katakana_to_hiragana(src) {
+			Chunk {
+				src: pos..pos + len,
+				text,
+				kind: ChunkKind::Rule,
+			}
+		} else if let Some((katakana, len)) = half_width_chunk(src) {
+			let chr = katakana.chars().next().unwrap();
+			let text = if char_in_range(chr, KATAKANA_START, KATAKANA_TO_HIRAGANA_END) {
+				let index = ((chr as u32) - KATAKANA_START) as usize * KANA_CHAR_LEN;
+				&HIRAGANA_CHARS[index..index + KANA_CHAR_LEN]
+			} else {
+				TO_HIRAGANA.get(katakana).cloned().unwrap_or(katakana)
+			};
+			Chunk {
+				src: pos..pos + len,
+				text,
+				kind: ChunkKind::Rule,
+			}
+		} else if char_in_range(next, HIRAGANA_START, HIRAGANA_END) {
+			Chunk {
+				src: pos..pos + size,
+				text: &src[..size],
+				kind: ChunkKind::Passthrough,
+			}
+		} else if next == ROMAJI_SEPARATOR
+			&& src[size..].starts_with(|c: char| c.is_ascii_alphabetic())
+		{
+			// The separator splits the romaji, so it is removed from the output
+			Chunk {
+				src: pos..pos + size,
+				text: "",
+				kind: ChunkKind::Rule,
+			}
+		} else {
+			let b = src.as_bytes();
+			let c = b[0] as char;
+			if b.len() >= 2 && c != 'n' && c != 'N' && is_consonant(c, true) && b[0] == b[1] {
+				// Handle the double consonant case
+				Chunk {
+					src: pos..pos + 1,
+					text: "っ",
+					kind: ChunkKind::Rule,
+				}
+			} else {
+				// Try to convert all chunk sizes, starting from largest down to 1.
+				let max_chunk = if next == ':' || next.is_ascii_alphabetic() {
+					// Multi-char lookup keys either start with A-Z or `:`
+					*TO_HIRAGANA_MAX_CHUNK
+				} else {
+					1
+				};
+				let mut chunk = None;
+				for len in (1..=max_chunk).rev() {
+					let key = get_prefix(src, len);
+					if let Some((key, kana)) = TO_HIRAGANA.get_key_value(key) {
+						chunk = Some(Chunk {
+							src: pos..pos + key.len(),
+							text: kana,
+							kind: ChunkKind::Table(key),
+						});
+						break;
+					}
+				}
+
+				// If could not find a conversion, just pass through the character.
+				chunk.unwrap_or(Chunk {
+					src: pos..pos + size,
+					text: &src[..size],
+					kind: ChunkKind::Passthrough,
+				})
+			}
+		};
+
+		let chunk = extend_passthrough(self.input, chunk);
+		self.pos = chunk.src.end;
+		Some(chunk)
+	}
+}
+
+/// Iterator over the chunks for the katakana conversion of a string.
+///
+/// This converts directly to katakana, so katakana that have no hiragana
+/// equivalent (e.g. `ヷ`) are kept as is.
+pub struct KatakanaChunks<'a> {
+	input: &'a str,
+	pos: usize,
+}
+
+impl<'a> KatakanaChunks<'a> {
+	pub fn new(input: &'a str) -> KatakanaChunks<'a> {
+		KatakanaChunks { input, pos: 0 }
+	}
+
+	/// Resumes a conversion from the given position of the input.
+	pub(crate) fn resume(input: &'a str, pos: usize) -> KatakanaChunks<'a> {
+		KatakanaChunks { input, pos }
+	}
+}
+
+impl<'a> Iterator for KatakanaChunks<'a> {
+	type Item = Chunk<'a>;
+
+	fn next(&mut self) -> Option<Chunk<'a>> {
+		let pos = self.pos;
+		let src = &self.input[pos..];
+		let next = src.chars().next()?;
+		let size = next.len_utf8();
+
+		let chunk = if let Some(chunk) = extenders_chunk(self.input, pos) {
+			chunk
+		} else if let Some((kana, len)) = composed_chunk(src) {
+			// Kana followed by a combining voiced mark
+			let chr = kana.chars().next().unwrap();
+			let text = if char_in_range(chr, HIRAGANA_START, HIRAGANA_END) {
+				let index = ((chr as u32) - HIRAGANA_START) as usize * KANA_CHAR_LEN;
+				&KATAKANA_CHARS[index..index + KANA_CHAR_LEN]
+			} else if chr == 'ゞ' {
+				"ヾ"
+			} else {
+				kana
+			};
+			Chunk {
+				src: pos..pos + len,
+				text,
+				kind: ChunkKind::Rule,
+			}
+		} else if char_in_range(next, HIRAGANA_START, HIRAGANA_END) {
+			// For hiragana we can convert directly just by offseting the code
+			let index = ((next as u32) - HIRAGANA_START) as usize * KANA_CHAR_LEN;
+			Chunk {
+				src: pos..pos + size,
+				text: &KATAKANA_CHARS[index..index + KANA_CHAR_LEN],
+				kind: ChunkKind::Rule,
+			}
+		} else if next == 'ゝ' || next == 'ゞ' {
+			Chunk {
+				src: pos..pos + size,
+				text: if next == 'ゝ' { "ヽ" } else { "ヾ" },
+				kind: ChunkKind::Rule,
+			}
+		} else if let Some(text) = expand_kana_ligature(next) {
+			Chunk {
+				src: pos..pos + size,
+				text,
+				kind: ChunkKind::Rule,
+			}
+		} else if let Some((text, len)) = half_width_chunk(src) {
+			Chunk {
+				src: pos..pos + len,
+				text,
+				kind: ChunkKind::Rule,
+			}
+		} else if char_in_range(next, KATAKANA_START, KATAKANA_END)
+			|| char_in_range(next, AINU_KATAKANA_START, AINU_KATAKANA_END)
+		{
+			Chunk {
+				src: pos..pos + size,
+				text: &src[..size],
+				kind: ChunkKind::Passthrough,
+			}
+		} else {
+			// Romaji uses the same chunks as the hiragana conversion.
+			let chunk = HiraganaChunks::resume(self.input, pos).next().unwrap();
+			let text = match chunk.kind {
+				ChunkKind::Table(key) => TO_KATAKANA[key].as_str(),
+				ChunkKind::Rule if chunk.text == "っ" => "ッ",
+				_ => chunk.text,
+			};
+			Chunk { text, ..chunk }
+		};
+
+		let chunk = extend_passthrough(self.input, chunk);
+		self.pos = chunk.src.end;
+		Some(chunk)
+	}
+}
+
+/// Iterator over the chunks for the romaji conversion of a string.
+pub struct RomajiChunks<'a> {
+	input: &'a str,
+	pos: usize,
+	last_romaji: &'static str,
+	peeked: Option<(Chunk<'a>, &'static str)>,
+}
+
+impl<'a> RomajiChunks<'a> {
+	pub fn new(input: &'a str) -> RomajiChunks<'a> {
+		RomajiChunks::resume(input, 0, "")
+	}
+
+	/// Resumes a conversion from the given position of the input, using a
+	/// state previously returned by [RomajiChunks::state].
+	pub(crate) fn resume(input: &'a str, pos: usize, state: &'static str) -> RomajiChunks<'a> {
+		RomajiChunks {
+			input,
+			pos,
+			last_romaji: state,
+			peeked: None,
+		}
+	}
+
+	/// Returns the state of the conversion after the last chunk, which can be
+	/// used to resume the conversion.
+	pub(crate) fn state(&self) -> &'static str {
+		self.last_romaji
+	}
+
+	/// Resolves the chunk at the given position of the input. This does not
+	/// handle the small `っ`, which depends on the following chunk.
+	///
+	/// Returns the chunk and the romaji syllable that should be repeated by
+	/// a following iteration mark.
+	fn resolve(&self, pos: usize) -> (Chunk<'a>, &'static str) {
+		let src = &self.input[pos..];
+		let next = src.chars().next().unwrap();
+		let size = next.len_utf8();
+
+		if next == 'ヽ' || next == 'ゝ' || next == 'ヾ' || next == 'ゞ' {
+			// Iteration marks repeat the last syllable
+			let voiced = next == 'ヾ' || next == 'ゞ';
+			let repeat = match self.last_romaji {
+				"yori" => "ri",
+				"koto" => "to",
+				_ => self.last_romaji,
+			};
+			let repeat = if voiced {
+				let voiced = romaji_to_voiced(repeat);
+				if !voiced.is_empty() {
+					voiced
+				} else {
+					// Even though it is wrong, we accept a voiced mark in a
+					// syllable that has no voiced equivalent.
+					repeat
+				}
+			} else {
+				repeat
+			};
+			if !repeat.is_empty() {
+				let chunk = Chunk {
+					src: pos..pos + size,
+					text: repeat,
+					kind: ChunkKind::Rule,
+				};
+				return (chunk, repeat);
+			} else {
+				let chunk = Chunk {
+					src: pos..pos + size,
+					text: INVALID_ITERATION_MARK,
+					kind: ChunkKind::Rule,
+				};
+				return (chunk, self.last_romaji);
+			}
+		}
+
+		if is_kana_ligature(next) {
+			let (romaji, last_romaji) = ligature_romaji(next);
+			let chunk = Chunk {
+				src: pos..pos + size,
+				text: romaji,
+				kind: ChunkKind::Rule,
+			};
+			return (chunk, last_romaji);
+		}
+
+		if TO_ROMAJI_CHARS.contains(&next) {
+			// Try to convert all chunk sizes down to 1
+			for len in (1..=*TO_ROMAJI_MAX_CHUNK).rev() {
+				let chunk = get_prefix(src, len);
+				if let Some((key, romaji)) = TO_ROMAJI.get_key_value(chunk) {
+					let chunk = Chunk {
+						src: pos..pos + chunk.len(),
+						text: romaji,
+						kind: ChunkKind::Table(key),
+					};
+					return (chunk, *romaji);
+				}
+			}
+		}
+
+		// If could not find a conversion, just pass through the character.
+		let chunk = Chunk {
+			src: pos..pos + size,
+			text: &src[..size],
+			kind: ChunkKind::Passthrough,
+		};
+		(chunk, self.last_romaji)
+	}
+}
+
+impl<'a> Iterator for RomajiChunks<'a> {
+	type Item = Chunk<'a>;
+
+	fn next(&mut self) -> Option<Chunk<'a>> {
+		let extenders = if self.pos < self.input.len() {
+			extenders_chunk(self.input, self.pos)
+		} else {
+			None
+		};
+		let (chunk, last_romaji) = if let Some(extenders) = extenders {
+			(extenders, self.last_romaji)
+		} else if let Some(peeked) = self.peeked.take() {
+			peeked
+		} else if self.pos < self.input.len() {
+			let pos = self.pos;
+			let next = self.input[pos..].chars().next().unwrap();
+			if next == 'っ' || next == 'ッ' || next == 'ｯ' {
+				// The small tsu doubles the consonant of the following chunk,
+				// so we need to resolve it first, skipping any extenders.
+				let end = pos + next.len_utf8();
+				let following = end + grapheme_extend_len(&self.input[end..]);
+				let mut text = SMALL_TSU_REPR;
+				match self.input[following..].chars().next() {
+					None | Some('っ') | Some('ッ') | Some('ｯ') => {}
+					Some(_) => {
+						let (following, last_romaji) = self.resolve(following);
+						if let ChunkKind::Table(_) = following.kind {
+							let doubled = following.text.chars().next().unwrap();
+							if is_consonant(doubled, true) {
+								text = &following.text[..1];
+							}
+						}
+						self.peeked = Some((following, last_romaji));
+					}
+				}
+				let chunk = Chunk {
+					src: pos..end,
+					text,
+					kind: ChunkKind::Rule,
+				};
+				(chunk, self.last_romaji)
+			} else {
+				self.resolve(pos)
+			}
+		} else {
+			return None;
+		};
+
+		let chunk = extend_passthrough(self.input, chunk);
+		self.pos = chunk.src.end;
+		self.last_romaji = last_romaji;
+		Some(chunk)
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_chunks() {
+		let chunks = romaji_chunks("きょうは晴れ").collect::<Vec<_>>();
+		assert_eq!(
+			chunks,
+			vec![
+				Chunk {
+					src: 0..6,
+					text: "kyo",
+					kind: ChunkKind::Table("きょ"),
+				},
+				Chunk {
+					src: 6..9,
+					text: "u",
+					kind: ChunkKind::Table("う"),
+				},
+				Chunk {
+					src: 9..12,
+					text: "ha",
+					kind: ChunkKind::Table("は"),
+				},
+				Chunk {
+					src: 12..15,
+					text: "晴",
+					kind: ChunkKind::Passthrough,
+				},
+				Chunk {
+					src: 15..18,
+					text: "re",
+					kind: ChunkKind::Table("れ"),
+				},
+			]
+		);
+
+		let chunks = hiragana_chunks("kitte カ")
+			.map(|x| x.text)
+			.collect::<Vec<_>>();
+		assert_eq!(chunks, vec!["き", "っ", "て", " ", "か"]);
+
+		// Stopping early
+		let first = romaji_chunks("がっこう")
+			.take(2)
+			.map(|x| x.text)
+			.collect::<String>();
+		assert_eq!(first, "gak");
+	}
+}
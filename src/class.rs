@@ -0,0 +1,151 @@
+//! Character ranges and regular expression classes for each [CharKind].
+
+use std::ops::RangeInclusive;
+
+use super::kind::*;
+
+lazy_static! {
+	/// Ranges of characters for each kind, indexed by the kind.
+	///
+	/// These are computed from [get_kind], so they always agree with it.
+	static ref KIND_RANGES: Vec<Vec<RangeInclusive<char>>> = {
+		let mut ranges = vec![Vec::new(); ALL_KINDS.len()];
+		let chars = (0..=0x10FFFF).filter_map(std::char::from_u32);
+		for chr in chars {
+			push_char(&mut ranges[get_kind(chr) as usize], chr);
+		}
+		ranges
+	};
+}
+
+/// Returns the character following the given one, skipping the surrogates.
+fn next_char(chr: char) -> Option<char> {
+	match chr {
+		'\u{D7FF}' => Some('\u{E000}'),
+		_ => std::char::from_u32(chr as u32 + 1),
+	}
+}
+
+/// Adds a character to a list of sorted ranges, extending the last range if
+/// the character follows it.
+fn push_char(ranges: &mut Vec<RangeInclusive<char>>, chr: char) {
+	if let Some(last) = ranges.last_mut() {
+		if next_char(*last.end()) == Some(chr) {
+			*last = *last.start()..=chr;
+			return;
+		}
+	}
+	ranges.push(chr..=chr);
+}
+
+/// Returns the sorted ranges of the characters of the given kinds, merging
+/// the adjacent ranges.
+///
+/// The ranges are exactly the characters for which [get_kind] returns one
+/// of the kinds.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(char_ranges(CharKind::BarLine), vec!['ー'..='ー', 'ｰ'..='ｰ']);
+/// assert_eq!(char_ranges(CharKind::RomanDigit), vec!['０'..='９']);
+/// ```
+pub fn char_ranges<K: Into<CharKindSet>>(kinds: K) -> Vec<RangeInclusive<char>> {
+	let kinds = kinds.into();
+	let mut all = kinds
+		.iter()
+		.flat_map(|kind| KIND_RANGES[kind as usize].iter().cloned())
+		.collect::<Vec<_>>();
+	all.sort_by_key(|range| *range.start());
+
+	let mut ranges: Vec<RangeInclusive<char>> = Vec::with_capacity(all.len());
+	for range in all {
+		match ranges.last_mut() {
+			Some(last) if next_char(*last.end()) == Some(*range.start()) => {
+				*last = *last.start()..=*range.end();
+			}
+			_ => ranges.push(range),
+		}
+	}
+	ranges
+}
+
+/// Returns a regular expression character class matching the characters of
+/// the given kinds, as in [char_ranges].
+///
+/// All characters are written as `\u{...}` escapes, which is the syntax used
+/// by the `regex` crate. An empty set returns the class `[^\s\S]`, which
+/// does not match any character.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(
+///     char_class(CharKind::Hiragana),
+///     r"[\u{3041}-\u{3096}\u{309F}\u{1B001}]"
+/// );
+/// assert_eq!(
+///     char_class(CharKind::BarLine | CharKind::RomanDigit),
+///     r"[\u{30FC}\u{FF10}-\u{FF19}\u{FF70}]"
+/// );
+/// ```
+pub fn char_class<K: Into<CharKindSet>>(kinds: K) -> String {
+	let ranges = char_ranges(kinds);
+	if ranges.is_empty() {
+		return String::from(r"[^\s\S]");
+	}
+
+	let mut class = String::from("[");
+	for range in ranges {
+		let (start, end) = (*range.start() as u32, *range.end() as u32);
+		if start == end {
+			class.push_str(&format!("\\u{{{:04X}}}", start));
+		} else {
+			class.push_str(&format!("\\u{{{:04X}}}-\\u{{{:04X}}}", start, end));
+		}
+	}
+	class.push(']');
+	class
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_char_ranges() {
+		assert!(char_ranges(CharKindSet::empty()).is_empty());
+		assert_eq!(char_class(CharKindSet::empty()), r"[^\s\S]");
+		assert_eq!(
+			char_ranges(CharKindSet::all()),
+			vec!['\u{0}'..='\u{10FFFF}']
+		);
+		assert_eq!(
+			char_ranges(CharKind::Hiragana | CharKind::BarLine),
+			vec![
+				'ぁ'..='ゖ',
+				'ゟ'..='ゟ',
+				'ー'..='ー',
+				'ｰ'..='ｰ',
+				'𛀁'..='𛀁'
+			]
+		);
+		assert_eq!(
+			char_class(CharKind::Space),
+			concat!(
+				r"[\u{0009}-\u{000D}\u{0020}\u{0085}\u{00A0}\u{1680}\u{2000}-\u{200A}",
+				r"\u{2028}-\u{2029}\u{202F}\u{205F}\u{3000}]"
+			)
+		);
+
+		// The ranges must agree with get_kind
+		for &kind in ALL_KINDS.iter() {
+			for range in char_ranges(kind) {
+				assert_eq!(get_kind(*range.start()), kind);
+				assert_eq!(get_kind(*range.end()), kind);
+			}
+		}
+	}
+}
@@ -0,0 +1,100 @@
+//! Completion of partial romaji input.
+
+use super::table::*;
+
+/// Possible completion for a romaji prefix, returned by [romaji_completions].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RomajiCompletion {
+	/// Complete romaji for the completion, starting with the prefix.
+	pub romaji: &'static str,
+	/// Kana for the completed romaji.
+	pub kana: &'static str,
+}
+
+/// Returns the possible kana for the romaji starting with the given prefix,
+/// including the prefix itself if it is complete.
+///
+/// Completions are sorted by the length of the romaji, then alphabetically.
+/// The completed part of the romaji is always lower case, regardless of the
+/// case of the prefix.
+///
+/// ```
+/// let kana = kana::romaji_completions("ky")
+///     .iter()
+///     .map(|x| x.kana)
+///     .collect::<Vec<_>>();
+/// assert_eq!(kana, vec!["きゃ", "きぇ", "きぃ", "きょ", "きゅ"]);
+/// ```
+pub fn romaji_completions(prefix: &str) -> Vec<RomajiCompletion> {
+	if prefix.is_empty() {
+		return Vec::new();
+	}
+	let mut out = TO_HIRAGANA
+		.iter()
+		.filter(|(key, _)| {
+			key.starts_with(prefix) && {
+				let rest = &key[prefix.len()..];
+				!rest.chars().any(|c| c.is_uppercase())
+			}
+		})
+		.map(|(&romaji, &kana)| RomajiCompletion { romaji, kana })
+		.collect::<Vec<_>>();
+	out.sort_by(|a, b| (a.romaji.len(), a.romaji).cmp(&(b.romaji.len(), b.romaji)));
+	out
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_romaji_completions() {
+		fn check(prefix: &str, expected: &[(&str, &str)]) {
+			let actual = romaji_completions(prefix)
+				.iter()
+				.map(|x| (x.romaji, x.kana))
+				.collect::<Vec<_>>();
+			assert_eq!(actual, expected, "prefix `{}`", prefix);
+		}
+
+		check("", &[]);
+		check("v!", &[]);
+		check(
+			"ky",
+			&[
+				("kya", "きゃ"),
+				("kye", "きぇ"),
+				("kyi", "きぃ"),
+				("kyo", "きょ"),
+				("kyu", "きゅ"),
+			],
+		);
+		check(
+			"KY",
+			&[
+				("KYa", "きゃ"),
+				("KYe", "きぇ"),
+				("KYi", "きぃ"),
+				("KYo", "きょ"),
+				("KYu", "きゅ"),
+			],
+		);
+		check("kya", &[("kya", "きゃ")]);
+		check("shi", &[("shi", "し")]);
+
+		let n = romaji_completions("n");
+		assert_eq!(
+			n[0],
+			RomajiCompletion {
+				romaji: "n",
+				kana: "ん"
+			}
+		);
+		assert!(n.contains(&RomajiCompletion {
+			romaji: "nya",
+			kana: "にゃ"
+		}));
+	}
+}
@@ -0,0 +1,99 @@
+//! Composition of the combining voiced sound marks.
+//!
+//! Text from NFD normalized sources (e.g. file names on macOS) uses the base
+//! kana followed by a combining mark (U+3099 or U+309A) instead of the voiced
+//! kana. The conversion functions already handle both forms.
+
+use std::borrow::Cow;
+
+use super::util::*;
+
+/// Composes any kana followed by a combining voiced or semi-voiced mark into
+/// the respective voiced kana (e.g. `か` and U+3099 to `が`).
+///
+/// Marks that cannot be combined are kept as is. Returns the input unchanged,
+/// without allocating, if there is nothing to compose.
+///
+/// ```
+/// assert_eq!(kana::compose_kana("か\u{3099}は\u{309A}"), "がぱ");
+/// ```
+pub fn compose_kana<'a>(input: &'a str) -> Cow<'a, str> {
+	if !input.contains(&[COMBINING_VOICED_MARK, COMBINING_SEMI_VOICED_MARK][..]) {
+		return Cow::Borrowed(input);
+	}
+	let mut out = String::with_capacity(input.len());
+	let mut chars = input.chars().peekable();
+	while let Some(chr) = chars.next() {
+		let composed = chars.peek().and_then(|&mark| compose_voiced(chr, mark));
+		match composed {
+			Some(composed) => {
+				out.push_str(composed);
+				chars.next();
+			}
+			None => out.push(chr),
+		}
+	}
+	Cow::Owned(out)
+}
+
+/// Decomposes voiced and semi-voiced kana into the base kana followed by the
+/// combining mark (e.g. `が` to `か` and U+3099), as in NFD normalization.
+///
+/// Returns the input unchanged, without allocating, if there is nothing to
+/// decompose.
+///
+/// ```
+/// assert_eq!(kana::decompose_kana("がパ"), "か\u{3099}ハ\u{309A}");
+/// ```
+pub fn decompose_kana<'a>(input: &'a str) -> Cow<'a, str> {
+	if !input.chars().any(|chr| decompose_voiced(chr).is_some()) {
+		return Cow::Borrowed(input);
+	}
+	let mut out = String::with_capacity(input.len() * 2);
+	for chr in input.chars() {
+		match decompose_voiced(chr) {
+			Some((base, mark)) => {
+				out.push(base);
+				out.push(mark);
+			}
+			None => out.push(chr),
+		}
+	}
+	Cow::Owned(out)
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::super::to::*;
+	use super::*;
+
+	#[test]
+	fn test_compose_kana() {
+		const COMPOSED: &str =
+			"がぎぐげござじずぜぞだぢづでどばびぶべぼぱぴぷぺぽゔゞ ガパヴヷヸヹヺヾ";
+
+		let decomposed = decompose_kana(COMPOSED);
+		assert_eq!(decomposed.chars().count(), COMPOSED.chars().count() * 2 - 1);
+		assert_eq!(compose_kana(&decomposed), COMPOSED);
+
+		assert!(matches!(compose_kana("かな"), Cow::Borrowed(_)));
+		assert!(matches!(decompose_kana("かな"), Cow::Borrowed(_)));
+		assert_eq!(
+			compose_kana("\u{3099}あ\u{3099}は\u{309A}\u{3099}"),
+			"\u{3099}あ\u{3099}ぱ\u{3099}"
+		);
+	}
+
+	#[test]
+	fn test_decomposed_conversion() {
+		let input = decompose_kana("がっこう ゴジラ ぱゞ");
+		assert_eq!(to_hiragana(&input), "がっこう ごじら ぱゞ");
+		assert_eq!(to_katakana(&input), "ガッコウ ゴジラ パヾ");
+		assert_eq!(
+			to_romaji(decompose_kana("がっこう ゴジラ ぎょ")),
+			"gakkou gojira gyo"
+		);
+	}
+}
@@ -0,0 +1,218 @@
+//! Detection of visually confusable characters, such as the katakana `ロ`
+//! and the kanji `口`.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use super::kind::*;
+
+/// A confusable character returned by [find_confusables].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Confusable {
+	/// Byte range of the character in the input.
+	pub src: Range<usize>,
+	/// The character found in the input.
+	pub found: char,
+	/// The character that was likely meant, given the surrounding text.
+	pub suggestion: char,
+}
+
+/// Script of the characters around a confusable character.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Context {
+	Katakana,
+	KatakanaHalfWidth,
+	Kanji,
+	Digit,
+	FullWidthDigit,
+}
+
+/// Returns true for the characters handled by [suggestion].
+fn is_confusable(chr: char) -> bool {
+	"ロ口カ力エ工ニ二ー一ｰ－".contains(chr)
+}
+
+/// Returns the context given by a neighboring character, if any. The
+/// confusable characters do not give any context.
+fn context_of(chr: char) -> Option<Context> {
+	if is_confusable(chr) {
+		return None;
+	}
+	let context = match get_kind(chr) {
+		CharKind::Katakana => Context::Katakana,
+		CharKind::KatakanaHalfWidth => Context::KatakanaHalfWidth,
+		CharKind::Kanji => Context::Kanji,
+		CharKind::RomanDigit => Context::FullWidthDigit,
+		_ => match chr {
+			'々' => Context::Kanji,
+			'0'..='9' => Context::Digit,
+			_ => return None,
+		},
+	};
+	Some(context)
+}
+
+/// Returns the context of a character from its neighbors. Neighbors with
+/// different contexts (e.g. at the boundary between katakana and kanji, as
+/// in `ページ一覧`) give no context, since either character could be meant.
+fn context(prev: Option<char>, next: Option<char>) -> Option<Context> {
+	match (prev.and_then(context_of), next.and_then(context_of)) {
+		(Some(prev), Some(next)) if prev != next => None,
+		(Some(context), _) | (_, Some(context)) => Some(context),
+		(None, None) => None,
+	}
+}
+
+/// Returns the character that was likely meant for a confusable character,
+/// given its context, or the character itself if it is not confusable.
+fn suggestion(chr: char, context: Context) -> char {
+	const PAIRS: [(char, char); 4] = [('ロ', '口'), ('カ', '力'), ('エ', '工'), ('ニ', '二')];
+	if let Some(&(kana, kanji)) = PAIRS.iter().find(|x| x.0 == chr || x.1 == chr) {
+		return match context {
+			Context::Katakana => kana,
+			Context::Kanji => kanji,
+			_ => chr,
+		};
+	}
+	match chr {
+		// Long sound marks and dashes
+		'ー' | '一' | 'ｰ' | '－' => match context {
+			Context::Katakana => 'ー',
+			Context::KatakanaHalfWidth => 'ｰ',
+			Context::Kanji => '一',
+			Context::FullWidthDigit => '－',
+			Context::Digit => '-',
+		},
+		_ => chr,
+	}
+}
+
+/// Returns the visually confusable characters in the input that are likely
+/// wrong given the surrounding text, with the suggested replacement.
+///
+/// The confusables are the long sound marks and dashes (`ー`, `一`, `ｰ` and
+/// `－`) and the katakana that look like kanji (`ロ` and `口`, `カ` and `力`,
+/// `エ` and `工`, `ニ` and `二`). The suggestion is based on the script of
+/// the neighboring characters, so a character without katakana, kanji or
+/// digits around it is never reported, and neither is a character between
+/// two different scripts (e.g. the `一` in `ページ一覧`).
+///
+/// This is meant for the post-processing of OCR output and validation of
+/// user input.
+///
+/// ```
+/// let found = kana::find_confusables("ラ一メンを食べる人口");
+/// assert_eq!(found.len(), 1);
+/// assert_eq!(found[0].src, 3..6);
+/// assert_eq!((found[0].found, found[0].suggestion), ('一', 'ー'));
+/// ```
+pub fn find_confusables(input: &str) -> Vec<Confusable> {
+	let chars = input.char_indices().collect::<Vec<_>>();
+	let mut found = Vec::new();
+	for (index, &(offset, chr)) in chars.iter().enumerate() {
+		let prev = index.checked_sub(1).map(|index| chars[index].1);
+		let next = chars.get(index + 1).map(|x| x.1);
+		let suggestion = match context(prev, next) {
+			Some(context) => suggestion(chr, context),
+			None => continue,
+		};
+		// The `カ` counter (e.g. `3カ月`) is written as katakana, and `口コミ`
+		// is written with a kanji.
+		let counter = chr == 'カ' && matches!(next, Some('月' | '所' | '国' | '条' | '年'));
+		let kuchikomi = input[offset..].starts_with("口コミ");
+		if suggestion != chr && !counter && !kuchikomi {
+			found.push(Confusable {
+				src: offset..offset + chr.len_utf8(),
+				found: chr,
+				suggestion,
+			});
+		}
+	}
+	found
+}
+
+/// Replaces the confusable characters returned by [find_confusables] by the
+/// suggested character. Returns the input without allocating if there are
+/// no confusables.
+///
+/// ```
+/// assert_eq!(kana::fix_confusables("エ場でラ一メン"), "工場でラーメン");
+/// assert_eq!(kana::fix_confusables("ｶﾚ－"), "ｶﾚｰ");
+/// ```
+pub fn fix_confusables<'a>(input: &'a str) -> Cow<'a, str> {
+	let found = find_confusables(input);
+	if found.is_empty() {
+		return Cow::Borrowed(input);
+	}
+	let mut out = String::with_capacity(input.len());
+	let mut last = 0;
+	for confusable in found {
+		out.push_str(&input[last..confusable.src.start]);
+		out.push(confusable.suggestion);
+		last = confusable.src.end;
+	}
+	out.push_str(&input[last..]);
+	Cow::Owned(out)
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fix(input: &str) -> String {
+		fix_confusables(input).into_owned()
+	}
+
+	#[test]
+	fn test_find_confusables() {
+		assert!(find_confusables("").is_empty());
+		assert!(find_confusables("ロ口カ力エ工ニ二ー一ｰ－").is_empty());
+		assert_eq!(
+			find_confusables("第ー回"),
+			vec![Confusable {
+				src: 3..6,
+				found: 'ー',
+				suggestion: '一',
+			}]
+		);
+
+		// Already correct
+		for text in &[
+			"ラーメン",
+			"人口",
+			"工場",
+			"二人",
+			"ｶﾚｰ",
+			"一日",
+			"3カ月",
+			"カレー",
+			"ページ一覧",
+			"クラス一番",
+			"データ二件",
+			"アジア工業",
+			"ホテル口コミ",
+			"ラーメン一杯",
+		] {
+			assert!(
+				matches!(fix_confusables(text), Cow::Borrowed(_)),
+				"{}",
+				text
+			);
+		}
+
+		// No context
+		assert_eq!(fix("口をあける"), "口をあける");
+		assert_eq!(fix("ロ"), "ロ");
+		assert_eq!(fix("a-b"), "a-b");
+
+		assert_eq!(fix("コ一ヒ一"), "コーヒー");
+		assert_eq!(fix("ｺ－ﾋ－"), "ｺｰﾋｰ");
+		assert_eq!(fix("第ー回"), "第一回");
+		assert_eq!(fix("ロ紅とカ士"), "口紅と力士");
+		assert_eq!(fix("エネルギ一と二ンジン"), "エネルギーとニンジン");
+		assert_eq!(fix("03ー1234"), "03-1234");
+		assert_eq!(fix("０３ー１２３４"), "０３－１２３４");
+	}
+}
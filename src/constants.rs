@@ -9,6 +9,10 @@ pub const HIRAGANA_END: u32 = 0x3096;
 /// Codepoint for the first Katakana character (Katakana Letter Small A `ァ`).
 pub const KATAKANA_START: u32 = 0x30A1;
 
+/// Codepoint for the last character in the Katakana block (Katakana Digraph
+/// Koto `ヿ`).
+pub const KATAKANA_END: u32 = 0x30FF;
+
 /// Last Katakana that can be converted directly to Hiragana by offseting
 /// (Katakana Letter Small Ke `ヶ`).
 pub const KATAKANA_TO_HIRAGANA_END: u32 = 0x30F6;
@@ -18,3 +22,43 @@ pub const KATAKANA_TO_HIRAGANA_END: u32 = 0x30F6;
 ///
 /// Only valid between `KATAKANA_START` and `KATAKANA_TO_HIRAGANA_END`.
 pub const KATAKANA_TO_HIRAGANA_OFFSET_SUB: u32 = KATAKANA_START - HIRAGANA_START;
+
+/// All Hiragana characters from `HIRAGANA_START` to `HIRAGANA_END`, in order.
+///
+/// Each character is encoded in UTF-8 using exactly `KANA_CHAR_LEN` bytes.
+pub const HIRAGANA_CHARS: &str = "ぁあぃいぅうぇえぉおかがきぎくぐけげこごさざしじすずせぜそぞただちぢっつづてでとどなにぬねのはばぱひびぴふぶぷへべぺほぼぽまみむめもゃやゅゆょよらりるれろゎわゐゑをんゔゕゖ";
+
+/// All Katakana characters from `KATAKANA_START` to `KATAKANA_TO_HIRAGANA_END`,
+/// in order.
+///
+/// Each character is encoded in UTF-8 using exactly `KANA_CHAR_LEN` bytes.
+pub const KATAKANA_CHARS: &str = "ァアィイゥウェエォオカガキギクグケゲコゴサザシジスズセゼソゾタダチヂッツヅテデトドナニヌネノハバパヒビピフブプヘベペホボポマミムメモャヤュユョヨラリルレロヮワヰヱヲンヴヵヶ";
+
+/// Codepoint for the first Halfwidth Katakana character (Halfwidth Katakana
+/// Letter Wo `ｦ`).
+pub const HALF_WIDTH_KATAKANA_START: u32 = 0xFF66;
+
+/// Codepoint for the last Halfwidth Katakana character (Halfwidth Katakana
+/// Letter N `ﾝ`).
+pub const HALF_WIDTH_KATAKANA_END: u32 = 0xFF9D;
+
+/// Full-width Katakana for each Halfwidth Katakana character from
+/// `HALF_WIDTH_KATAKANA_START` to `HALF_WIDTH_KATAKANA_END`, in order. This
+/// includes the prolonged sound mark `ー` for `ｰ`.
+///
+/// Each character is encoded in UTF-8 using exactly `KANA_CHAR_LEN` bytes.
+pub const HALF_WIDTH_TO_KATAKANA: &str = "ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+
+/// Length in bytes of the UTF-8 encoding for characters in `HIRAGANA_CHARS`.
+pub const KANA_CHAR_LEN: usize = 3;
+
+/// Start of the Katakana Phonetic Extensions block, with the small katakana
+/// used in Ainu.
+pub const AINU_KATAKANA_START: u32 = 0x31F0;
+
+/// End of the Katakana Phonetic Extensions block.
+pub const AINU_KATAKANA_END: u32 = 0x31FF;
+
+/// Full size hiragana for each of the small katakana in the Katakana Phonetic
+/// Extensions block, starting at `AINU_KATAKANA_START`.
+pub const AINU_KATAKANA_TO_HIRAGANA: &str = "くしすとぬはひふへほむらりるれろ";
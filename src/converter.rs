@@ -0,0 +1,172 @@
+//! Conversion of input that is received in pieces.
+
+use super::chunks::*;
+use super::options::*;
+
+/// Converts input that is received in successive pieces, such as packets
+/// from a network connection.
+///
+/// The input can be split at any point, including in the middle of a romaji
+/// syllable (e.g. `sh` and `a`) or a double consonant. Because of that, the
+/// end of each piece is kept pending until enough input follows it to convert
+/// it correctly. Call [Converter::finish] at the end of the input to convert
+/// the pending input.
+///
+/// ```
+/// use kana::*;
+///
+/// let mut converter = Converter::new(ConversionTarget::Hiragana);
+/// let mut output = String::new();
+/// output.push_str(&converter.push_str("kitt"));
+/// output.push_str(&converter.push_str("e wo kaimash"));
+/// output.push_str(&converter.push_str("ita"));
+/// output.push_str(&converter.finish());
+/// assert_eq!(output, "きって を かいました");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Converter {
+	target: ConversionTarget,
+	pending: String,
+	state: &'static str,
+}
+
+impl Converter {
+	/// Creates a new converter for the given target.
+	pub fn new(target: ConversionTarget) -> Converter {
+		Converter {
+			target,
+			pending: String::new(),
+			state: "",
+		}
+	}
+
+	/// Appends the input to the converter and returns the output that is
+	/// ready.
+	///
+	/// The returned output may be empty if all the input is still pending.
+	pub fn push_str(&mut self, input: &str) -> String {
+		let mut out = String::new();
+		self.push_str_to(input, &mut out);
+		out
+	}
+
+	/// Converts all the pending input and returns its output.
+	///
+	/// This resets the converter, which can then be used for a new input.
+	pub fn finish(&mut self) -> String {
+		let mut out = String::new();
+		self.finish_to(&mut out);
+		out
+	}
+
+	/// Input that is pending conversion.
+	pub fn pending(&self) -> &str {
+		&self.pending
+	}
+
+	/// Same as [Converter::push_str] but appends the output to `out`.
+	pub(crate) fn push_str_to(&mut self, input: &str, out: &mut String) {
+		self.pending.push_str(input);
+		self.convert(out, max_lookahead());
+	}
+
+	/// Same as [Converter::finish] but appends the output to `out`.
+	pub(crate) fn finish_to(&mut self, out: &mut String) {
+		self.convert(out, 0);
+		self.state = "";
+	}
+
+	fn convert(&mut self, out: &mut String, lookahead: usize) {
+		let input = &self.pending[..];
+		let mut pos = 0;
+		match self.target {
+			ConversionTarget::Hiragana => {
+				for chunk in HiraganaChunks::new(input) {
+					if chunk.src.end + lookahead > input.len() {
+						break;
+					}
+					out.push_str(chunk.text);
+					pos = chunk.src.end;
+				}
+			}
+			ConversionTarget::Katakana => {
+				for chunk in KatakanaChunks::new(input) {
+					if chunk.src.end + lookahead > input.len() {
+						break;
+					}
+					out.push_str(chunk.text);
+					pos = chunk.src.end;
+				}
+			}
+			ConversionTarget::Romaji => {
+				let mut chunks = RomajiChunks::resume(input, 0, self.state);
+				while let Some(chunk) = chunks.next() {
+					if chunk.src.end + lookahead > input.len() {
+						break;
+					}
+					out.push_str(chunk.text);
+					pos = chunk.src.end;
+					self.state = chunks.state();
+				}
+			}
+		}
+		self.pending.drain(..pos);
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_converter() {
+		fn check(target: ConversionTarget, pieces: &[&str], expected: &str) {
+			let mut converter = Converter::new(target);
+			let mut output = String::new();
+			for piece in pieces {
+				output.push_str(&converter.push_str(piece));
+			}
+			output.push_str(&converter.finish());
+			assert_eq!(output, expected, "pieces {:?}", pieces);
+			assert_eq!(converter.pending(), "");
+		}
+
+		check(ConversionTarget::Hiragana, &[], "");
+		check(ConversionTarget::Hiragana, &["sh", "a"], "しゃ");
+		check(ConversionTarget::Hiragana, &["ki", "t", "te"], "きって");
+		check(ConversionTarget::Hiragana, &["n", "ya"], "にゃ");
+		check(ConversionTarget::Hiragana, &["n", "n", "ya"], "んにゃ");
+		check(ConversionTarget::Hiragana, &["kon", ""], "こん");
+		check(
+			ConversionTarget::Katakana,
+			&["ko", "-", "hi", "-"],
+			"コーヒー",
+		);
+		check(ConversionTarget::Romaji, &["が", "っ", "こう"], "gakkou");
+		check(ConversionTarget::Romaji, &["き", "ょ", "う"], "kyou");
+		check(ConversionTarget::Romaji, &["こ", "ゝ", "ろ"], "kokoro");
+		check(ConversionTarget::Romaji, &["いっ"], "i'");
+
+		let mut converter = Converter::new(ConversionTarget::Hiragana);
+		assert_eq!(converter.push_str("sh"), "");
+		assert_eq!(converter.pending(), "sh");
+		assert_eq!(converter.finish(), "sh");
+
+		let mut converter = Converter::new(ConversionTarget::Romaji);
+		assert_eq!(converter.push_str("こゝ"), "");
+		assert_eq!(converter.finish(), "koko");
+		assert_eq!(converter.push_str("ゝ"), "");
+		assert_eq!(converter.finish(), "?");
+
+		let text = "kore ha nagai bun desu. ".repeat(10);
+		let mut converter = Converter::new(ConversionTarget::Hiragana);
+		let output = converter.push_str(&text);
+		assert!(!output.is_empty());
+		assert_eq!(
+			output + &converter.finish(),
+			super::super::to_hiragana(&text)
+		);
+	}
+}
@@ -0,0 +1,116 @@
+//! Fast counting of the character kinds in a string.
+//!
+//! The kinds for the Basic Multilingual Plane are precomputed in a lookup
+//! table, so counting does a single table access per character instead of
+//! matching against the ranges of each kind.
+
+use super::kind::*;
+
+lazy_static! {
+	/// Index in [ALL_KINDS] of the kind for each character of the Basic
+	/// Multilingual Plane.
+	static ref KIND_TABLE: Vec<u8> = {
+		(0..=0xFFFF)
+			.map(|code| match std::char::from_u32(code) {
+				Some(chr) => get_kind(chr) as u8,
+				None => CharKind::None as u8,
+			})
+			.collect()
+	};
+}
+
+/// Returns the index of the kind of a character in [ALL_KINDS].
+#[inline]
+fn kind_index(table: &[u8], chr: char) -> usize {
+	match table.get(chr as usize) {
+		Some(&index) => index as usize,
+		None => get_kind(chr) as usize,
+	}
+}
+
+/// Returns the number of characters of each kind in the input.
+///
+/// This is the same as [kind_histogram], which uses this function.
+///
+/// ```
+/// use kana::*;
+///
+/// let counts = count_by_kind("漢字とカナ");
+/// assert_eq!(counts.count(CharKind::Kanji), 2);
+/// assert_eq!(counts.count(CharKind::Katakana), 2);
+/// ```
+pub fn count_by_kind<S: AsRef<str>>(input: S) -> KindHistogram {
+	let table = KIND_TABLE.as_slice();
+	let mut counts = [0; ALL_KINDS.len()];
+	for chr in input.as_ref().chars() {
+		counts[kind_index(table, chr)] += 1;
+	}
+	KindHistogram::from_counts(counts)
+}
+
+/// Returns the number of kanji in the input.
+///
+/// ```
+/// assert_eq!(kana::count_kanji("日本語のテキスト"), 3);
+/// ```
+pub fn count_kanji<S: AsRef<str>>(input: S) -> usize {
+	let table = KIND_TABLE.as_slice();
+	let kanji = CharKind::Kanji as usize;
+	input
+		.as_ref()
+		.chars()
+		.filter(|&chr| kind_index(table, chr) == kanji)
+		.count()
+}
+
+/// Returns the number of kana in the input, including the halfwidth katakana
+/// and the prolonged sound mark.
+///
+/// ```
+/// assert_eq!(kana::count_kana("日本語のテキスト"), 5);
+/// ```
+pub fn count_kana<S: AsRef<str>>(input: S) -> usize {
+	let table = KIND_TABLE.as_slice();
+	let kana =
+		CharKind::Hiragana | CharKind::Katakana | CharKind::KatakanaHalfWidth | CharKind::BarLine;
+	input
+		.as_ref()
+		.chars()
+		.filter(|&chr| kana.contains(ALL_KINDS[kind_index(table, chr)]))
+		.count()
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::is::*;
+
+	#[test]
+	fn test_count() {
+		assert_eq!(count_kanji(""), 0);
+		assert_eq!(count_kana(""), 0);
+		assert_eq!(count_by_kind("").total(), 0);
+
+		let text = "漢字𠀀とｶﾀｶﾅ、ラーメン abc";
+		assert_eq!(count_kanji(text), 3);
+		assert_eq!(count_kana(text), 9);
+
+		let counts = count_by_kind(text);
+		assert_eq!(counts.total(), text.chars().count());
+		assert_eq!(counts.count(CharKind::Kanji), 3);
+		assert_eq!(counts.count(CharKind::KatakanaHalfWidth), 4);
+		assert_eq!(counts.count(CharKind::BarLine), 1);
+		assert_eq!(counts.count(CharKind::Romaji), 3);
+
+		// The table must agree with the ranges for the whole plane
+		for code in 0..=0xFFFF {
+			if let Some(chr) = std::char::from_u32(code) {
+				let text = chr.to_string();
+				assert_eq!(count_kanji(&text), is_kanji(chr) as usize, "U+{:04X}", code);
+				assert_eq!(count_kana(&text), is_kana(chr) as usize, "U+{:04X}", code);
+			}
+		}
+	}
+}
@@ -0,0 +1,337 @@
+//! Display width of text in terminal columns, following the East Asian Width
+//! rules, and truncation and padding based on it.
+
+use std::borrow::Cow;
+
+use super::info::{get_width, CharWidth};
+
+/// Options for [char_width_with] and [display_width_with].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WidthOptions {
+	/// Count the East Asian ambiguous characters (e.g. `○`, `§` and the Greek
+	/// and Cyrillic letters) as two columns, as done by terminals configured
+	/// for CJK locales. By default they are one column.
+	pub ambiguous_wide: bool,
+}
+
+/// Returns the number of terminal columns used by the character.
+///
+/// Full-width characters (e.g. kana, kanji and `Ａ`) are two columns and
+/// halfwidth characters (e.g. ASCII and `ｶ`) are one. Control characters,
+/// combining marks (e.g. the combining dakuten U+3099), zero width spaces and
+/// variation selectors are zero columns.
+///
+/// ```
+/// assert_eq!(kana::char_width('あ'), 2);
+/// assert_eq!(kana::char_width('ｱ'), 1);
+/// assert_eq!(kana::char_width('\u{3099}'), 0);
+/// ```
+pub fn char_width(chr: char) -> usize {
+	char_width_with(chr, &WidthOptions::default())
+}
+
+/// Same as [char_width] but with the given options.
+///
+/// ```
+/// let options = kana::WidthOptions {
+///     ambiguous_wide: true,
+/// };
+/// assert_eq!(kana::char_width('○'), 1);
+/// assert_eq!(kana::char_width_with('○', &options), 2);
+/// ```
+pub fn char_width_with(chr: char, options: &WidthOptions) -> usize {
+	if is_zero_width(chr) {
+		return 0;
+	}
+	match get_width(chr) {
+		CharWidth::Full => 2,
+		CharWidth::Half => 1,
+		CharWidth::Neutral if options.ambiguous_wide && is_ambiguous_width(chr) => 2,
+		CharWidth::Neutral => 1,
+	}
+}
+
+/// Returns the number of terminal columns used by the text, which is the sum
+/// of the [char_width] of its characters.
+///
+/// ```
+/// assert_eq!(kana::display_width("漢字とｶﾅ!"), 9);
+/// ```
+pub fn display_width<S: AsRef<str>>(input: S) -> usize {
+	display_width_with(input, &WidthOptions::default())
+}
+
+/// Same as [display_width] but with the given options.
+pub fn display_width_with<S: AsRef<str>>(input: S, options: &WidthOptions) -> usize {
+	input
+		.as_ref()
+		.chars()
+		.map(|chr| char_width_with(chr, options))
+		.sum()
+}
+
+/// Returns the longest prefix of the input that fits in the given number of
+/// columns, as measured by [display_width].
+///
+/// Characters are never split, so the prefix can be one column short when
+/// the next character is full-width. Zero width characters following the
+/// last character (e.g. combining marks) are kept.
+///
+/// ```
+/// assert_eq!(kana::truncate_to_width("漢字とｶﾅ", 5), "漢字");
+/// assert_eq!(kana::truncate_to_width("漢字とｶﾅ", 7), "漢字とｶ");
+/// ```
+pub fn truncate_to_width(input: &str, width: usize) -> &str {
+	truncate_to_width_with(input, width, &WidthOptions::default())
+}
+
+/// Same as [truncate_to_width] but with the given options.
+pub fn truncate_to_width_with<'a>(input: &'a str, width: usize, options: &WidthOptions) -> &'a str {
+	let mut used = 0;
+	for (offset, chr) in input.char_indices() {
+		used += char_width_with(chr, options);
+		if used > width {
+			return &input[..offset];
+		}
+	}
+	input
+}
+
+/// Truncates the input to fit in the given number of columns, ending it with
+/// the ellipsis if anything was removed.
+///
+/// The input is returned unchanged if it fits. Otherwise the ellipsis is
+/// included in the width, and is itself truncated if it does not fit.
+///
+/// ```
+/// assert_eq!(kana::ellipsize_to_width("漢字とｶﾅ", 8, "…"), "漢字とｶﾅ");
+/// assert_eq!(kana::ellipsize_to_width("漢字とｶﾅ", 7, "…"), "漢字と…");
+/// assert_eq!(kana::ellipsize_to_width("漢字とｶﾅ", 6, "..."), "漢...");
+/// ```
+pub fn ellipsize_to_width<'a>(input: &'a str, width: usize, ellipsis: &str) -> Cow<'a, str> {
+	ellipsize_to_width_with(input, width, ellipsis, &WidthOptions::default())
+}
+
+/// Same as [ellipsize_to_width] but with the given options.
+pub fn ellipsize_to_width_with<'a>(
+	input: &'a str,
+	width: usize,
+	ellipsis: &str,
+	options: &WidthOptions,
+) -> Cow<'a, str> {
+	if display_width_with(input, options) <= width {
+		return Cow::Borrowed(input);
+	}
+	let ellipsis = truncate_to_width_with(ellipsis, width, options);
+	let width = width - display_width_with(ellipsis, options);
+	let mut out = truncate_to_width_with(input, width, options).to_string();
+	out.push_str(ellipsis);
+	Cow::Owned(out)
+}
+
+/// Alignment of the text for [pad_to_width].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TextAlign {
+	/// Add the padding after the text.
+	Left,
+	/// Add the padding before the text.
+	Right,
+	/// Split the padding around the text, with the extra column after it.
+	Center,
+}
+
+/// Pads the input with spaces to fill the given number of columns, as
+/// measured by [display_width].
+///
+/// Text that is already as wide or wider is returned unchanged, so this can
+/// be combined with [truncate_to_width] to get an exact width.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(pad_to_width("カナ", 6, TextAlign::Left), "カナ  ");
+/// assert_eq!(pad_to_width("ｶﾅ", 6, TextAlign::Right), "    ｶﾅ");
+/// assert_eq!(pad_to_width("カ", 5, TextAlign::Center), " カ  ");
+/// ```
+pub fn pad_to_width(input: &str, width: usize, align: TextAlign) -> String {
+	pad_to_width_with(input, width, align, &WidthOptions::default())
+}
+
+/// Same as [pad_to_width] but with the given options.
+pub fn pad_to_width_with(
+	input: &str,
+	width: usize,
+	align: TextAlign,
+	options: &WidthOptions,
+) -> String {
+	let padding = width.saturating_sub(display_width_with(input, options));
+	let (before, after) = match align {
+		TextAlign::Left => (0, padding),
+		TextAlign::Right => (padding, 0),
+		TextAlign::Center => (padding / 2, padding - padding / 2),
+	};
+	let mut out = String::with_capacity(input.len() + padding);
+	out.extend(std::iter::repeat_n(' ', before));
+	out.push_str(input);
+	out.extend(std::iter::repeat_n(' ', after));
+	out
+}
+
+/// Returns true for the characters that take no columns.
+fn is_zero_width(chr: char) -> bool {
+	chr.is_control()
+		|| matches!(
+			chr,
+			// Combining diacritical marks
+			'\u{0300}'..='\u{036F}'
+			| '\u{1AB0}'..='\u{1AFF}'
+			| '\u{1DC0}'..='\u{1DFF}'
+			| '\u{20D0}'..='\u{20FF}'
+			| '\u{FE20}'..='\u{FE2F}'
+			// Combining kana voiced marks
+			| '\u{3099}' | '\u{309A}'
+			// Zero width spaces, joiners and directional marks
+			| '\u{200B}'..='\u{200F}'
+			| '\u{2060}'..='\u{2064}'
+			| '\u{FEFF}'
+			// Variation selectors
+			| '\u{FE00}'..='\u{FE0F}'
+			| '\u{E0100}'..='\u{E01EF}'
+		)
+}
+
+/// Returns true for the East Asian ambiguous width characters that are
+/// relevant to Japanese text, such as the Latin-1 symbols, Greek and Cyrillic
+/// letters, arrows, math operators, box drawing and geometric shapes.
+fn is_ambiguous_width(chr: char) -> bool {
+	matches!(
+		chr,
+		// Latin-1 symbols and letters
+		'\u{A1}' | '\u{A4}' | '\u{A7}' | '\u{A8}' | '\u{AA}' | '\u{AD}' | '\u{AE}'
+		| '\u{B0}'..='\u{B4}'
+		| '\u{B6}'..='\u{BA}'
+		| '\u{BC}'..='\u{BF}'
+		| '\u{C6}' | '\u{D0}' | '\u{D7}' | '\u{D8}'
+		| '\u{DE}'..='\u{E1}'
+		| '\u{E6}' | '\u{E8}' | '\u{E9}' | '\u{EA}' | '\u{EC}' | '\u{ED}' | '\u{F0}'
+		| '\u{F2}' | '\u{F3}'
+		| '\u{F7}'..='\u{FA}'
+		| '\u{FC}' | '\u{FE}'
+		// Greek and Cyrillic
+		| '\u{391}'..='\u{3A9}'
+		| '\u{3B1}'..='\u{3C9}'
+		| '\u{401}'
+		| '\u{410}'..='\u{44F}'
+		| '\u{451}'
+		// General punctuation
+		| '\u{2010}' | '\u{2013}'..='\u{2016}' | '\u{2018}' | '\u{2019}' | '\u{201C}'
+		| '\u{201D}' | '\u{2020}'..='\u{2022}' | '\u{2024}'..='\u{2027}' | '\u{2030}'
+		| '\u{2032}' | '\u{2033}' | '\u{2035}' | '\u{203B}' | '\u{203E}'
+		// Letterlike symbols, number forms and roman numerals
+		| '\u{2103}' | '\u{2105}' | '\u{2109}' | '\u{2113}' | '\u{2116}' | '\u{2121}'
+		| '\u{2122}' | '\u{2126}' | '\u{212B}'
+		| '\u{2153}' | '\u{2154}'
+		| '\u{215B}'..='\u{215E}'
+		| '\u{2160}'..='\u{216B}'
+		| '\u{2170}'..='\u{2179}'
+		| '\u{2189}'
+		// Arrows and math operators
+		| '\u{2190}'..='\u{2199}'
+		| '\u{21D2}' | '\u{21D4}' | '\u{2200}' | '\u{2202}' | '\u{2203}' | '\u{2207}'
+		| '\u{2208}' | '\u{220B}' | '\u{220F}' | '\u{2211}' | '\u{2215}' | '\u{221A}'
+		| '\u{221D}'..='\u{2220}'
+		| '\u{2223}' | '\u{2225}'
+		| '\u{2227}'..='\u{222C}'
+		| '\u{222E}'
+		| '\u{2234}'..='\u{2237}'
+		| '\u{223C}' | '\u{223D}' | '\u{2248}' | '\u{224C}' | '\u{2252}' | '\u{2260}'
+		| '\u{2261}'
+		| '\u{2264}'..='\u{2267}'
+		| '\u{226A}' | '\u{226B}' | '\u{226E}' | '\u{226F}' | '\u{2282}' | '\u{2283}'
+		| '\u{2286}' | '\u{2287}' | '\u{2295}' | '\u{2299}' | '\u{22A5}' | '\u{22BF}'
+		| '\u{2312}'
+		// Enclosed alphanumerics, box drawing and block elements
+		| '\u{2460}'..='\u{24E9}'
+		| '\u{24EB}'..='\u{254B}'
+		| '\u{2550}'..='\u{2573}'
+		| '\u{2580}'..='\u{258F}'
+		| '\u{2592}'..='\u{2595}'
+		// Geometric shapes and miscellaneous symbols
+		| '\u{25A0}' | '\u{25A1}'
+		| '\u{25A3}'..='\u{25A9}'
+		| '\u{25B2}' | '\u{25B3}' | '\u{25B6}' | '\u{25B7}' | '\u{25BC}' | '\u{25BD}'
+		| '\u{25C0}' | '\u{25C1}'
+		| '\u{25C6}'..='\u{25C8}'
+		| '\u{25CB}'
+		| '\u{25CE}'..='\u{25D1}'
+		| '\u{25E2}'..='\u{25E5}'
+		| '\u{25EF}' | '\u{2605}' | '\u{2606}' | '\u{2609}' | '\u{260E}' | '\u{260F}'
+		| '\u{261C}' | '\u{261E}' | '\u{2640}' | '\u{2642}' | '\u{2660}' | '\u{2661}'
+		| '\u{2663}'..='\u{2665}'
+		| '\u{2667}'..='\u{266A}'
+		| '\u{266C}' | '\u{266D}' | '\u{266F}' | '\u{273D}'
+		| '\u{2776}'..='\u{277F}'
+		| '\u{2B56}'..='\u{2B59}'
+		// Replacement character
+		| '\u{FFFD}'
+	)
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_display_width() {
+		assert_eq!(display_width(""), 0);
+		assert_eq!(display_width("abc 123"), 7);
+		assert_eq!(display_width("ひらがなカタカナ漢字"), 20);
+		assert_eq!(display_width("ｶﾀｶﾅｰﾞ"), 6);
+		assert_eq!(display_width("ＡＢＣ　１２３"), 14);
+		assert_eq!(display_width("か\u{3099}"), 2);
+		assert_eq!(display_width("葛\u{E0100}"), 2);
+		assert_eq!(display_width("\t\n\u{200B}"), 0);
+		assert_eq!(display_width("😀𠀀"), 4);
+		assert_eq!(display_width("é○§Ω"), 4);
+
+		let options = WidthOptions {
+			ambiguous_wide: true,
+		};
+		assert_eq!(display_width_with("é○§Ω", &options), 8);
+		assert_eq!(display_width_with("abcかな", &options), 7);
+	}
+
+	#[test]
+	fn test_truncate_and_pad() {
+		assert_eq!(truncate_to_width("", 5), "");
+		assert_eq!(truncate_to_width("かな", 0), "");
+		assert_eq!(truncate_to_width("かな", 1), "");
+		assert_eq!(truncate_to_width("かな", 3), "か");
+		assert_eq!(truncate_to_width("かな", 4), "かな");
+		assert_eq!(truncate_to_width("ｶﾞｷﾞ", 3), "ｶﾞｷ");
+		assert_eq!(truncate_to_width("か\u{3099}き", 2), "か\u{3099}");
+		assert_eq!(truncate_to_width("a○b", 2), "a○");
+
+		let options = WidthOptions {
+			ambiguous_wide: true,
+		};
+		assert_eq!(truncate_to_width_with("a○b", 2, &options), "a");
+
+		assert_eq!(ellipsize_to_width("", 0, "…"), "");
+		assert_eq!(ellipsize_to_width("かな", 4, "…"), "かな");
+		assert_eq!(ellipsize_to_width("かなかな", 4, "…"), "か…");
+		assert_eq!(ellipsize_to_width("かなかな", 5, "…"), "かな…");
+		assert_eq!(ellipsize_to_width("かなかな", 2, "..."), "..");
+		assert_eq!(ellipsize_to_width_with("かなかな", 4, "…", &options), "か…");
+		assert_eq!(ellipsize_to_width_with("かなかな", 3, "…", &options), "…");
+
+		assert_eq!(pad_to_width("", 2, TextAlign::Left), "  ");
+		assert_eq!(pad_to_width("かな", 5, TextAlign::Right), " かな");
+		assert_eq!(pad_to_width("かな", 5, TextAlign::Center), "かな ");
+		assert_eq!(pad_to_width("かな", 4, TextAlign::Center), "かな");
+		assert_eq!(pad_to_width("かなかな", 2, TextAlign::Left), "かなかな");
+		assert_eq!(pad_to_width_with("○", 2, TextAlign::Left, &options), "○");
+	}
+}
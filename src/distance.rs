@@ -0,0 +1,337 @@
+//! Edit distance between kana strings.
+//!
+//! The distance is computed over morae instead of characters, so `きょ` to
+//! `きゃ` is a single substitution. Substitutions between similar morae (e.g.
+//! `か` and `が`) can be given a lower cost than unrelated ones.
+
+use super::fold::fold_for_search;
+use super::gojuon::kana_vowel;
+use super::mora::*;
+
+/// Costs for each edit operation of [mora_edit_distance_with].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MoraEditCosts {
+	/// Cost of inserting a mora.
+	pub insertion: f64,
+	/// Cost of deleting a mora.
+	pub deletion: f64,
+	/// Cost of replacing a mora by an unrelated mora.
+	pub substitution: f64,
+	/// Cost of replacing a mora by its voiced or unvoiced counterpart (e.g.
+	/// `か` and `が`, or `は` and `ぱ`).
+	pub voicing: f64,
+	/// Cost of replacing a small kana by its full size counterpart (e.g. `つ`
+	/// and `っ`).
+	pub small_kana: f64,
+	/// Cost of replacing between the spellings of a long vowel, such as the
+	/// long vowel mark and the vowel it stands for (e.g. `こー` and `こう`)
+	/// or two vowels (e.g. `こう` and `こお`).
+	///
+	/// Set this to `0.0` to ignore the differences in the spelling of the
+	/// long vowels.
+	pub long_vowel: f64,
+}
+
+impl Default for MoraEditCosts {
+	/// Costs of `1.0` for insertions, deletions and unrelated substitutions,
+	/// and `0.5` for the similar substitutions.
+	fn default() -> MoraEditCosts {
+		MoraEditCosts {
+			insertion: 1.0,
+			deletion: 1.0,
+			substitution: 1.0,
+			voicing: 0.5,
+			small_kana: 0.5,
+			long_vowel: 0.5,
+		}
+	}
+}
+
+/// Returns the edit distance between two strings, measured in morae, using
+/// the default [MoraEditCosts].
+///
+/// The strings are compared with the [search fold](fn.fold_for_search.html)
+/// applied, so differences in script (hiragana and katakana), width and case
+/// do not count towards the distance.
+///
+/// ```
+/// assert_eq!(kana::mora_edit_distance("とうきょう", "トーキョー"), 1.0);
+/// assert_eq!(kana::mora_edit_distance("きょう", "きゅう"), 1.0);
+/// assert_eq!(kana::mora_edit_distance("かき", "がき"), 0.5);
+/// ```
+pub fn mora_edit_distance<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> f64 {
+	mora_edit_distance_with(a, b, &MoraEditCosts::default())
+}
+
+/// Same as [mora_edit_distance] but using the given costs.
+///
+/// ```
+/// use kana::*;
+///
+/// let costs = MoraEditCosts {
+///     long_vowel: 0.0,
+///     ..MoraEditCosts::default()
+/// };
+/// assert_eq!(mora_edit_distance_with("ラーメン", "らあめん", &costs), 0.0);
+/// assert_eq!(mora_edit_distance_with("ｺｰﾋｰ", "こおひい", &costs), 0.0);
+/// assert_eq!(mora_edit_distance_with("ラーメン", "らいめん", &costs), 1.0);
+/// ```
+pub fn mora_edit_distance_with<A: AsRef<str>, B: AsRef<str>>(
+	a: A,
+	b: B,
+	costs: &MoraEditCosts,
+) -> f64 {
+	let a = fold_for_search(a);
+	let b = fold_for_search(b);
+	let a = Morae::new(&a).collect::<Vec<_>>();
+	let b = Morae::new(&b).collect::<Vec<_>>();
+	distance(&a, &long_vowels(&a), &b, &long_vowels(&b), costs)
+}
+
+/// A candidate matched by [fuzzy_match].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FuzzyMatch {
+	/// Index of the candidate in the candidates given.
+	pub index: usize,
+	/// Edit distance between the query and the candidate, as given by
+	/// [mora_edit_distance].
+	pub distance: f64,
+	/// Similarity between the query and the candidate, from `0.0` to `1.0`
+	/// (identical), which is the distance relative to the number of morae in
+	/// the longest of both.
+	pub score: f64,
+}
+
+/// Returns the candidates within `max_distance` of the query, as given by
+/// [mora_edit_distance], ranked from the closest match.
+///
+/// Candidates with the same distance are kept in the given order.
+///
+/// ```
+/// let words = ["ラーメン", "うどん", "らあめん", "ラーメン屋"];
+/// let matches = kana::fuzzy_match("らーめん", &words, 1.0);
+/// let matches = matches.iter().map(|x| words[x.index]).collect::<Vec<_>>();
+/// assert_eq!(matches, vec!["ラーメン", "らあめん", "ラーメン屋"]);
+/// ```
+pub fn fuzzy_match<Q, I, S>(query: Q, candidates: I, max_distance: f64) -> Vec<FuzzyMatch>
+where
+	Q: AsRef<str>,
+	I: IntoIterator<Item = S>,
+	S: AsRef<str>,
+{
+	fuzzy_match_with(query, candidates, max_distance, &MoraEditCosts::default())
+}
+
+/// Same as [fuzzy_match] but using the given costs.
+pub fn fuzzy_match_with<Q, I, S>(
+	query: Q,
+	candidates: I,
+	max_distance: f64,
+	costs: &MoraEditCosts,
+) -> Vec<FuzzyMatch>
+where
+	Q: AsRef<str>,
+	I: IntoIterator<Item = S>,
+	S: AsRef<str>,
+{
+	let query = fold_for_search(query);
+	let query = Morae::new(&query).collect::<Vec<_>>();
+	let long_query = long_vowels(&query);
+
+	let mut matches = Vec::new();
+	for (index, candidate) in candidates.into_iter().enumerate() {
+		let candidate = fold_for_search(candidate);
+		let candidate = Morae::new(&candidate).collect::<Vec<_>>();
+		let long = long_vowels(&candidate);
+		let distance = distance(&query, &long_query, &candidate, &long, costs);
+		if distance <= max_distance {
+			let len = query.len().max(candidate.len());
+			let score = if len > 0 {
+				(1.0 - distance / len as f64).max(0.0)
+			} else {
+				1.0
+			};
+			matches.push(FuzzyMatch {
+				index,
+				distance,
+				score,
+			});
+		}
+	}
+	matches.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+	matches
+}
+
+/// Returns the edit distance between the (folded) morae, given which of
+/// those are [long vowels](long_vowels).
+fn distance(
+	a: &[&str],
+	long_a: &[bool],
+	b: &[&str],
+	long_b: &[bool],
+	costs: &MoraEditCosts,
+) -> f64 {
+	// Standard dynamic programming over two rows of the distance matrix.
+	let mut last = (0..=b.len())
+		.map(|j| j as f64 * costs.insertion)
+		.collect::<Vec<_>>();
+	let mut next = vec![0.0; b.len() + 1];
+	for (i, mora_a) in a.iter().enumerate() {
+		next[0] = (i + 1) as f64 * costs.deletion;
+		for (j, mora_b) in b.iter().enumerate() {
+			let long = long_a[i] && long_b[j];
+			let substitution = last[j] + substitution_cost(mora_a, mora_b, long, costs);
+			let deletion = last[j + 1] + costs.deletion;
+			let insertion = next[j] + costs.insertion;
+			next[j + 1] = substitution.min(deletion).min(insertion);
+		}
+		std::mem::swap(&mut last, &mut next);
+	}
+	last[b.len()]
+}
+
+/// Returns, for each of the (folded) morae, whether it extends the vowel of
+/// the previous mora (e.g. the `ー` and `う` in `こーこう`).
+pub(crate) fn long_vowels(morae: &[&str]) -> Vec<bool> {
+	let mut vowel = None;
+	morae
+		.iter()
+		.map(|&mora| {
+			let long = matches!(
+				(vowel, mora),
+				(Some(_), "ー")
+					| (Some('a'), "あ")
+					| (Some('i'), "い")
+					| (Some('u'), "う")
+					| (Some('e'), "い")
+					| (Some('e'), "え")
+					| (Some('o'), "う")
+					| (Some('o'), "お")
+			);
+			if !long {
+				vowel = mora.chars().last().and_then(kana_vowel);
+			}
+			long
+		})
+		.collect()
+}
+
+/// Returns the cost of replacing one (folded) mora by the other, where `long`
+/// is true if both extend the vowel of their previous mora.
+fn substitution_cost(a: &str, b: &str, long: bool, costs: &MoraEditCosts) -> f64 {
+	if a == b {
+		return 0.0;
+	}
+
+	if long {
+		return costs.long_vowel.min(costs.substitution);
+	}
+
+	if a.chars().count() != b.chars().count() {
+		return costs.substitution;
+	}
+
+	// Morae of the same length are similar if each of their characters
+	// differ only by voicing or size.
+	let mut cost = 0.0_f64;
+	for (chr_a, chr_b) in a.chars().zip(b.chars()) {
+		if chr_a == chr_b {
+			continue;
+		} else if unvoiced(chr_a) == unvoiced(chr_b) {
+			cost = cost.max(costs.voicing);
+		} else if full_size(chr_a) == full_size(chr_b) {
+			cost = cost.max(costs.small_kana);
+		} else {
+			return costs.substitution;
+		}
+	}
+	cost.min(costs.substitution)
+}
+
+/// Returns the unvoiced counterpart of a hiragana.
+fn unvoiced(chr: char) -> char {
+	const VOICED: &str = "がぎぐげござじずぜぞだぢづでどばびぶべぼぱぴぷぺぽゔ";
+	const UNVOICED: &str = "かきくけこさしすせそたちつてとはひふへほはひふへほう";
+	match VOICED.chars().position(|x| x == chr) {
+		Some(index) => UNVOICED.chars().nth(index).unwrap(),
+		None => chr,
+	}
+}
+
+/// Returns the full size counterpart of a small hiragana.
+fn full_size(chr: char) -> char {
+	const SMALL: &str = "ぁぃぅぇぉっゃゅょゎゕゖ";
+	const FULL: &str = "あいうえおつやゆよわかけ";
+	match SMALL.chars().position(|x| x == chr) {
+		Some(index) => FULL.chars().nth(index).unwrap(),
+		None => chr,
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_mora_edit_distance() {
+		assert_eq!(mora_edit_distance("", ""), 0.0);
+		assert_eq!(mora_edit_distance("", "きょう"), 2.0);
+		assert_eq!(mora_edit_distance("ラーメン", "らーめん"), 0.0);
+		assert_eq!(mora_edit_distance("きって", "きつて"), 0.5);
+		assert_eq!(mora_edit_distance("ぎゃく", "きゃく"), 0.5);
+		assert_eq!(mora_edit_distance("はん", "ぱん"), 0.5);
+		assert_eq!(mora_edit_distance("コーヒー", "こうひい"), 1.0);
+		assert_eq!(mora_edit_distance("ねこ", "いぬ"), 2.0);
+		assert_eq!(mora_edit_distance("ねこ", "ねこだ"), 1.0);
+		assert_eq!(mora_edit_distance("東京", "東北"), 1.0);
+		assert_eq!(mora_edit_distance("ｶﾞｯｺｳ", "がっこう"), 0.0);
+		assert_eq!(mora_edit_distance("ＡＢＣ", "abc"), 0.0);
+		assert_eq!(mora_edit_distance("とおい", "とうい"), 0.5);
+		assert_eq!(mora_edit_distance("せんせい", "せんせえ"), 0.5);
+		assert_eq!(mora_edit_distance("かあ", "かい"), 1.0);
+		assert_eq!(mora_edit_distance("かー", "かい"), 1.0);
+
+		let costs = MoraEditCosts {
+			insertion: 2.0,
+			deletion: 3.0,
+			substitution: 1.5,
+			voicing: 0.1,
+			small_kana: 0.2,
+			long_vowel: 5.0,
+		};
+		assert_eq!(mora_edit_distance_with("か", "が", &costs), 0.1);
+		assert_eq!(mora_edit_distance_with("や", "ゃ", &costs), 0.2);
+		assert_eq!(mora_edit_distance_with("ー", "う", &costs), 1.5);
+		assert_eq!(mora_edit_distance_with("ねこ", "ねこだ", &costs), 2.0);
+		assert_eq!(mora_edit_distance_with("ねこだ", "ねこ", &costs), 3.0);
+	}
+
+	#[test]
+	fn test_fuzzy_match() {
+		let empty: &[&str] = &[];
+		assert!(fuzzy_match("ねこ", empty, 1.0).is_empty());
+
+		let words = ["いぬ", "ネコ", "ねこだ", "", "ｶﾞｯｺｳ", "ねこ"];
+		let matches = fuzzy_match("ねこ", words, 1.0);
+		let result = matches
+			.iter()
+			.map(|x| (x.index, x.distance, x.score))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			result,
+			vec![(1, 0.0, 1.0), (5, 0.0, 1.0), (2, 1.0, 1.0 - 1.0 / 3.0)]
+		);
+
+		let matches = fuzzy_match("", words, 0.0);
+		assert_eq!(matches.len(), 1);
+		assert_eq!(matches[0].index, 3);
+		assert_eq!(matches[0].score, 1.0);
+
+		let matches = fuzzy_match("かっこう", words.iter(), 0.5);
+		assert_eq!(matches.len(), 1);
+		assert_eq!((matches[0].index, matches[0].distance), (4, 0.5));
+		assert_eq!(matches[0].score, 0.875);
+	}
+}
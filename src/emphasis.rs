@@ -0,0 +1,147 @@
+//! Katakana emphasis of hiragana text.
+//!
+//! Writing a word in katakana is commonly used in Japanese to emphasize it,
+//! similar to the use of italics in English.
+
+use std::ops::Range;
+
+use super::is::*;
+use super::util::*;
+
+/// Returns true if the character is part of a hiragana word.
+fn is_word_char(chr: char) -> bool {
+	is_hiragana(chr) || chr == 'ゝ' || chr == 'ゞ' || chr == 'ー'
+}
+
+/// Iterates over the hiragana words in the input, as the byte range of each
+/// word. A word is any sequence of hiragana, including iteration marks and the
+/// prolonged sound mark.
+fn hiragana_words(input: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+	let mut chars = input.char_indices().peekable();
+	std::iter::from_fn(move || {
+		let start = loop {
+			match chars.next() {
+				Some((pos, chr)) if is_word_char(chr) => break pos,
+				Some(_) => continue,
+				None => return None,
+			}
+		};
+		let mut end = input.len();
+		while let Some(&(pos, chr)) = chars.peek() {
+			if !is_word_char(chr) {
+				end = pos;
+				break;
+			}
+			chars.next();
+		}
+		Some(start..end)
+	})
+}
+
+/// Converts the hiragana words in the input selected by the predicate into
+/// katakana, preserving everything else.
+///
+/// The predicate is called with each word, which is any sequence of hiragana
+/// in the input (including iteration marks and `ー`).
+///
+/// ```
+/// let text = kana::to_katakana_emphasis("本当に、すごい！", |word| word == "すごい");
+/// assert_eq!(text, "本当に、スゴイ！");
+/// ```
+pub fn to_katakana_emphasis<S: AsRef<str>, F: FnMut(&str) -> bool>(
+	input: S,
+	mut predicate: F,
+) -> String {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	let mut last = 0;
+	for word in hiragana_words(input) {
+		let text = &input[word.clone()];
+		if predicate(text) {
+			out.push_str(&input[last..word.start]);
+			out.extend(text.chars().map(hiragana_to_katakana));
+			last = word.end;
+		}
+	}
+	out.push_str(&input[last..]);
+	out
+}
+
+/// Converts the hiragana in the given byte ranges of the input into katakana,
+/// preserving everything else.
+///
+/// Ranges may overlap and be given in any order. Range boundaries that are
+/// not at a character boundary include the whole character.
+pub fn to_katakana_emphasis_ranges<S: AsRef<str>>(input: S, ranges: &[Range<usize>]) -> String {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	for (pos, chr) in input.char_indices() {
+		let end = pos + chr.len_utf8();
+		let selected = ranges.iter().any(|x| x.start < end && pos < x.end);
+		if selected && is_word_char(chr) {
+			out.push(hiragana_to_katakana(chr));
+		} else {
+			out.push(chr);
+		}
+	}
+	out
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_to_katakana_emphasis() {
+		assert_eq!(to_katakana_emphasis("", |_| true), "");
+		assert_eq!(to_katakana_emphasis("ひらがな", |_| true), "ヒラガナ");
+		assert_eq!(to_katakana_emphasis("ひらがな", |_| false), "ひらがな");
+		assert_eq!(
+			to_katakana_emphasis("ほんとうにすごいよ、すごい！", |x| x
+				== "すごい"),
+			"ほんとうにすごいよ、スゴイ！"
+		);
+		assert_eq!(
+			to_katakana_emphasis("ほんとうに すごい！", |x| x.starts_with("すご")),
+			"ほんとうに スゴイ！"
+		);
+		assert_eq!(
+			to_katakana_emphasis("漢字とかな、カタカナとabc", |_| true),
+			"漢字トカナ、カタカナトabc"
+		);
+		assert_eq!(
+			to_katakana_emphasis("らーめん と こゝろ", |x| x != "と"),
+			"ラーメン と コヽロ"
+		);
+
+		let mut words = Vec::new();
+		to_katakana_emphasis("日本ごのかなと漢字", |x| {
+			words.push(x.to_string());
+			false
+		});
+		assert_eq!(words, vec!["ごのかなと"]);
+	}
+
+	#[test]
+	fn test_to_katakana_emphasis_ranges() {
+		assert_eq!(to_katakana_emphasis_ranges("ひらがな", &[]), "ひらがな");
+		assert_eq!(
+			to_katakana_emphasis_ranges("ひらがな", &[0..6, 6..12]),
+			"ヒラガナ"
+		);
+		assert_eq!(
+			to_katakana_emphasis_ranges("ひらがな", &[6..12, 0..3]),
+			"ヒらガナ"
+		);
+		assert_eq!(
+			to_katakana_emphasis_ranges("ひらがな", &[4..5, 20..30]),
+			"ひラがな"
+		);
+		assert_eq!(
+			to_katakana_emphasis_ranges("a漢字かな", &[0..1, 1..13]),
+			"a漢字カナ"
+		);
+	}
+}
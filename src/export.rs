@@ -0,0 +1,172 @@
+//! Export of the conversion tables in machine-readable formats.
+
+use std::fmt::Write;
+
+use super::table::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Output format for [export_tables].
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableFormat {
+	/// A JSON object with one object per table, mapping each key to its
+	/// value:
+	///
+	/// ```text
+	/// {"to_hiragana":{"A":"あ",...},"to_katakana":{...},"to_romaji":{...}}
+	/// ```
+	Json,
+
+	/// CSV with a header and one `table,key,value` row per table entry. Fields
+	/// are quoted as described in RFC 4180 when required.
+	Csv,
+}
+
+/// Returns the complete tables used by the conversion functions in the given
+/// format.
+///
+/// The tables include all the generated variants of the keys used by the
+/// conversion (e.g. the upper case romaji and katakana keys). Entries in each
+/// table are sorted by key.
+///
+/// The exported tables are:
+///
+/// - `to_hiragana`: romaji and punctuation to hiragana.
+/// - `to_katakana`: romaji and punctuation to katakana.
+/// - `to_romaji`: hiragana, katakana and punctuation to romaji.
+///
+/// Note that the conversion functions also apply rules that are not part of
+/// the tables, such as double consonants and iteration marks.
+pub fn export_tables(format: TableFormat) -> String {
+	let tables = [
+		("to_hiragana", sorted_entries(&TO_HIRAGANA)),
+		("to_katakana", sorted_entries(&TO_KATAKANA)),
+		("to_romaji", sorted_entries(&TO_ROMAJI)),
+	];
+
+	let mut out = String::new();
+	match format {
+		TableFormat::Json => {
+			out.push('{');
+			for (index, (name, entries)) in tables.iter().enumerate() {
+				if index > 0 {
+					out.push(',');
+				}
+				push_json_str(&mut out, name);
+				out.push_str(":{");
+				for (index, (key, value)) in entries.iter().enumerate() {
+					if index > 0 {
+						out.push(',');
+					}
+					push_json_str(&mut out, key);
+					out.push(':');
+					push_json_str(&mut out, value);
+				}
+				out.push('}');
+			}
+			out.push('}');
+		}
+		TableFormat::Csv => {
+			out.push_str("table,key,value\r\n");
+			for (name, entries) in tables.iter() {
+				for (key, value) in entries.iter() {
+					out.push_str(name);
+					out.push(',');
+					push_csv_field(&mut out, key);
+					out.push(',');
+					push_csv_field(&mut out, value);
+					out.push_str("\r\n");
+				}
+			}
+		}
+	}
+	out
+}
+
+/// Returns the entries of the table sorted by key.
+fn sorted_entries<'a, V: AsRef<str>>(
+	table: &'a fnv::FnvHashMap<&'static str, V>,
+) -> Vec<(&'static str, &'a str)> {
+	let mut entries = table
+		.iter()
+		.map(|(&key, value)| (key, value.as_ref()))
+		.collect::<Vec<_>>();
+	entries.sort();
+	entries
+}
+
+pub(crate) fn push_json_str(out: &mut String, text: &str) {
+	out.push('"');
+	for chr in text.chars() {
+		match chr {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			'\u{0}'..='\u{1F}' => {
+				let _ = write!(out, "\\u{:04x}", chr as u32);
+			}
+			_ => out.push(chr),
+		}
+	}
+	out.push('"');
+}
+
+fn push_csv_field(out: &mut String, text: &str) {
+	let quote = text.is_empty()
+		|| text.starts_with(' ')
+		|| text.ends_with(' ')
+		|| text.contains(&[',', '"', '\r', '\n'][..]);
+	if quote {
+		out.push('"');
+		out.push_str(&text.replace('"', "\"\""));
+		out.push('"');
+	} else {
+		out.push_str(text);
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_export_tables_json() {
+		let json = export_tables(TableFormat::Json);
+		assert!(json.starts_with("{\"to_hiragana\":{"));
+		assert!(json.ends_with("}}"));
+		assert!(json.contains("\"to_katakana\":{"));
+		assert!(json.contains("\"to_romaji\":{"));
+		assert!(json.contains("\"kya\":\"きゃ\""));
+		assert!(json.contains("\"KyA\":\"キャ\""));
+		assert!(json.contains("\"キャ\":\"kya\""));
+		assert!(json.contains("\": \":\"："));
+
+		let mut out = String::new();
+		push_json_str(&mut out, "a\"b\\c\n\u{1}");
+		assert_eq!(out, "\"a\\\"b\\\\c\\n\\u0001\"");
+	}
+
+	#[test]
+	fn test_export_tables_csv() {
+		let csv = export_tables(TableFormat::Csv);
+		let lines = csv.split("\r\n").collect::<Vec<_>>();
+		assert_eq!(lines[0], "table,key,value");
+		assert_eq!(lines.last(), Some(&""));
+		assert_eq!(lines.len(), TO_HIRAGANA.len() * 2 + TO_ROMAJI.len() + 2);
+		assert!(lines.contains(&"to_hiragana,kya,きゃ"));
+		assert!(lines.contains(&"to_katakana,kya,キャ"));
+		assert!(lines.contains(&"to_romaji,キャ,kya"));
+		assert!(lines.contains(&"to_hiragana,\",\",、"));
+		assert!(lines.contains(&"to_hiragana,\": \",："));
+
+		let mut out = String::new();
+		push_csv_field(&mut out, "a\"b");
+		assert_eq!(out, "\"a\"\"b\"");
+	}
+}
@@ -0,0 +1,233 @@
+//! Extraction and cleanup of the Japanese text in mixed input, such as
+//! source code, logs and scraped pages.
+
+use std::ops::Range;
+
+use super::is::*;
+
+/// Options for [extract_japanese_with].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ExtractOptions {
+	/// Include the Japanese punctuation (e.g. `「`, `。` and the U+3000
+	/// Ideographic Space) in the segments, instead of splitting on them.
+	///
+	/// Segments must still contain at least one letter or mark.
+	pub include_punctuation: bool,
+}
+
+/// Returns an iterator over the maximal segments of Japanese letters and
+/// marks (e.g. `ー` and `々`) in the input, with their byte range.
+///
+/// ```
+/// let input = r#"log("エラー: ファイルが見つかりません")"#;
+/// let segments = kana::extract_japanese(input).collect::<Vec<_>>();
+/// assert_eq!(segments, vec![
+///     (5..14, "エラー"),
+///     (16..52, "ファイルが見つかりません"),
+/// ]);
+/// ```
+pub fn extract_japanese<'a>(input: &'a str) -> JapaneseSegments<'a> {
+	extract_japanese_with(input, &ExtractOptions::default())
+}
+
+/// Same as [extract_japanese] but with the given options.
+///
+/// ```
+/// let options = kana::ExtractOptions {
+///     include_punctuation: true,
+/// };
+/// let segments = kana::extract_japanese_with("msg = 「はい、いいえ」;", &options)
+///     .map(|x| x.1)
+///     .collect::<Vec<_>>();
+/// assert_eq!(segments, vec!["「はい、いいえ」"]);
+/// ```
+pub fn extract_japanese_with<'a>(input: &'a str, options: &ExtractOptions) -> JapaneseSegments<'a> {
+	JapaneseSegments {
+		input,
+		offset: 0,
+		options: *options,
+	}
+}
+
+/// Iterator returned by [extract_japanese] and [extract_japanese_with].
+#[derive(Clone, Debug)]
+pub struct JapaneseSegments<'a> {
+	input: &'a str,
+	offset: usize,
+	options: ExtractOptions,
+}
+
+impl<'a> JapaneseSegments<'a> {
+	fn is_letter(chr: char) -> bool {
+		is_letter(chr) || is_japanese_mark(chr)
+	}
+
+	fn is_included(&self, chr: char) -> bool {
+		Self::is_letter(chr) || (self.options.include_punctuation && is_japanese_punctuation(chr))
+	}
+}
+
+impl<'a> Iterator for JapaneseSegments<'a> {
+	type Item = (Range<usize>, &'a str);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.offset < self.input.len() {
+			let text = &self.input[self.offset..];
+			let start = match text.find(|chr| self.is_included(chr)) {
+				Some(start) => self.offset + start,
+				None => break,
+			};
+			let end = match self.input[start..].find(|chr| !self.is_included(chr)) {
+				Some(end) => start + end,
+				None => self.input.len(),
+			};
+			self.offset = end;
+
+			let segment = &self.input[start..end];
+			if segment.chars().any(Self::is_letter) {
+				return Some((start..end, segment));
+			}
+		}
+		self.offset = self.input.len();
+		None
+	}
+}
+
+/// Options for [strip_non_japanese_with].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StripOptions {
+	/// Keep the ASCII digits.
+	pub keep_digits: bool,
+	/// Keep whitespace. The U+3000 Ideographic Space is always kept, since it
+	/// is Japanese punctuation.
+	pub keep_spaces: bool,
+	/// Replace each run of removed characters by this character, instead of
+	/// removing them.
+	pub replace_with: Option<char>,
+}
+
+/// Removes everything that is not a Japanese letter, mark or punctuation from
+/// the input.
+///
+/// ```
+/// assert_eq!(kana::strip_non_japanese("<p>日本語、OK!</p>"), "日本語、");
+/// ```
+pub fn strip_non_japanese<S: AsRef<str>>(input: S) -> String {
+	strip_non_japanese_with(input, &StripOptions::default())
+}
+
+/// Same as [strip_non_japanese] but with the given options.
+///
+/// ```
+/// let options = kana::StripOptions {
+///     keep_digits: true,
+///     keep_spaces: true,
+///     replace_with: Some('_'),
+/// };
+/// assert_eq!(
+///     kana::strip_non_japanese_with("<b>2020年</b> の夏", &options),
+///     "_2020年_ の夏"
+/// );
+/// ```
+pub fn strip_non_japanese_with<S: AsRef<str>>(input: S, options: &StripOptions) -> String {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	let mut removed = false;
+	for chr in input.chars() {
+		let keep = is_letter(chr)
+			|| is_japanese_mark(chr)
+			|| is_japanese_punctuation(chr)
+			|| (options.keep_digits && chr.is_ascii_digit())
+			|| (options.keep_spaces && is_space(chr));
+		if keep {
+			if let (true, Some(replacement)) = (removed, options.replace_with) {
+				out.push(replacement);
+			}
+			out.push(chr);
+			removed = false;
+		} else {
+			removed = true;
+		}
+	}
+	if let (true, Some(replacement)) = (removed, options.replace_with) {
+		out.push(replacement);
+	}
+	out
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn extract(input: &str, include_punctuation: bool) -> Vec<&str> {
+		let options = ExtractOptions {
+			include_punctuation,
+		};
+		extract_japanese_with(input, &options)
+			.map(|(range, text)| {
+				assert_eq!(&input[range], text);
+				text
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_extract_japanese() {
+		assert!(extract("", false).is_empty());
+		assert!(extract("no japanese here", false).is_empty());
+		assert_eq!(extract("漢字", false), vec!["漢字"]);
+		assert_eq!(extract("a漢字bかなcｶﾅ", false), vec!["漢字", "かな", "ｶﾅ"]);
+		assert_eq!(extract("人々 ラーメン", false), vec!["人々", "ラーメン"]);
+		assert_eq!(extract("「はい、いいえ」", false), vec!["はい", "いいえ"]);
+		assert_eq!(extract("한국어 ㄅ 😀", false), Vec::<&str>::new());
+
+		assert_eq!(extract("「はい、いいえ」", true), vec!["「はい、いいえ」"]);
+		assert_eq!(
+			extract("「」。 はい　いいえ。", true),
+			vec!["はい　いいえ。"]
+		);
+		assert_eq!(extract("x「」y", true), Vec::<&str>::new());
+	}
+
+	#[test]
+	fn test_strip_non_japanese() {
+		assert_eq!(strip_non_japanese(""), "");
+		assert_eq!(strip_non_japanese("abc 123"), "");
+		assert_eq!(
+			strip_non_japanese("「ｶﾅ」、かな　漢字々ー"),
+			"「ｶﾅ」、かな　漢字々ー"
+		);
+		assert_eq!(strip_non_japanese("1 かな\t2 😀カナ"), "かなカナ");
+
+		let options = StripOptions {
+			keep_digits: true,
+			..StripOptions::default()
+		};
+		assert_eq!(
+			strip_non_japanese_with("1 かな\t2 😀カナ", &options),
+			"1かな2カナ"
+		);
+
+		let options = StripOptions {
+			keep_spaces: true,
+			..StripOptions::default()
+		};
+		assert_eq!(
+			strip_non_japanese_with("1 かな\t2 😀カナ", &options),
+			" かな\t カナ"
+		);
+
+		let options = StripOptions {
+			replace_with: Some(' '),
+			..StripOptions::default()
+		};
+		assert_eq!(
+			strip_non_japanese_with("1 かな\t2 😀カナ", &options),
+			" かな カナ"
+		);
+		assert_eq!(strip_non_japanese_with("かな!", &options), "かな ");
+		assert_eq!(strip_non_japanese_with("!!!", &options), " ");
+	}
+}
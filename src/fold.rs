@@ -0,0 +1,512 @@
+//! Kana folded string comparison.
+//!
+//! Kana folding treats hiragana and katakana as equivalent, such that `ラーメン`
+//! and `らーめん` compare as equal.
+
+use std::borrow::Cow;
+use std::iter::Peekable;
+use std::ops::Range;
+use std::str::CharIndices;
+
+use super::mora::*;
+use super::util::{
+	compose_voiced, half_width_to_katakana, half_width_voiced_to_katakana, katakana_to_hiragana,
+};
+use super::width::halfwidth_ascii_char;
+
+/// Folds a kana character for comparison.
+#[inline]
+fn fold(chr: char) -> char {
+	katakana_to_hiragana(chr)
+}
+
+/// Returns the first character of a non-empty string.
+#[inline]
+fn first_char(text: &str) -> char {
+	text.chars().next().unwrap()
+}
+
+/// Options for [fold_for_search_with], which disable each of the folds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SearchFoldOptions {
+	/// Keep the case of the roman letters.
+	pub keep_case: bool,
+	/// Keep the full-width roman letters, digits and punctuation, and the
+	/// ideographic space.
+	pub keep_fullwidth: bool,
+	/// Keep the halfwidth katakana and the kana followed by a combining
+	/// voiced or semi-voiced mark.
+	pub keep_halfwidth_kana: bool,
+	/// Keep the katakana instead of converting them to hiragana.
+	pub keep_katakana: bool,
+}
+
+/// Iterator over the folded characters of a string.
+#[derive(Clone, Debug)]
+struct FoldChars<'a> {
+	input: &'a str,
+	chars: Peekable<CharIndices<'a>>,
+	options: SearchFoldOptions,
+}
+
+impl<'a> FoldChars<'a> {
+	fn new(input: &'a str, options: &SearchFoldOptions) -> FoldChars<'a> {
+		FoldChars {
+			input,
+			chars: input.char_indices().peekable(),
+			options: *options,
+		}
+	}
+
+	/// Returns the byte offset in the input of the next character.
+	fn offset(&mut self) -> usize {
+		match self.chars.peek() {
+			Some(&(offset, _)) => offset,
+			None => self.input.len(),
+		}
+	}
+
+	/// Consumes the folded characters of the needle, returning false if
+	/// they do not match.
+	fn eat(&mut self, needle: &str) -> bool {
+		let options = self.options;
+		FoldChars::new(needle, &options).all(|chr| self.next() == Some(chr))
+	}
+
+	/// Finds the next match of the needle, returning its byte range in the
+	/// input and moving past it.
+	fn find(&mut self, needle: &str) -> Option<Range<usize>> {
+		loop {
+			let start = self.offset();
+			let mut chars = self.clone();
+			if chars.eat(needle) {
+				*self = chars;
+				return Some(start..self.offset());
+			}
+			self.next()?;
+		}
+	}
+
+	/// Returns the full-width kana for the character, which is combined with
+	/// the following voiced mark (e.g. `ｶﾞ` and `か` with U+3099), if any.
+	fn next_kana(&mut self, chr: char) -> char {
+		let next = self.chars.peek().map(|x| x.1);
+		let voiced = match half_width_to_katakana(chr) {
+			Some(kana) => {
+				let voiced = next.and_then(|mark| half_width_voiced_to_katakana(chr, mark));
+				if voiced.is_none() {
+					return first_char(kana);
+				}
+				voiced
+			}
+			None => next.and_then(|mark| compose_voiced(chr, mark)),
+		};
+		match voiced {
+			Some(voiced) => {
+				self.chars.next();
+				first_char(voiced)
+			}
+			None => chr,
+		}
+	}
+}
+
+impl<'a> Iterator for FoldChars<'a> {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		let (_, mut chr) = self.chars.next()?;
+		let options = self.options;
+		if !options.keep_halfwidth_kana {
+			chr = self.next_kana(chr);
+		}
+		if !options.keep_fullwidth {
+			chr = halfwidth_ascii_char(chr);
+		}
+		if !options.keep_case && chr.is_uppercase() {
+			let mut lower = chr.to_lowercase();
+			if lower.len() == 1 {
+				chr = lower.next().unwrap();
+			}
+		}
+		if !options.keep_katakana {
+			chr = fold(chr);
+		}
+		Some(chr)
+	}
+}
+
+/// Returns true if both strings are equal, comparing using kana folding.
+///
+/// Halfwidth katakana compare equal to the full-width kana, and a kana
+/// followed by a combining voiced or semi-voiced mark compares equal to the
+/// voiced kana. This does not allocate.
+///
+/// ```
+/// assert!(kana::eq_kana("ラーメン", "らーめん"));
+/// assert!(kana::eq_kana("ｶﾞｯｺｳ", "がっこう"));
+/// assert!(!kana::eq_kana("かっこう", "がっこう"));
+/// ```
+pub fn eq_kana<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> bool {
+	let options = SearchFoldOptions {
+		keep_case: true,
+		keep_fullwidth: true,
+		..SearchFoldOptions::default()
+	};
+	FoldChars::new(a.as_ref(), &options).eq(FoldChars::new(b.as_ref(), &options))
+}
+
+/// Folds the input into a canonical key for searching, in a single pass.
+///
+/// This lowercases the roman letters, converts the full-width roman letters,
+/// digits and punctuation to ASCII (as in
+/// [to_halfwidth_ascii](fn.to_halfwidth_ascii.html)), the halfwidth katakana
+/// to full-width (combining the voiced marks) and the katakana to hiragana.
+/// Two strings that fold to the same key compare equal for searching.
+///
+/// ```
+/// assert_eq!(kana::fold_for_search("ＡＢＣ Tokyo ﾗｰﾒﾝ カレー"), "abc tokyo らーめん かれー");
+/// assert_eq!(kana::fold_for_search("ｶﾞｲﾄﾞ"), kana::fold_for_search("がいど"));
+/// ```
+pub fn fold_for_search<S: AsRef<str>>(input: S) -> String {
+	fold_for_search_with(input, &SearchFoldOptions::default())
+}
+
+/// Returns true if the haystack starts with the prefix, comparing with the
+/// [search fold](fold_for_search) applied to both.
+///
+/// This does not allocate. Unlike [starts_with_kana_fold], the prefix can end
+/// in the middle of a mora, as needed to match the input as it is typed.
+///
+/// ```
+/// assert!(kana::starts_with_kana("ラーメン屋", "らーめ"));
+/// assert!(kana::starts_with_kana("ｷｮｳﾄ", "きょ"));
+/// assert!(kana::starts_with_kana("Ｔｏｋｙｏ", "TO"));
+/// assert!(!kana::starts_with_kana("ラーメン", "らめ"));
+/// ```
+pub fn starts_with_kana<A: AsRef<str>, B: AsRef<str>>(haystack: A, prefix: B) -> bool {
+	FoldChars::new(haystack.as_ref(), &SearchFoldOptions::default()).eat(prefix.as_ref())
+}
+
+/// Returns the byte offset of the first match of the needle in the haystack,
+/// comparing with the [search fold](fold_for_search) applied to both.
+///
+/// This does not allocate. Matches never start at a voiced mark combined by
+/// the fold (e.g. the `ﾞ` in `ｶﾞ`).
+///
+/// ```
+/// assert_eq!(kana::find_kana("東京のラーメン", "らーめん"), Some(9));
+/// assert_eq!(kana::find_kana("ｶﾞｲﾄﾞ", "ど"), Some(9));
+/// assert_eq!(kana::find_kana("ラーメン", "うどん"), None);
+/// ```
+pub fn find_kana<A: AsRef<str>, B: AsRef<str>>(haystack: A, needle: B) -> Option<usize> {
+	find_kana_range(haystack, needle).map(|range| range.start)
+}
+
+/// Same as [find_kana] but returns the byte range of the match in the
+/// original haystack, which can have a different length from the needle
+/// (e.g. `ｶﾞ` matching `が`).
+///
+/// ```
+/// let text = "ﾗｰﾒﾝとｶﾞｲﾄﾞ";
+/// let range = kana::find_kana_range(text, "がいど").unwrap();
+/// assert_eq!(&text[range], "ｶﾞｲﾄﾞ");
+/// ```
+pub fn find_kana_range<A: AsRef<str>, B: AsRef<str>>(
+	haystack: A,
+	needle: B,
+) -> Option<Range<usize>> {
+	FoldChars::new(haystack.as_ref(), &SearchFoldOptions::default()).find(needle.as_ref())
+}
+
+/// Returns an iterator over the byte ranges in the original haystack of the
+/// non-overlapping matches of the needle, comparing as in [find_kana].
+///
+/// This is meant for highlighting the matches in the original text. An empty
+/// needle has no matches.
+///
+/// ```
+/// let text = "ラーメンとﾗｰﾒﾝ";
+/// let matches = kana::find_kana_ranges(text, "らーめん")
+///     .map(|range| &text[range])
+///     .collect::<Vec<_>>();
+/// assert_eq!(matches, vec!["ラーメン", "ﾗｰﾒﾝ"]);
+/// ```
+pub fn find_kana_ranges<'a>(haystack: &'a str, needle: &'a str) -> KanaMatches<'a> {
+	KanaMatches {
+		chars: FoldChars::new(haystack, &SearchFoldOptions::default()),
+		needle,
+	}
+}
+
+/// Replaces the matches of the pattern in the haystack, found as in
+/// [find_kana_ranges], by the replacement.
+///
+/// The text outside of the matches is kept unchanged. Returns the haystack
+/// without allocating if there are no matches.
+///
+/// ```
+/// let text = "ラーメンとらーめんとﾗｰﾒﾝ";
+/// assert_eq!(kana::replace_kana(text, "らーめん", "うどん"), "うどんとうどんとうどん");
+/// assert_eq!(kana::replace_kana("ｶﾞｲﾄﾞ", "ど", "ﾄ"), "ｶﾞｲﾄ");
+/// ```
+pub fn replace_kana<'a>(haystack: &'a str, pattern: &str, replacement: &str) -> Cow<'a, str> {
+	let mut out = String::new();
+	let mut last = 0;
+	for range in find_kana_ranges(haystack, pattern) {
+		out.push_str(&haystack[last..range.start]);
+		out.push_str(replacement);
+		last = range.end;
+	}
+	if last == 0 {
+		return Cow::Borrowed(haystack);
+	}
+	out.push_str(&haystack[last..]);
+	Cow::Owned(out)
+}
+
+/// Iterator returned by [find_kana_ranges].
+#[derive(Clone, Debug)]
+pub struct KanaMatches<'a> {
+	chars: FoldChars<'a>,
+	needle: &'a str,
+}
+
+impl<'a> Iterator for KanaMatches<'a> {
+	type Item = Range<usize>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.needle.is_empty() {
+			return None;
+		}
+		self.chars.find(self.needle)
+	}
+}
+
+/// Same as [fold_for_search] but with the given options.
+///
+/// ```
+/// let options = kana::SearchFoldOptions {
+///     keep_katakana: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(kana::fold_for_search_with("ＡＢＣ ﾗｰﾒﾝ", &options), "abc ラーメン");
+/// ```
+pub fn fold_for_search_with<S: AsRef<str>>(input: S, options: &SearchFoldOptions) -> String {
+	FoldChars::new(input.as_ref(), options).collect()
+}
+
+/// Returns true if the input starts with the given prefix, comparing as in
+/// [starts_with_kana].
+///
+/// The prefix must end at a mora boundary of the input, so `きょう` starts with
+/// `キョ` but not with `き`.
+///
+/// ```
+/// assert!(kana::starts_with_kana_fold("ｶﾞｯｺｳ", "が"));
+/// assert!(!kana::starts_with_kana_fold("きょうと", "き"));
+/// ```
+pub fn starts_with_kana_fold<A: AsRef<str>, B: AsRef<str>>(input: A, prefix: B) -> bool {
+	let input = input.as_ref();
+	let mut chars = FoldChars::new(input, &SearchFoldOptions::default());
+	chars.eat(prefix.as_ref()) && is_mora_boundary(input, chars.offset())
+}
+
+/// Returns true if the input ends with the given suffix, comparing as in
+/// [starts_with_kana].
+///
+/// The suffix must start at a mora boundary of the input, so `きょう` ends with
+/// `ョウ` but not with `ょう`.
+///
+/// ```
+/// assert!(kana::ends_with_kana_fold("ﾄｳｷｮｳ", "きょう"));
+/// assert!(!kana::ends_with_kana_fold("ﾄｳｷｮｳ", "ょう"));
+/// ```
+pub fn ends_with_kana_fold<A: AsRef<str>, B: AsRef<str>>(input: A, suffix: B) -> bool {
+	let input = input.as_ref();
+	let suffix = suffix.as_ref();
+	let options = SearchFoldOptions::default();
+	// each folded character takes at most two characters of the input (e.g.
+	// `ｶﾞ` for `が`), so the suffix can only start near the end
+	let max = 2 * suffix.chars().count();
+	let starts = input.char_indices().map(|x| x.0).rev().take(max);
+	Some(input.len()).into_iter().chain(starts).any(|start| {
+		let mut chars = FoldChars::new(&input[start..], &options);
+		chars.eat(suffix) && chars.next().is_none() && is_mora_boundary(input, start)
+	})
+}
+
+/// Returns the number of morae in the common prefix of both strings,
+/// comparing each mora as in [starts_with_kana].
+pub fn common_prefix_morae<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> usize {
+	let options = SearchFoldOptions::default();
+	Morae::new(a.as_ref())
+		.zip(Morae::new(b.as_ref()))
+		.take_while(|(a, b)| FoldChars::new(a, &options).eq(FoldChars::new(b, &options)))
+		.count()
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_eq_kana() {
+		assert!(eq_kana("", ""));
+		assert!(eq_kana("ラーメン", "らーめん"));
+		assert!(eq_kana("ﾗｰﾒﾝ", "ラーメン"));
+		assert!(eq_kana("ﾊﾟﾝとｳﾞｧ", "ぱんとゔぁ"));
+		assert!(eq_kana("か\u{3099}ハ\u{309A}", "がぱ"));
+		assert!(eq_kana("ヽゝ", "ゝヽ"));
+		assert!(eq_kana("東京タワー", "東京たわー"));
+		assert!(!eq_kana("ラーメン", "らーめ"));
+		assert!(!eq_kana("ﾊﾟ", "は"));
+		assert!(!eq_kana("ABC", "abc"));
+		assert!(!eq_kana("ＡＢＣ", "ABC"));
+	}
+
+	#[test]
+	fn test_fold_for_search() {
+		assert_eq!(fold_for_search(""), "");
+		assert_eq!(
+			fold_for_search("ＲＡＭＥＮ　Ramen ＃１ ﾊﾟﾝ ヴァ が\u{3099}"),
+			"ramen ramen #1 ぱん ゔぁ が\u{3099}"
+		);
+		assert_eq!(fold_for_search("ÉCOLE Ōsaka"), "école ōsaka");
+		assert_eq!(fold_for_search("ﾞｶﾞﾞ"), "ﾞがﾞ");
+		assert_eq!(fold_for_search("ワ\u{3099}"), "ヷ");
+
+		let options = SearchFoldOptions {
+			keep_case: true,
+			keep_fullwidth: true,
+			keep_halfwidth_kana: true,
+			keep_katakana: true,
+		};
+		let input = "ＡＢＣ Abc ｶﾞ カ\u{3099}";
+		assert_eq!(fold_for_search_with(input, &options), input);
+	}
+
+	#[test]
+	fn test_starts_with_kana() {
+		assert!(starts_with_kana("", ""));
+		assert!(starts_with_kana("らーめん", ""));
+		assert!(starts_with_kana("らーめん", "ラーメン"));
+		assert!(starts_with_kana("がっこう", "ｶﾞｯ"));
+		assert!(starts_with_kana("ｶﾞｯｺｳ", "が"));
+		assert!(starts_with_kana("ＡＢＣ１", "abc1"));
+		assert!(!starts_with_kana("ｶﾞｯｺｳ", "か"));
+		assert!(!starts_with_kana("らーめん", "らーめんや"));
+		assert!(!starts_with_kana("", "ら"));
+	}
+
+	#[test]
+	fn test_find_kana() {
+		assert_eq!(find_kana("", ""), Some(0));
+		assert_eq!(find_kana("", "あ"), None);
+		assert_eq!(find_kana("あい", ""), Some(0));
+		assert_eq!(find_kana("あいう", "イウ"), Some(3));
+		assert_eq!(find_kana("ｱｲｳ", "いう"), Some(3));
+		assert_eq!(find_kana("ﾊﾞﾊﾟﾊ", "は"), Some(12));
+		assert_eq!(find_kana("ﾊﾞﾊﾟﾊ", "ぱは"), Some(6));
+		assert_eq!(find_kana("Ｒａｍｅｎ and ramen", "RAMEN"), Some(0));
+		assert_eq!(find_kana("ab RAMEN", "ramen"), Some(3));
+		assert_eq!(find_kana("ラーメン", "めんや"), None);
+	}
+
+	#[test]
+	fn test_find_kana_ranges() {
+		fn matches<'a>(text: &'a str, needle: &'a str) -> Vec<&'a str> {
+			find_kana_ranges(text, needle).map(|x| &text[x]).collect()
+		}
+
+		assert_eq!(find_kana_range("", ""), Some(0..0));
+		assert_eq!(find_kana_range("ｶﾞｯｺｳ", "がっ"), Some(0..9));
+		assert_eq!(find_kana_range("ﾊﾟﾝ", "ぱんや"), None);
+		assert!(matches("あいう", "").is_empty());
+		assert!(matches("あいう", "え").is_empty());
+		assert_eq!(matches("ああああ", "アア"), vec!["ああ", "ああ"]);
+		assert_eq!(
+			matches("ｶﾞｲﾄﾞ ガイド か\u{3099}いと\u{3099}", "ガイド"),
+			vec!["ｶﾞｲﾄﾞ", "ガイド", "か\u{3099}いと\u{3099}"]
+		);
+		assert_eq!(
+			matches("ＲＡＭＥＮ, Ramen", "ramen"),
+			vec!["ＲＡＭＥＮ", "Ramen"]
+		);
+	}
+
+	#[test]
+	fn test_replace_kana() {
+		assert!(matches!(
+			replace_kana("あいう", "え", "x"),
+			Cow::Borrowed("あいう")
+		));
+		assert!(matches!(replace_kana("あいう", "", "x"), Cow::Borrowed(_)));
+		assert_eq!(replace_kana("", "あ", "x"), "");
+		assert_eq!(replace_kana("あいう", "ア", ""), "いう");
+		assert_eq!(replace_kana("あいう", "いう", "x"), "あx");
+		assert_eq!(
+			replace_kana("ＢＡＫＡ、ばか、バカ、ﾊﾞｶ、はか", "baka", "**"),
+			"**、ばか、バカ、ﾊﾞｶ、はか"
+		);
+		assert_eq!(
+			replace_kana("ＢＡＫＡ、ばか、バカ、ﾊﾞｶ、はか", "ばか", "**"),
+			"ＢＡＫＡ、**、**、**、はか"
+		);
+	}
+
+	#[test]
+	fn test_starts_with_kana_fold() {
+		assert!(starts_with_kana_fold("らーめん", ""));
+		assert!(starts_with_kana_fold("らーめん", "ラー"));
+		assert!(starts_with_kana_fold("ラーメン", "らーめん"));
+		assert!(starts_with_kana_fold("きょうと", "キョ"));
+		assert!(starts_with_kana_fold("ゝゞ", "ヽ"));
+		assert!(!starts_with_kana_fold("きょうと", "き"));
+		assert!(!starts_with_kana_fold("らーめん", "らーめんや"));
+		assert!(!starts_with_kana_fold("らーめん", "ら-"));
+
+		// Halfwidth katakana and combining marks
+		assert!(starts_with_kana_fold("ｶﾞｯｺｳ", "が"));
+		assert!(starts_with_kana_fold("ｶﾞｯｺｳ", "ガッ"));
+		assert!(starts_with_kana_fold("か\u{3099}っこう", "が"));
+		assert!(starts_with_kana_fold("がっこう", "ｶﾞ"));
+		assert!(starts_with_kana_fold("ｷｮｳﾄ", "きょ"));
+		assert!(!starts_with_kana_fold("ｶﾞｯｺｳ", "か"));
+		assert!(!starts_with_kana_fold("ｷｮｳﾄ", "き"));
+		assert!(!starts_with_kana_fold("か\u{3099}っこう", "か"));
+	}
+
+	#[test]
+	fn test_ends_with_kana_fold() {
+		assert!(ends_with_kana_fold("らーめん", ""));
+		assert!(ends_with_kana_fold("らーめん", "メン"));
+		assert!(ends_with_kana_fold("とうきょう", "キョウ"));
+		assert!(!ends_with_kana_fold("とうきょう", "ょう"));
+		assert!(!ends_with_kana_fold("めん", "らーめん"));
+		assert!(!ends_with_kana_fold("", "ん"));
+
+		// Halfwidth katakana and combining marks
+		assert!(ends_with_kana_fold("ｶﾞｲﾄﾞ", "ど"));
+		assert!(ends_with_kana_fold("ｶﾞｲﾄﾞ", "がいど"));
+		assert!(ends_with_kana_fold("がいと\u{3099}", "ド"));
+		assert!(ends_with_kana_fold("ﾄｳｷｮｳ", "きょう"));
+		assert!(!ends_with_kana_fold("ｶﾞｲﾄﾞ", "と"));
+		assert!(!ends_with_kana_fold("ｶﾞｲﾄﾞ", "ﾞ"));
+		assert!(!ends_with_kana_fold("がいと\u{3099}", "\u{3099}"));
+		assert!(!ends_with_kana_fold("ﾄｳｷｮｳ", "ょう"));
+	}
+
+	#[test]
+	fn test_common_prefix_morae() {
+		assert_eq!(common_prefix_morae("", "きょう"), 0);
+		assert_eq!(common_prefix_morae("きょうと", "キョウカ"), 2);
+		assert_eq!(common_prefix_morae("きょう", "きよう"), 0);
+		assert_eq!(common_prefix_morae("ラーメン", "らーめん"), 4);
+		assert_eq!(common_prefix_morae("東京", "東北"), 1);
+		assert_eq!(common_prefix_morae("ｶﾞｯｺｳ", "がっこう"), 4);
+		assert_eq!(common_prefix_morae("か\u{3099}っか", "がっこう"), 2);
+	}
+}
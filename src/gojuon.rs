@@ -0,0 +1,294 @@
+//! Position of kana in the gojūon (五十音) table.
+
+use super::util::{half_width_to_katakana, katakana_to_hiragana};
+
+/// Row of the gojūon table, given by the consonant of the kana.
+///
+/// Includes the rows for the dakuten (e.g. `Ga`) and handakuten (`Pa`)
+/// variants.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GojuonRow {
+	A,
+	Ka,
+	Ga,
+	Sa,
+	Za,
+	Ta,
+	Da,
+	Na,
+	Ha,
+	Ba,
+	Pa,
+	Ma,
+	Ya,
+	Ra,
+	Wa,
+}
+
+/// Column of the gojūon table, given by the vowel of the kana.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GojuonColumn {
+	A,
+	I,
+	U,
+	E,
+	O,
+}
+
+impl GojuonRow {
+	/// Returns the row without the dakuten or handakuten (e.g. `Ka` for `Ga`
+	/// and `Ha` for both `Ba` and `Pa`).
+	pub fn base(self) -> GojuonRow {
+		match self {
+			GojuonRow::Ga => GojuonRow::Ka,
+			GojuonRow::Za => GojuonRow::Sa,
+			GojuonRow::Da => GojuonRow::Ta,
+			GojuonRow::Ba | GojuonRow::Pa => GojuonRow::Ha,
+			row => row,
+		}
+	}
+
+	/// Returns true for the dakuten and handakuten rows.
+	pub fn is_voiced(self) -> bool {
+		self.base() != self
+	}
+}
+
+/// Placeholder for the gaps in the table (e.g. `yi`).
+const NONE: char = '\0';
+
+/// The gojūon table in hiragana, in the same order as [GojuonRow].
+const GOJUON: [[char; 5]; 15] = [
+	['あ', 'い', 'う', 'え', 'お'],
+	['か', 'き', 'く', 'け', 'こ'],
+	['が', 'ぎ', 'ぐ', 'げ', 'ご'],
+	['さ', 'し', 'す', 'せ', 'そ'],
+	['ざ', 'じ', 'ず', 'ぜ', 'ぞ'],
+	['た', 'ち', 'つ', 'て', 'と'],
+	['だ', 'ぢ', 'づ', 'で', 'ど'],
+	['な', 'に', 'ぬ', 'ね', 'の'],
+	['は', 'ひ', 'ふ', 'へ', 'ほ'],
+	['ば', 'び', 'ぶ', 'べ', 'ぼ'],
+	['ぱ', 'ぴ', 'ぷ', 'ぺ', 'ぽ'],
+	['ま', 'み', 'む', 'め', 'も'],
+	['や', NONE, 'ゆ', NONE, 'よ'],
+	['ら', 'り', 'る', 'れ', 'ろ'],
+	['わ', 'ゐ', NONE, 'ゑ', 'を'],
+];
+
+const ROWS: [GojuonRow; 15] = [
+	GojuonRow::A,
+	GojuonRow::Ka,
+	GojuonRow::Ga,
+	GojuonRow::Sa,
+	GojuonRow::Za,
+	GojuonRow::Ta,
+	GojuonRow::Da,
+	GojuonRow::Na,
+	GojuonRow::Ha,
+	GojuonRow::Ba,
+	GojuonRow::Pa,
+	GojuonRow::Ma,
+	GojuonRow::Ya,
+	GojuonRow::Ra,
+	GojuonRow::Wa,
+];
+
+const COLUMNS: [GojuonColumn; 5] = [
+	GojuonColumn::A,
+	GojuonColumn::I,
+	GojuonColumn::U,
+	GojuonColumn::E,
+	GojuonColumn::O,
+];
+
+/// Returns the position of a hiragana or katakana character in the gojūon
+/// table.
+///
+/// Returns `None` for characters outside the table, including small kana and
+/// `ん`.
+pub fn gojuon_position(chr: char) -> Option<(GojuonRow, GojuonColumn)> {
+	let chr = katakana_to_hiragana(chr);
+	if chr == NONE {
+		return None;
+	}
+	for (row, kana) in GOJUON.iter().enumerate() {
+		if let Some(col) = kana.iter().position(|&x| x == chr) {
+			return Some((ROWS[row], COLUMNS[col]));
+		}
+	}
+	None
+}
+
+/// Returns the hiragana at the given position of the gojūon table, or `None`
+/// for the gaps in the table (`yi`, `ye` and `wu`).
+pub fn gojuon_char(row: GojuonRow, column: GojuonColumn) -> Option<char> {
+	let chr = GOJUON[row as usize][column as usize];
+	if chr == NONE {
+		None
+	} else {
+		Some(chr)
+	}
+}
+
+/// Returns the position in the gojūon table for [kana_vowel] and
+/// [kana_consonant_class], which also handle the small and halfwidth kana,
+/// `ゔ` and `ヷヸヹヺ`.
+///
+/// Since the table has no voiced rows for `ゔ` and `ヷヸヹヺ`, those return
+/// the row of their unvoiced kana (`A` and `Wa`).
+fn kana_position(chr: char) -> Option<(GojuonRow, GojuonColumn)> {
+	let chr = match half_width_to_katakana(chr) {
+		Some(katakana) => katakana.chars().next().unwrap(),
+		None => chr,
+	};
+	let chr = katakana_to_hiragana(chr);
+	let position = match chr {
+		'ゔ' => (GojuonRow::A, GojuonColumn::U),
+		'ヷ' => (GojuonRow::Wa, GojuonColumn::A),
+		'ヸ' => (GojuonRow::Wa, GojuonColumn::I),
+		'ヹ' => (GojuonRow::Wa, GojuonColumn::E),
+		'ヺ' => (GojuonRow::Wa, GojuonColumn::O),
+		_ => return gojuon_position(to_full_size(chr)),
+	};
+	Some(position)
+}
+
+/// Returns the vowel of a hiragana or katakana character as a lowercase
+/// romaji letter (`a`, `i`, `u`, `e` or `o`).
+///
+/// This includes the small and halfwidth kana (e.g. `ゃ` and `ｬ` are `a`),
+/// `ゔ` and `ヷヸヹヺ`. Returns `None` for characters without a vowel, such
+/// as `ん` and `っ`.
+pub fn kana_vowel(chr: char) -> Option<char> {
+	kana_position(chr).map(|(_, column)| match column {
+		GojuonColumn::A => 'a',
+		GojuonColumn::I => 'i',
+		GojuonColumn::U => 'u',
+		GojuonColumn::E => 'e',
+		GojuonColumn::O => 'o',
+	})
+}
+
+/// Returns the consonant class of a hiragana or katakana character, given
+/// by its row in the gojūon table.
+///
+/// The voiced rows (e.g. [GojuonRow::Ga]) are returned for characters with
+/// dakuten and handakuten. Use [GojuonRow::base] to get the unvoiced class.
+///
+/// Small kana return the class of their full size version (e.g. `ゃ` is
+/// [GojuonRow::Ya]), and halfwidth kana the class of their full-width
+/// version. Since there are no voiced rows for them, `ゔ` is
+/// [GojuonRow::A] and `ヷヸヹヺ` are [GojuonRow::Wa]. Returns `None` for
+/// characters outside the table, which are the same that have no
+/// [kana_vowel].
+pub fn kana_consonant_class(chr: char) -> Option<GojuonRow> {
+	kana_position(chr).map(|(row, _)| row)
+}
+
+/// Converts a small hiragana to its full size version.
+fn to_full_size(chr: char) -> char {
+	match chr {
+		'ぁ' => 'あ',
+		'ぃ' => 'い',
+		'ぅ' => 'う',
+		'ぇ' => 'え',
+		'ぉ' => 'お',
+		'ゃ' => 'や',
+		'ゅ' => 'ゆ',
+		'ょ' => 'よ',
+		'ゎ' => 'わ',
+		'ゕ' => 'か',
+		'ゖ' => 'け',
+		_ => chr,
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_gojuon_position() {
+		use GojuonColumn as C;
+		use GojuonRow as R;
+
+		assert_eq!(gojuon_position('あ'), Some((R::A, C::A)));
+		assert_eq!(gojuon_position('キ'), Some((R::Ka, C::I)));
+		assert_eq!(gojuon_position('ず'), Some((R::Za, C::U)));
+		assert_eq!(gojuon_position('ヂ'), Some((R::Da, C::I)));
+		assert_eq!(gojuon_position('ぽ'), Some((R::Pa, C::O)));
+		assert_eq!(gojuon_position('ゆ'), Some((R::Ya, C::U)));
+		assert_eq!(gojuon_position('ヲ'), Some((R::Wa, C::O)));
+		assert_eq!(gojuon_position('ゑ'), Some((R::Wa, C::E)));
+
+		assert_eq!(gojuon_position('ん'), None);
+		assert_eq!(gojuon_position('ゃ'), None);
+		assert_eq!(gojuon_position('ー'), None);
+		assert_eq!(gojuon_position('a'), None);
+		assert_eq!(gojuon_position('\0'), None);
+
+		assert_eq!(gojuon_char(R::Ba, C::E), Some('べ'));
+		assert_eq!(gojuon_char(R::Ya, C::I), None);
+		assert_eq!(gojuon_char(R::Wa, C::U), None);
+
+		for &row in ROWS.iter() {
+			for &col in COLUMNS.iter() {
+				if let Some(chr) = gojuon_char(row, col) {
+					assert_eq!(gojuon_position(chr), Some((row, col)));
+				}
+			}
+		}
+
+		assert_eq!(R::Pa.base(), R::Ha);
+		assert_eq!(R::Ka.base(), R::Ka);
+		assert!(R::Ga.is_voiced());
+		assert!(!R::Wa.is_voiced());
+	}
+
+	#[test]
+	fn test_kana_vowel_and_consonant() {
+		assert_eq!(kana_vowel('か'), Some('a'));
+		assert_eq!(kana_vowel('ギ'), Some('i'));
+		assert_eq!(kana_vowel('ぷ'), Some('u'));
+		assert_eq!(kana_vowel('ゃ'), Some('a'));
+		assert_eq!(kana_vowel('ォ'), Some('o'));
+		assert_eq!(kana_vowel('ゔ'), Some('u'));
+		assert_eq!(kana_vowel('ヹ'), Some('e'));
+		assert_eq!(kana_vowel('ん'), None);
+		assert_eq!(kana_vowel('っ'), None);
+		assert_eq!(kana_vowel('ー'), None);
+		assert_eq!(kana_vowel('k'), None);
+
+		assert_eq!(kana_consonant_class('あ'), Some(GojuonRow::A));
+		assert_eq!(kana_consonant_class('シ'), Some(GojuonRow::Sa));
+		assert_eq!(kana_consonant_class('ぢ'), Some(GojuonRow::Da));
+		assert_eq!(kana_consonant_class('ゅ'), Some(GojuonRow::Ya));
+		assert_eq!(kana_consonant_class('ヵ'), Some(GojuonRow::Ka));
+		assert_eq!(kana_consonant_class('を'), Some(GojuonRow::Wa));
+		assert_eq!(kana_consonant_class('ン'), None);
+		assert_eq!(kana_consonant_class('漢'), None);
+
+		assert_eq!(kana_consonant_class('ヷ'), Some(GojuonRow::Wa));
+		assert_eq!(kana_consonant_class('ヺ'), Some(GojuonRow::Wa));
+		assert_eq!(kana_consonant_class('ゔ'), Some(GojuonRow::A));
+		assert_eq!(kana_consonant_class('ヴ'), Some(GojuonRow::A));
+		assert_eq!(kana_consonant_class('ｶ'), Some(GojuonRow::Ka));
+		assert_eq!(kana_consonant_class('ｮ'), Some(GojuonRow::Ya));
+		assert_eq!(kana_vowel('ｶ'), Some('a'));
+		assert_eq!(kana_vowel('ｮ'), Some('o'));
+		assert_eq!(kana_vowel('ｰ'), None);
+
+		// Both functions handle the same characters
+		for chr in ('\u{3040}'..='\u{30FF}').chain('\u{FF61}'..='\u{FF9F}') {
+			assert_eq!(
+				kana_vowel(chr).is_some(),
+				kana_consonant_class(chr).is_some(),
+				"{}",
+				chr
+			);
+		}
+	}
+}
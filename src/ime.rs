@@ -0,0 +1,169 @@
+//! Incremental romaji input, similar to an input method editor (IME).
+
+use super::chunks::*;
+use super::table::*;
+
+/// State machine for typing kana using romaji, one key at a time.
+///
+/// Keys are kept as pending until they can be converted unambiguously, at
+/// which point their hiragana is appended to the committed text.
+///
+/// ```
+/// use kana::KanaInput;
+///
+/// let mut input = KanaInput::new();
+/// input.push('k');
+/// assert_eq!((input.committed(), input.pending()), ("", "k"));
+/// input.push('a');
+/// assert_eq!((input.committed(), input.pending()), ("か", ""));
+///
+/// input.push_str("nji");
+/// assert_eq!(input.committed(), "かんじ");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct KanaInput {
+	committed: String,
+	pending: String,
+}
+
+impl KanaInput {
+	/// Creates a new empty input.
+	pub fn new() -> KanaInput {
+		KanaInput::default()
+	}
+
+	/// Converted text.
+	pub fn committed(&self) -> &str {
+		&self.committed
+	}
+
+	/// Keys that are pending conversion.
+	pub fn pending(&self) -> &str {
+		&self.pending
+	}
+
+	/// Returns the committed text followed by the pending keys, as it should
+	/// be displayed to the user.
+	pub fn text(&self) -> String {
+		format!("{}{}", self.committed, self.pending)
+	}
+
+	/// Types a single key.
+	pub fn push(&mut self, key: char) {
+		self.pending.push(key);
+		self.convert(false);
+	}
+
+	/// Types each key in the input.
+	pub fn push_str(&mut self, keys: &str) {
+		for key in keys.chars() {
+			self.push(key);
+		}
+	}
+
+	/// Converts the pending keys as they are, even if they could be part of a
+	/// longer sequence (e.g. a final `n` is committed as `ん`).
+	pub fn commit(&mut self) {
+		self.convert(true);
+	}
+
+	/// Erases the last pending key or, if there are none, the last committed
+	/// character. Returns false if the input is empty.
+	pub fn backspace(&mut self) -> bool {
+		self.pending
+			.pop()
+			.or_else(|| self.committed.pop())
+			.is_some()
+	}
+
+	/// Commits any pending keys and returns the committed text, leaving the
+	/// input empty.
+	pub fn finish(&mut self) -> String {
+		self.commit();
+		std::mem::take(&mut self.committed)
+	}
+
+	/// Clears the input.
+	pub fn clear(&mut self) {
+		self.committed.clear();
+		self.pending.clear();
+	}
+
+	fn convert(&mut self, force: bool) {
+		while !self.pending.is_empty() {
+			if !force
+				&& (TO_HIRAGANA_PREFIXES.contains(self.pending.as_str()) || self.pending == "|")
+			{
+				break;
+			}
+			let chunk = HiraganaChunks::new(&self.pending).next().unwrap();
+			self.committed.push_str(chunk.text);
+			let end = chunk.src.end;
+			self.pending.drain(..end);
+		}
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_kana_input() {
+		fn check(keys: &str, expected: &[(&str, &str)]) {
+			let mut input = KanaInput::new();
+			let states = keys
+				.chars()
+				.map(|key| {
+					input.push(key);
+					(input.committed().to_string(), input.pending().to_string())
+				})
+				.collect::<Vec<_>>();
+			let states = states
+				.iter()
+				.map(|(a, b)| (a.as_str(), b.as_str()))
+				.collect::<Vec<_>>();
+			assert_eq!(states, expected, "keys `{}`", keys);
+		}
+
+		check("ka", &[("", "k"), ("か", "")]);
+		check(
+			"kitte",
+			&[
+				("", "k"),
+				("き", ""),
+				("き", "t"),
+				("きっ", "t"),
+				("きって", ""),
+			],
+		);
+		check(
+			"shan",
+			&[("", "s"), ("", "sh"), ("しゃ", ""), ("しゃ", "n")],
+		);
+		check("nka", &[("", "n"), ("ん", "k"), ("んか", "")]);
+		check("n'a", &[("", "n"), ("", "n'"), ("んあ", "")]);
+		check("n|ya", &[("", "n"), ("ん", "|"), ("ん", "y"), ("んや", "")]);
+		check("a1", &[("あ", ""), ("あ1", "")]);
+		check("Ka.", &[("", "K"), ("か", ""), ("か。", "")]);
+
+		let mut input = KanaInput::new();
+		input.push_str("kon");
+		assert_eq!(input.text(), "こn");
+		assert!(input.backspace());
+		assert_eq!(input.text(), "こ");
+		assert!(input.backspace());
+		assert!(!input.backspace());
+
+		input.push_str("honn");
+		assert_eq!((input.committed(), input.pending()), ("ほん", "n"));
+		assert_eq!(input.finish(), "ほんん");
+		assert_eq!(input.text(), "");
+
+		input.push_str("xy");
+		input.clear();
+		assert_eq!(input.text(), "");
+	}
+}
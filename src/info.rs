@@ -0,0 +1,241 @@
+//! Metadata for a single character.
+
+use super::block::*;
+use super::kind::*;
+use super::names::*;
+use super::util::*;
+use super::validate::KanaField;
+
+/// Width of a character in East Asian typography.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CharWidth {
+	/// Full-width characters, which take the space of a kanji (e.g. kana,
+	/// kanji and `Ａ`).
+	Full,
+	/// Halfwidth characters, which take half the space of a kanji (e.g. ASCII
+	/// and `ｶ`).
+	Half,
+	/// Characters without a specific width in East Asian typography (e.g.
+	/// `é` and `°`).
+	Neutral,
+}
+
+/// Voicing of a kana character.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Voicing {
+	/// Kana that has a voiced or semi-voiced counterpart (e.g. `か` and `は`).
+	Unvoiced,
+	/// Voiced kana (e.g. `が` and `ば`).
+	Voiced,
+	/// Semi-voiced kana (e.g. `ぱ`).
+	SemiVoiced,
+}
+
+/// Metadata for a character, returned by [char_info].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CharInfo {
+	/// The character.
+	pub chr: char,
+	/// Unicode code point of the character.
+	pub code: u32,
+	/// Kind of the character, as returned by [get_kind].
+	pub kind: CharKind,
+	/// Unicode block of the character, as returned by [get_block].
+	pub block: UnicodeBlock,
+	/// Width of the character.
+	pub width: CharWidth,
+	/// True if the character is a small kana (e.g. `ゃ` and `ッ`).
+	pub is_small_kana: bool,
+	/// Voicing of the character, if it is a kana that is voiced or has a
+	/// voiced counterpart.
+	pub voicing: Option<Voicing>,
+}
+
+/// Returns the metadata for a character.
+///
+/// ```
+/// use kana::*;
+///
+/// let info = char_info('ガ');
+/// assert_eq!(info.code, 0x30AC);
+/// assert_eq!(info.kind, CharKind::Katakana);
+/// assert_eq!(info.block, UnicodeBlock::Katakana);
+/// assert_eq!(info.width, CharWidth::Full);
+/// assert_eq!(info.is_small_kana, false);
+/// assert_eq!(info.voicing, Some(Voicing::Voiced));
+/// ```
+pub fn char_info(chr: char) -> CharInfo {
+	CharInfo {
+		chr,
+		code: chr as u32,
+		kind: get_kind(chr),
+		block: get_block(chr),
+		width: get_width(chr),
+		is_small_kana: KanaField::SmallKana.accepts(chr),
+		voicing: get_voicing(chr),
+	}
+}
+
+/// Returns the Unicode name of a character in the kana, Japanese punctuation
+/// and symbol blocks (e.g. `HIRAGANA LETTER SMALL TU` for `っ`).
+///
+/// The covered blocks are CJK Symbols and Punctuation, Hiragana, Katakana,
+/// Katakana Phonetic Extensions, Enclosed CJK Letters and Months, CJK
+/// Compatibility and Halfwidth and Fullwidth Forms. Returns `None` for any
+/// other character, including kanji.
+///
+/// ```
+/// assert_eq!(kana::char_name('っ'), Some("HIRAGANA LETTER SMALL TU"));
+/// assert_eq!(kana::char_name('ｰ'), Some("HALFWIDTH KATAKANA-HIRAGANA PROLONGED SOUND MARK"));
+/// assert_eq!(kana::char_name('漢'), None);
+/// ```
+pub fn char_name(chr: char) -> Option<&'static str> {
+	let code = chr as u32;
+	let name = match code {
+		0x3000..=0x30FF => CJK_SYMBOLS_AND_KANA[(code - 0x3000) as usize],
+		0x31F0..=0x31FF => KATAKANA_EXTENSIONS[(code - 0x31F0) as usize],
+		0x3200..=0x33FF => ENCLOSED_AND_COMPATIBILITY[(code - 0x3200) as usize],
+		0xFF00..=0xFFEF => HALFWIDTH_AND_FULLWIDTH[(code - 0xFF00) as usize],
+		_ => return None,
+	};
+	if name.is_empty() {
+		None
+	} else {
+		Some(name)
+	}
+}
+
+/// Returns the width of the character, based on the East Asian Width of the
+/// Unicode blocks relevant to Japanese.
+pub(crate) fn get_width(chr: char) -> CharWidth {
+	match chr {
+		// ASCII and the halfwidth forms, including halfwidth katakana, hangul
+		// and symbols
+		'\u{20}'..='\u{7E}' | '\u{FF61}'..='\u{FFDC}' | '\u{FFE8}'..='\u{FFEE}' => CharWidth::Half,
+		// Full-width forms
+		'\u{FF01}'..='\u{FF60}' | '\u{FFE0}'..='\u{FFE6}' => CharWidth::Full,
+		// CJK blocks from the radicals to Yi, Hangul syllables, compatibility
+		// ideographs and forms, the kana supplements and the ideographs
+		// extensions
+		'\u{1100}'..='\u{115F}'
+		| '\u{2E80}'..='\u{303E}'
+		| '\u{3041}'..='\u{A4CF}'
+		| '\u{AC00}'..='\u{D7A3}'
+		| '\u{F900}'..='\u{FAFF}'
+		| '\u{FE10}'..='\u{FE19}'
+		| '\u{FE30}'..='\u{FE6F}'
+		| '\u{1AFF0}'..='\u{1B16F}'
+		| '\u{20000}'..='\u{3FFFD}' => CharWidth::Full,
+		_ => match get_kind(chr) {
+			CharKind::Emoji if chr > '\u{FFFF}' => CharWidth::Full,
+			_ => CharWidth::Neutral,
+		},
+	}
+}
+
+/// Returns the voicing of a kana character.
+pub(crate) fn get_voicing(chr: char) -> Option<Voicing> {
+	let chr = match half_width_to_katakana(chr) {
+		Some(full) => full.chars().next().unwrap(),
+		None => chr,
+	};
+	match decompose_voiced(chr) {
+		Some((_, COMBINING_VOICED_MARK)) => Some(Voicing::Voiced),
+		Some(_) => Some(Voicing::SemiVoiced),
+		None if has_voiced_kana(chr) => Some(Voicing::Unvoiced),
+		None => None,
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_char_info() {
+		assert_eq!(
+			char_info('ゃ'),
+			CharInfo {
+				chr: 'ゃ',
+				code: 0x3083,
+				kind: CharKind::Hiragana,
+				block: UnicodeBlock::Hiragana,
+				width: CharWidth::Full,
+				is_small_kana: true,
+				voicing: None,
+			}
+		);
+		assert_eq!(
+			char_info('ﾊ'),
+			CharInfo {
+				chr: 'ﾊ',
+				code: 0xFF8A,
+				kind: CharKind::KatakanaHalfWidth,
+				block: UnicodeBlock::HalfwidthAndFullwidthForms,
+				width: CharWidth::Half,
+				is_small_kana: false,
+				voicing: Some(Voicing::Unvoiced),
+			}
+		);
+		assert_eq!(
+			char_info('a'),
+			CharInfo {
+				chr: 'a',
+				code: 0x61,
+				kind: CharKind::Romaji,
+				block: UnicodeBlock::BasicLatin,
+				width: CharWidth::Half,
+				is_small_kana: false,
+				voicing: None,
+			}
+		);
+
+		let width = |input: &str, width: CharWidth| {
+			for chr in input.chars() {
+				assert_eq!(
+					char_info(chr).width,
+					width,
+					"{} (U+{:04X})",
+					chr,
+					chr as u32
+				);
+			}
+		};
+		width("あアー漢𠀀々。「」　ＡＡ１！￥㌔한😀", CharWidth::Full);
+		width("aZ0 !~ｱｰﾞ｡｢ﾡ￨", CharWidth::Half);
+		width("éō°☀\n\u{0}", CharWidth::Neutral);
+
+		let voicing = |input: &str, voicing: Option<Voicing>| {
+			for chr in input.chars() {
+				assert_eq!(char_info(chr).voicing, voicing, "{}", chr);
+			}
+		};
+		voicing("かさたはカハうウワゝヽｶﾊｳ", Some(Voicing::Unvoiced));
+		voicing("がざだばゔガバヴヷゞヾ", Some(Voicing::Voiced));
+		voicing("ぱぴぷぺぽパポ", Some(Voicing::SemiVoiced));
+		voicing("あなまやらんアンｱー漢a", None);
+	}
+
+	#[test]
+	fn test_char_name() {
+		assert_eq!(char_name('　'), Some("IDEOGRAPHIC SPACE"));
+		assert_eq!(char_name('。'), Some("IDEOGRAPHIC FULL STOP"));
+		assert_eq!(char_name('ぁ'), Some("HIRAGANA LETTER SMALL A"));
+		assert_eq!(
+			char_name('\u{3099}'),
+			Some("COMBINING KATAKANA-HIRAGANA VOICED SOUND MARK")
+		);
+		assert_eq!(char_name('ヿ'), Some("KATAKANA DIGRAPH KOTO"));
+		assert_eq!(char_name('ㇰ'), Some("KATAKANA LETTER SMALL KU"));
+		assert_eq!(char_name('㈱'), Some("PARENTHESIZED IDEOGRAPH STOCK"));
+		assert_eq!(char_name('㌔'), Some("SQUARE KIRO"));
+		assert_eq!(char_name('Ａ'), Some("FULLWIDTH LATIN CAPITAL LETTER A"));
+		assert_eq!(char_name('ﾝ'), Some("HALFWIDTH KATAKANA LETTER N"));
+		assert_eq!(char_name('\u{3040}'), None);
+		assert_eq!(char_name('\u{FF00}'), None);
+		assert_eq!(char_name('a'), None);
+		assert_eq!(char_name('漢'), None);
+	}
+}
@@ -56,13 +56,293 @@ pub fn is_japanese_mark(chr: char) -> bool {
 }
 
 /// Returns true if the character is a japanese-style punctuation.
+///
+/// This includes the U+3000 Ideographic Space, even though its kind is
+/// `Space` and not `JapanesePunctuation`.
 pub fn is_japanese_punctuation(chr: char) -> bool {
 	match chr {
-		japanese_punctuation_range!() => true,
+		japanese_punctuation_range!() | '　' => true,
 		_ => false,
 	}
 }
 
+/// Returns true if the character is a general japanese symbol, such as the
+/// circled and squared characters (e.g. `㊤` and `㌔`) or the kanji radicals.
+///
+/// Those are the characters with the `JapaneseSymbol` kind.
+pub fn is_japanese_symbol(chr: char) -> bool {
+	matches!(chr, japanese_symbol_range!())
+}
+
+/// Returns true if the character is a full-width roman letter (`Ａ` to `Ｚ`
+/// and `ａ` to `ｚ`).
+pub fn is_roman_letter(chr: char) -> bool {
+	matches!(chr, roman_letter_range!())
+}
+
+/// Returns true if the character is a full-width roman digit (`０` to `９`).
+pub fn is_roman_digit(chr: char) -> bool {
+	matches!(chr, roman_digit_range!())
+}
+
+/// Returns true if the character is a full-width roman punctuation (e.g. `！`
+/// and `＠`).
+pub fn is_roman_punctuation(chr: char) -> bool {
+	matches!(chr, roman_punctuation_range!())
+}
+
+/// Returns true if the character is the prolonged sound mark (`ー` or the
+/// halfwidth `ｰ`).
+pub fn is_prolonged_sound_mark(chr: char) -> bool {
+	matches!(chr, prolonged_mark_range!())
+}
+
+/// Returns true if the character is an iteration mark, which repeats the
+/// previous kana (`ゝ`, `ゞ`, `ヽ` and `ヾ`) or kanji (`々` and `〻`).
+pub fn is_iteration_mark(chr: char) -> bool {
+	matches!(chr, 'ゝ' | 'ゞ' | 'ヽ' | 'ヾ' | '々' | '〻')
+}
+
+/// Returns true if the character is whitespace, including the U+3000
+/// Ideographic Space.
+///
+/// This is the same as `char::is_whitespace` and matches the `Space` kind.
+pub fn is_space(chr: char) -> bool {
+	matches!(chr, space_range!())
+}
+
+/// Returns true if the input has any kanji character.
+///
+/// ```
+/// assert!(kana::contains_kanji("ひらがなと漢字"));
+/// assert!(!kana::contains_kanji("ひらがな"));
+/// ```
+pub fn contains_kanji<S: AsRef<str>>(input: S) -> bool {
+	input.as_ref().chars().any(is_kanji)
+}
+
+/// Returns true if the input has any kana character, including the halfwidth
+/// katakana and the prolonged sound mark.
+///
+/// ```
+/// assert!(kana::contains_kana("漢字とカナ"));
+/// assert!(!kana::contains_kana("漢字"));
+/// ```
+pub fn contains_kana<S: AsRef<str>>(input: S) -> bool {
+	input.as_ref().chars().any(is_kana)
+}
+
+/// Returns true if the input has any Japanese character, that is kana, kanji
+/// or a Japanese mark (e.g. `々`).
+///
+/// Japanese punctuation is not considered, since it is shared with other
+/// languages.
+///
+/// ```
+/// assert!(kana::contains_japanese("Tokyo 東京"));
+/// assert!(!kana::contains_japanese("Tokyo。"));
+/// ```
+pub fn contains_japanese<S: AsRef<str>>(input: S) -> bool {
+	input
+		.as_ref()
+		.chars()
+		.any(|chr| is_letter(chr) || is_japanese_mark(chr))
+}
+
+/// Options for the string predicates, such as [is_hiragana_str_with].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StrCheckOptions {
+	/// Skip whitespace, including the U+3000 Ideographic Space.
+	pub ignore_spaces: bool,
+	/// Skip ASCII, full-width and Japanese punctuation (e.g. `!`, `！` and
+	/// `。`).
+	pub ignore_punctuation: bool,
+}
+
+/// Returns true if all the characters of the input pass the test, skipping
+/// the ones ignored by the options. Returns false if there are no characters
+/// left to test.
+fn check_str(input: &str, test: fn(char) -> bool, options: &StrCheckOptions) -> bool {
+	let mut any = false;
+	for chr in input.chars() {
+		if options.ignore_spaces && is_space(chr) {
+			continue;
+		}
+		let punctuation = chr.is_ascii_punctuation()
+			|| is_roman_punctuation(chr)
+			|| (is_japanese_punctuation(chr) && !is_space(chr));
+		if options.ignore_punctuation && punctuation {
+			continue;
+		}
+		if !test(chr) {
+			return false;
+		}
+		any = true;
+	}
+	any
+}
+
+/// Returns true if the input is not empty and all of its characters are
+/// hiragana, as in [is_hiragana].
+///
+/// ```
+/// assert!(kana::is_hiragana_str("ひらがな"));
+/// assert!(!kana::is_hiragana_str("ひらがなとカタカナ"));
+/// assert!(!kana::is_hiragana_str(""));
+/// ```
+pub fn is_hiragana_str<S: AsRef<str>>(input: S) -> bool {
+	is_hiragana_str_with(input, &StrCheckOptions::default())
+}
+
+/// Same as [is_hiragana_str] but skipping the characters ignored by the
+/// options. Returns false if all characters are ignored.
+///
+/// ```
+/// let options = kana::StrCheckOptions {
+///     ignore_spaces: true,
+///     ignore_punctuation: true,
+/// };
+/// assert!(kana::is_hiragana_str_with("はい、 そうです。", &options));
+/// assert!(!kana::is_hiragana_str_with("、 。", &options));
+/// ```
+pub fn is_hiragana_str_with<S: AsRef<str>>(input: S, options: &StrCheckOptions) -> bool {
+	check_str(input.as_ref(), is_hiragana, options)
+}
+
+/// Returns true if the input is not empty and all of its characters are
+/// katakana, as in [is_katakana].
+///
+/// Note that this does not accept the prolonged sound mark `ー`, see
+/// [is_kana_str].
+pub fn is_katakana_str<S: AsRef<str>>(input: S) -> bool {
+	is_katakana_str_with(input, &StrCheckOptions::default())
+}
+
+/// Same as [is_katakana_str] but skipping the characters ignored by the
+/// options. Returns false if all characters are ignored.
+pub fn is_katakana_str_with<S: AsRef<str>>(input: S, options: &StrCheckOptions) -> bool {
+	check_str(input.as_ref(), is_katakana, options)
+}
+
+/// Returns true if the input is not empty and all of its characters are
+/// kana, as in [is_kana].
+pub fn is_kana_str<S: AsRef<str>>(input: S) -> bool {
+	is_kana_str_with(input, &StrCheckOptions::default())
+}
+
+/// Same as [is_kana_str] but skipping the characters ignored by the options.
+/// Returns false if all characters are ignored.
+pub fn is_kana_str_with<S: AsRef<str>>(input: S, options: &StrCheckOptions) -> bool {
+	check_str(input.as_ref(), is_kana, options)
+}
+
+/// Returns true if the input is not empty and all of its characters are
+/// kanji, as in [is_kanji].
+pub fn is_kanji_str<S: AsRef<str>>(input: S) -> bool {
+	is_kanji_str_with(input, &StrCheckOptions::default())
+}
+
+/// Same as [is_kanji_str] but skipping the characters ignored by the options.
+/// Returns false if all characters are ignored.
+pub fn is_kanji_str_with<S: AsRef<str>>(input: S, options: &StrCheckOptions) -> bool {
+	check_str(input.as_ref(), is_kanji, options)
+}
+
+/// Returns true if the input is not empty and all of its characters are kana
+/// or kanji, as in [is_letter].
+pub fn is_letter_str<S: AsRef<str>>(input: S) -> bool {
+	is_letter_str_with(input, &StrCheckOptions::default())
+}
+
+/// Same as [is_letter_str] but skipping the characters ignored by the
+/// options. Returns false if all characters are ignored.
+pub fn is_letter_str_with<S: AsRef<str>>(input: S, options: &StrCheckOptions) -> bool {
+	check_str(input.as_ref(), is_letter, options)
+}
+
+/// Declares variants of the character tests taking a raw code point, for FFI
+/// and UTF-16 callers. Invalid code points (e.g. surrogates) return false.
+macro_rules! u32_variants {
+	($($name:ident => $test:ident),* $(,)?) => {
+		$(
+			#[doc = concat!("Same as [", stringify!($test), "] but taking a raw code point.")]
+			///
+			/// Returns false for invalid code points, such as surrogates.
+			pub fn $name(code: u32) -> bool {
+				std::char::from_u32(code).map($test).unwrap_or(false)
+			}
+		)*
+	};
+}
+
+u32_variants! {
+	is_hiragana_u32 => is_hiragana,
+	is_katakana_u32 => is_katakana,
+	is_kanji_u32 => is_kanji,
+	is_kana_u32 => is_kana,
+	is_letter_u32 => is_letter,
+	is_japanese_mark_u32 => is_japanese_mark,
+	is_japanese_punctuation_u32 => is_japanese_punctuation,
+	is_japanese_symbol_u32 => is_japanese_symbol,
+	is_roman_letter_u32 => is_roman_letter,
+	is_roman_digit_u32 => is_roman_digit,
+	is_roman_punctuation_u32 => is_roman_punctuation,
+	is_prolonged_sound_mark_u32 => is_prolonged_sound_mark,
+	is_iteration_mark_u32 => is_iteration_mark,
+	is_space_u32 => is_space,
+}
+
+/// Returns the fraction of the characters in the input that are Japanese
+/// letters or marks, from `0.0` to `1.0`.
+///
+/// Whitespace, digits and punctuation (ASCII, full-width and Japanese) are
+/// not counted, since those are shared with other languages. Returns `0.0`
+/// if there are no characters left.
+///
+/// ```
+/// assert_eq!(kana::japanese_text_score("日本語 ok"), 0.6);
+/// ```
+pub fn japanese_text_score<S: AsRef<str>>(input: S) -> f64 {
+	let mut total = 0;
+	let mut japanese = 0;
+	for chr in input.as_ref().chars() {
+		let ignored = is_space(chr)
+			|| chr.is_ascii_punctuation()
+			|| chr.is_ascii_digit()
+			|| is_roman_digit(chr)
+			|| is_roman_punctuation(chr)
+			|| is_japanese_punctuation(chr);
+		if ignored {
+			continue;
+		}
+		total += 1;
+		if is_letter(chr) || is_japanese_mark(chr) {
+			japanese += 1;
+		}
+	}
+	if total == 0 {
+		0.0
+	} else {
+		japanese as f64 / total as f64
+	}
+}
+
+/// Returns true if the [japanese_text_score] of the input is at least the
+/// threshold, from `0.0` to `1.0`.
+///
+/// This can be used to route mixed-language input, with a threshold of
+/// `0.5` meaning that most of the text is Japanese.
+///
+/// ```
+/// assert!(kana::is_japanese_text("東京に行きます!", 0.5));
+/// assert!(!kana::is_japanese_text("I'm going to 東京!", 0.5));
+/// ```
+pub fn is_japanese_text<S: AsRef<str>>(input: S, threshold: f64) -> bool {
+	let input = input.as_ref();
+	let score = japanese_text_score(input);
+	score > 0.0 && score >= threshold
+}
+
 // spell-checker: disable
 
 #[cfg(test)]
@@ -124,4 +404,164 @@ mod tests {
 		assert!(!is_kanji('\u{4DFF}'));
 		assert!(!is_kanji('\u{9FB0}'));
 	}
+
+	#[test]
+	fn test_is_roman() {
+		for code in 0xFF01..=0xFF5E {
+			let chr = std::char::from_u32(code).unwrap();
+			let ascii = std::char::from_u32(code - 0xFEE0).unwrap();
+			assert_eq!(is_roman_letter(chr), ascii.is_ascii_alphabetic(), "{}", chr);
+			assert_eq!(is_roman_digit(chr), ascii.is_ascii_digit(), "{}", chr);
+			assert_eq!(
+				is_roman_punctuation(chr),
+				ascii.is_ascii_punctuation(),
+				"{}",
+				chr
+			);
+		}
+		for chr in "aZ0!　、。ー".chars() {
+			assert!(!is_roman_letter(chr), "!is_roman_letter({})", chr);
+			assert!(!is_roman_digit(chr), "!is_roman_digit({})", chr);
+			assert!(!is_roman_punctuation(chr), "!is_roman_punctuation({})", chr);
+		}
+	}
+
+	#[test]
+	fn test_is_mark() {
+		assert!(is_prolonged_sound_mark('ー'));
+		assert!(is_prolonged_sound_mark('ｰ'));
+		for chr in "ゝゞヽヾ々〻".chars() {
+			assert!(is_iteration_mark(chr), "is_iteration_mark({})", chr);
+			assert!(
+				!is_prolonged_sound_mark(chr),
+				"!is_prolonged_sound_mark({})",
+				chr
+			);
+		}
+		for chr in "-~〜一ｰー゛゜〆あア".chars() {
+			assert!(!is_iteration_mark(chr), "!is_iteration_mark({})", chr);
+		}
+		for chr in "-~〜一".chars() {
+			assert!(
+				!is_prolonged_sound_mark(chr),
+				"!is_prolonged_sound_mark({})",
+				chr
+			);
+		}
+	}
+
+	#[test]
+	fn test_is_space() {
+		for chr in " \t\r\n\u{A0}\u{2003}　".chars() {
+			assert!(is_space(chr), "is_space(U+{:04X})", chr as u32);
+		}
+		for chr in "a、。・_\u{200B}".chars() {
+			assert!(!is_space(chr), "!is_space(U+{:04X})", chr as u32);
+		}
+		for code in 0..0x10000 {
+			if let Some(chr) = std::char::from_u32(code) {
+				assert_eq!(is_space(chr), chr.is_whitespace(), "U+{:04X}", code);
+			}
+		}
+
+		// The ideographic space is still japanese punctuation
+		assert!(is_japanese_punctuation('　'));
+		assert!(!is_japanese_punctuation(' '));
+		assert_eq!(crate::get_kind('　'), crate::CharKind::Space);
+		assert_eq!(crate::get_kind(' '), crate::CharKind::Space);
+	}
+
+	#[test]
+	fn test_contains() {
+		assert!(!contains_kanji(""));
+		assert!(!contains_kana(""));
+		assert!(!contains_japanese(""));
+
+		assert!(contains_kanji("abc𠀀"));
+		assert!(!contains_kanji("abc かな 々"));
+
+		assert!(contains_kana("abcｶ"));
+		assert!(contains_kana("ー"));
+		assert!(!contains_kana("abc 漢字"));
+
+		assert!(contains_japanese("abc 々"));
+		assert!(contains_japanese("abc ゝ"));
+		assert!(contains_japanese("abc ｶ"));
+		assert!(!contains_japanese("abc 「」、。　"));
+	}
+
+	#[test]
+	fn test_is_str() {
+		assert!(!is_hiragana_str(""));
+		assert!(!is_katakana_str(""));
+		assert!(!is_kana_str(""));
+		assert!(!is_kanji_str(""));
+		assert!(!is_letter_str(""));
+
+		assert!(is_hiragana_str("ひらがな"));
+		assert!(!is_hiragana_str("ひらがな "));
+		assert!(is_katakana_str("カタカナｶﾀｶﾅ"));
+		assert!(!is_katakana_str("カタカナー"));
+		assert!(is_kana_str("カタカナーひらがな"));
+		assert!(!is_kana_str("カナ漢字"));
+		assert!(is_kanji_str("漢字"));
+		assert!(!is_kanji_str("漢字々"));
+		assert!(is_letter_str("カナ漢字"));
+
+		let spaces = StrCheckOptions {
+			ignore_spaces: true,
+			..StrCheckOptions::default()
+		};
+		let punctuation = StrCheckOptions {
+			ignore_punctuation: true,
+			..StrCheckOptions::default()
+		};
+		assert!(is_kanji_str_with("山田　太郎", &spaces));
+		assert!(!is_kanji_str_with("山田　太郎", &punctuation));
+		assert!(!is_kanji_str_with("山田、太郎", &spaces));
+		assert!(is_kanji_str_with("山田、太郎", &punctuation));
+		assert!(is_kana_str_with("「カナ」！?", &punctuation));
+		assert!(!is_kana_str_with(" \t", &spaces));
+		assert!(!is_letter_str_with("。", &punctuation));
+	}
+
+	#[test]
+	fn test_u32_variants() {
+		assert!(is_hiragana_u32(0x3042));
+		assert!(!is_hiragana_u32(0x30A2));
+		assert!(is_katakana_u32(0x30A2));
+		assert!(is_kanji_u32(0x20000));
+		assert!(is_kana_u32('ー' as u32));
+		assert!(is_space_u32(0x3000));
+		assert!(!is_hiragana_u32(0xD800));
+		assert!(!is_space_u32(0xDFFF));
+		assert!(!is_kanji_u32(0x110000));
+		assert!(!is_letter_u32(u32::MAX));
+	}
+
+	#[test]
+	fn test_japanese_text_score() {
+		assert_eq!(japanese_text_score(""), 0.0);
+		assert_eq!(japanese_text_score("123 !?、。　１２３"), 0.0);
+		assert_eq!(japanese_text_score("カタカナ"), 1.0);
+		assert_eq!(japanese_text_score("2020年々、ｶﾅ!"), 1.0);
+		assert_eq!(japanese_text_score("abcかな"), 0.4);
+		assert_eq!(japanese_text_score("한국어 かな"), 0.4);
+
+		assert!(is_japanese_text("ｶﾅ", 1.0));
+		assert!(is_japanese_text("abcかな", 0.4));
+		assert!(!is_japanese_text("abcかな", 0.5));
+		assert!(!is_japanese_text("123", 0.0));
+		assert!(!is_japanese_text("", 0.0));
+	}
+
+	#[test]
+	fn test_is_japanese_symbol() {
+		for chr in "〄〇〒〓〠〶〷〾〿￠￮㈀㈱㋾㌀㌔㏿⺀⻳⼀⿕".chars() {
+			assert!(is_japanese_symbol(chr), "is_japanese_symbol({})", chr);
+		}
+		for chr in "漢かカー々、。！a".chars() {
+			assert!(!is_japanese_symbol(chr), "!is_japanese_symbol({})", chr);
+		}
+	}
 }
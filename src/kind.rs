@@ -1,8 +1,16 @@
 //! Kind of japanese characters.
 
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitOr, Sub};
+use std::str::{Chars, FromStr};
+
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
+use super::count::count_by_kind;
+
 /// Enumeration with character kinds.
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -31,8 +39,8 @@ pub enum CharKind {
 
 	/// Japanese punctuation marks, i.e. characters that split words and phrases.
 	///
-	/// This includes U+3000 Ideographic Space. This does NOT include the
-	/// prolonged sound mark (see `BarLine`).
+	/// This does NOT include the prolonged sound mark (see `BarLine`) nor the
+	/// U+3000 Ideographic Space (see `Space`).
 	///
 	/// Examples of this are `、`, `。`, `・`, `〖`, `〗`, `「`, `」`.
 	///
@@ -57,16 +65,55 @@ pub enum CharKind {
 	/// Full-width and half-width roman punctuation (e.g. `：`, `；`, `＜`, `＝`, `＞`).
 	RomanPunctuation,
 
-	/// ASCII punctuation characters, including spaces.
+	/// ASCII punctuation characters. Note that the space is `Space`.
 	PunctuationASCII,
 
 	/// Letters from `A-Z` and digits `0-9`.
 	Romaji,
+
+	/// Korean Hangul syllables and jamo, including the halfwidth forms.
+	///
+	/// Note that the circled and parenthesized Hangul are `JapaneseSymbol`.
+	Hangul,
+
+	/// Chinese Bopomofo letters (e.g. `ㄅ`, `ㄆ`).
+	Bopomofo,
+
+	/// CJK punctuation forms that are not used in Japanese, such as the
+	/// vertical and small forms used in Chinese texts (e.g. `︐`, `﹐`).
+	CJKPunctuation,
+
+	/// Emoji characters with the default emoji presentation (e.g. `😀` and
+	/// `⭐`), including the joiner and variation selector used in emoji
+	/// sequences.
+	Emoji,
+
+	/// Symbols commonly used as building blocks of kaomoji faces, such as the
+	/// arms, eyes and mouths (e.g. `╯`, `°` and `□`).
+	///
+	/// Note that kaomoji also use characters of other kinds, such as the
+	/// `（` and `）` in `（╯°□°）╯`.
+	KaomojiSymbol,
+
+	/// Whitespace characters, including the ASCII space, the U+3000
+	/// Ideographic Space and line breaks.
+	Space,
+
+	/// Roman numeral characters (e.g. `Ⅻ` and `ⅻ`), common in Japanese
+	/// titles.
+	RomanNumeral,
+
+	/// Circled, parenthesized and full stop digits from 1 to 20 (e.g. `①`,
+	/// `⑴` and `⒈`).
+	///
+	/// Note that the circled numbers from 21 (e.g. `㉑`) are `JapaneseSymbol`.
+	EnclosedDigit,
 }
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn get_kind(chr: char) -> CharKind {
 	match chr {
+		space_range!() => CharKind::Space,
 		prolonged_mark_range!() => CharKind::BarLine,
 		hiragana_range!() => CharKind::Hiragana,
 		katakana_range!() => CharKind::Katakana,
@@ -80,6 +127,783 @@ pub fn get_kind(chr: char) -> CharKind {
 		japanese_punctuation_range!() => CharKind::JapanesePunctuation,
 		japanese_mark_range!() => CharKind::JapaneseMark,
 		japanese_symbol_range!() => CharKind::JapaneseSymbol,
+		hangul_range!() => CharKind::Hangul,
+		bopomofo_range!() => CharKind::Bopomofo,
+		cjk_punctuation_range!() => CharKind::CJKPunctuation,
+		emoji_range!() => CharKind::Emoji,
+		kaomoji_range!() => CharKind::KaomojiSymbol,
+		roman_numeral_range!() => CharKind::RomanNumeral,
+		enclosed_digit_range!() => CharKind::EnclosedDigit,
 		_ => CharKind::None,
 	}
 }
+
+/// Same as [get_kind] but taking a raw code point, for FFI and UTF-16
+/// callers.
+///
+/// Invalid code points (e.g. surrogates) have the `None` kind.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(get_kind_u32(0x3042), CharKind::Hiragana);
+/// assert_eq!(get_kind_u32(0xD800), CharKind::None);
+/// ```
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn get_kind_u32(code: u32) -> CharKind {
+	std::char::from_u32(code)
+		.map(get_kind)
+		.unwrap_or(CharKind::None)
+}
+
+/// Unicode block of a kanji character, returned by [get_kanji_block].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KanjiBlock {
+	/// CJK Unified Ideographs (U+4E00 to U+9FFF), which has all the common
+	/// kanji.
+	Unified,
+	/// CJK Unified Ideographs Extension A (U+3400 to U+4DBF).
+	ExtensionA,
+	/// CJK Unified Ideographs Extension B (U+20000 to U+2A6DF).
+	ExtensionB,
+	/// CJK Unified Ideographs Extension C (U+2A700 to U+2B73F).
+	ExtensionC,
+	/// CJK Unified Ideographs Extension D (U+2B740 to U+2B81F).
+	ExtensionD,
+	/// CJK Unified Ideographs Extension E (U+2B820 to U+2CEAF).
+	ExtensionE,
+	/// CJK Unified Ideographs Extension F (U+2CEB0 to U+2EBEF).
+	ExtensionF,
+	/// CJK Unified Ideographs Extension G (U+30000 to U+3134F).
+	ExtensionG,
+	/// CJK Unified Ideographs Extension H (U+31350 to U+323AF).
+	ExtensionH,
+	/// CJK Unified Ideographs Extension I (U+2EBF0 to U+2EE5F).
+	ExtensionI,
+	/// CJK Compatibility Ideographs (U+F900 to U+FAFF).
+	Compatibility,
+	/// CJK Compatibility Ideographs Supplement (U+2F800 to U+2FA1F).
+	CompatibilitySupplement,
+}
+
+/// Returns the Unicode block for a kanji character, as a finer-grained
+/// companion to [get_kind].
+///
+/// The extension blocks have increasingly rare characters, which are often
+/// missing from fonts.
+///
+/// Note that the compatibility ideographs are not classified as
+/// [CharKind::Kanji] by [get_kind], since those are normally replaced by
+/// their unified equivalent.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(get_kanji_block('漢'), Some(KanjiBlock::Unified));
+/// assert_eq!(get_kanji_block('𠀀'), Some(KanjiBlock::ExtensionB));
+/// assert_eq!(get_kanji_block('か'), None);
+/// ```
+pub fn get_kanji_block(chr: char) -> Option<KanjiBlock> {
+	let block = match chr {
+		'\u{4E00}'..='\u{9FFF}' => KanjiBlock::Unified,
+		'\u{3400}'..='\u{4DBF}' => KanjiBlock::ExtensionA,
+		'\u{20000}'..='\u{2A6DF}' => KanjiBlock::ExtensionB,
+		'\u{2A700}'..='\u{2B73F}' => KanjiBlock::ExtensionC,
+		'\u{2B740}'..='\u{2B81F}' => KanjiBlock::ExtensionD,
+		'\u{2B820}'..='\u{2CEAF}' => KanjiBlock::ExtensionE,
+		'\u{2CEB0}'..='\u{2EBEF}' => KanjiBlock::ExtensionF,
+		'\u{30000}'..='\u{3134F}' => KanjiBlock::ExtensionG,
+		'\u{31350}'..='\u{323AF}' => KanjiBlock::ExtensionH,
+		'\u{2EBF0}'..='\u{2EE5F}' => KanjiBlock::ExtensionI,
+		'\u{F900}'..='\u{FAFF}' => KanjiBlock::Compatibility,
+		'\u{2F800}'..='\u{2FA1F}' => KanjiBlock::CompatibilitySupplement,
+		_ => return None,
+	};
+	Some(block)
+}
+
+/// Kind of a [CharKind::JapaneseSymbol] character, returned by
+/// [get_symbol_kind].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+	/// Circled numbers (e.g. `㉑` and `㊿`).
+	EnclosedAlphanumeric,
+	/// Squared units and abbreviations, usually written with katakana or roman
+	/// letters (e.g. `㌔`, `㎏` and `㍻`).
+	SquaredUnit,
+	/// Circled and parenthesized kanji (e.g. `㊤` and `㈱`).
+	CircledKanji,
+	/// Any other symbol (e.g. `〒`, `㋐` and the kanji radicals).
+	Misc,
+}
+
+/// Returns the kind of a symbol character, as a finer-grained companion to
+/// [get_kind] for the [CharKind::JapaneseSymbol] characters.
+///
+/// Returns `None` for characters that are not a `JapaneseSymbol`.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(get_symbol_kind('㉑'), Some(SymbolKind::EnclosedAlphanumeric));
+/// assert_eq!(get_symbol_kind('㌔'), Some(SymbolKind::SquaredUnit));
+/// assert_eq!(get_symbol_kind('㈱'), Some(SymbolKind::CircledKanji));
+/// assert_eq!(get_symbol_kind('〒'), Some(SymbolKind::Misc));
+/// assert_eq!(get_symbol_kind('漢'), None);
+/// ```
+pub fn get_symbol_kind(chr: char) -> Option<SymbolKind> {
+	if get_kind(chr) != CharKind::JapaneseSymbol {
+		return None;
+	}
+	let kind = match chr {
+		// Circled numbers in the Enclosed CJK Letters and Months block
+		'\u{3248}'..='\u{325F}' | '\u{32B1}'..='\u{32BF}' => SymbolKind::EnclosedAlphanumeric,
+		// Parenthesized and circled ideographs
+		'\u{3220}'..='\u{3247}' | '\u{3280}'..='\u{32B0}' => SymbolKind::CircledKanji,
+		// Squared katakana words, latin abbreviations and era names, except
+		// for the telegraph symbols for hours and days
+		'\u{32CC}'..='\u{32CF}'
+		| '\u{3300}'..='\u{3357}'
+		| '\u{3371}'..='\u{33DF}'
+		| '\u{33FF}' => SymbolKind::SquaredUnit,
+		_ => SymbolKind::Misc,
+	};
+	Some(kind)
+}
+
+/// Returns an iterator over the characters of the input with their kind.
+///
+/// ```
+/// use kana::*;
+///
+/// let kinds = classify("かナ漢").collect::<Vec<_>>();
+/// assert_eq!(kinds, vec![
+///     ('か', CharKind::Hiragana),
+///     ('ナ', CharKind::Katakana),
+///     ('漢', CharKind::Kanji),
+/// ]);
+/// ```
+pub fn classify<'a>(input: &'a str) -> Classify<'a> {
+	Classify {
+		chars: input.chars(),
+	}
+}
+
+/// Iterator returned by [classify].
+#[derive(Clone, Debug)]
+pub struct Classify<'a> {
+	chars: Chars<'a>,
+}
+
+impl<'a> Classify<'a> {
+	/// Returns the remaining input that was not classified yet.
+	pub fn as_str(&self) -> &'a str {
+		self.chars.as_str()
+	}
+}
+
+impl<'a> Iterator for Classify<'a> {
+	type Item = (char, CharKind);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.chars.next().map(|chr| (chr, get_kind(chr)))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.chars.size_hint()
+	}
+}
+
+impl<'a> DoubleEndedIterator for Classify<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.chars.next_back().map(|chr| (chr, get_kind(chr)))
+	}
+}
+
+/// Returns an iterator over the runs of consecutive characters of the same
+/// kind in the input.
+///
+/// ```
+/// use kana::*;
+///
+/// let runs = kind_runs("漢字とカナ").collect::<Vec<_>>();
+/// assert_eq!(runs, vec![
+///     (CharKind::Kanji, "漢字"),
+///     (CharKind::Hiragana, "と"),
+///     (CharKind::Katakana, "カナ"),
+/// ]);
+/// ```
+pub fn kind_runs<'a>(input: &'a str) -> KindRuns<'a> {
+	KindRuns {
+		input,
+		kinds: classify(input),
+	}
+}
+
+/// Iterator returned by [kind_runs].
+#[derive(Clone, Debug)]
+pub struct KindRuns<'a> {
+	input: &'a str,
+	kinds: Classify<'a>,
+}
+
+impl<'a> Iterator for KindRuns<'a> {
+	type Item = (CharKind, &'a str);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.input.len() - self.kinds.as_str().len();
+		let (_, kind) = self.kinds.next()?;
+		loop {
+			let last = self.kinds.clone();
+			match self.kinds.next() {
+				Some((_, next)) if next == kind => {}
+				Some(_) => {
+					self.kinds = last;
+					break;
+				}
+				None => break,
+			}
+		}
+		let end = self.input.len() - self.kinds.as_str().len();
+		Some((kind, &self.input[start..end]))
+	}
+}
+
+/// All the [CharKind] values, in declaration order.
+pub(crate) const ALL_KINDS: [CharKind; 22] = [
+	CharKind::None,
+	CharKind::Hiragana,
+	CharKind::Katakana,
+	CharKind::KatakanaHalfWidth,
+	CharKind::Kanji,
+	CharKind::BarLine,
+	CharKind::JapanesePunctuation,
+	CharKind::JapaneseMark,
+	CharKind::JapaneseSymbol,
+	CharKind::RomanDigit,
+	CharKind::RomanLetter,
+	CharKind::RomanPunctuation,
+	CharKind::PunctuationASCII,
+	CharKind::Romaji,
+	CharKind::Hangul,
+	CharKind::Bopomofo,
+	CharKind::CJKPunctuation,
+	CharKind::Emoji,
+	CharKind::KaomojiSymbol,
+	CharKind::Space,
+	CharKind::RomanNumeral,
+	CharKind::EnclosedDigit,
+];
+
+impl CharKind {
+	/// Returns the stable name of the kind, which is the same as the variant
+	/// name (e.g. `"Hiragana"`).
+	///
+	/// This is the format used by `Display` and parsed by `FromStr`.
+	pub fn name(&self) -> &'static str {
+		match self {
+			CharKind::None => "None",
+			CharKind::Hiragana => "Hiragana",
+			CharKind::Katakana => "Katakana",
+			CharKind::KatakanaHalfWidth => "KatakanaHalfWidth",
+			CharKind::Kanji => "Kanji",
+			CharKind::BarLine => "BarLine",
+			CharKind::JapanesePunctuation => "JapanesePunctuation",
+			CharKind::JapaneseMark => "JapaneseMark",
+			CharKind::JapaneseSymbol => "JapaneseSymbol",
+			CharKind::RomanDigit => "RomanDigit",
+			CharKind::RomanLetter => "RomanLetter",
+			CharKind::RomanPunctuation => "RomanPunctuation",
+			CharKind::PunctuationASCII => "PunctuationASCII",
+			CharKind::Romaji => "Romaji",
+			CharKind::Hangul => "Hangul",
+			CharKind::Bopomofo => "Bopomofo",
+			CharKind::CJKPunctuation => "CJKPunctuation",
+			CharKind::Emoji => "Emoji",
+			CharKind::KaomojiSymbol => "KaomojiSymbol",
+			CharKind::Space => "Space",
+			CharKind::RomanNumeral => "RomanNumeral",
+			CharKind::EnclosedDigit => "EnclosedDigit",
+		}
+	}
+}
+
+impl fmt::Display for CharKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+impl FromStr for CharKind {
+	type Err = UnknownCharKind;
+
+	/// Parses the [CharKind::name] of a kind, ignoring ASCII case.
+	///
+	/// ```
+	/// use kana::*;
+	///
+	/// assert_eq!("Hiragana".parse(), Ok(CharKind::Hiragana));
+	/// assert_eq!("kanji".parse(), Ok(CharKind::Kanji));
+	/// assert!("Kana".parse::<CharKind>().is_err());
+	/// ```
+	fn from_str(s: &str) -> Result<CharKind, UnknownCharKind> {
+		ALL_KINDS
+			.iter()
+			.find(|kind| kind.name().eq_ignore_ascii_case(s))
+			.copied()
+			.ok_or_else(|| UnknownCharKind::Name(s.to_string()))
+	}
+}
+
+impl From<CharKind> for u8 {
+	/// Returns the numeric value of the kind, which is its index in the
+	/// declaration order (e.g. `0` for `None` and `1` for `Hiragana`).
+	///
+	/// The values are stable, since new kinds are only added at the end.
+	fn from(kind: CharKind) -> u8 {
+		kind as u8
+	}
+}
+
+impl TryFrom<u8> for CharKind {
+	type Error = UnknownCharKind;
+
+	/// Returns the kind for a numeric value from `u8::from`.
+	///
+	/// ```
+	/// use kana::*;
+	/// use std::convert::TryFrom;
+	///
+	/// let value = u8::from(CharKind::Katakana);
+	/// assert_eq!(CharKind::try_from(value), Ok(CharKind::Katakana));
+	/// assert!(CharKind::try_from(255).is_err());
+	/// ```
+	fn try_from(value: u8) -> Result<CharKind, UnknownCharKind> {
+		ALL_KINDS
+			.get(value as usize)
+			.copied()
+			.ok_or(UnknownCharKind::Value(value))
+	}
+}
+
+/// Error for parsing a [CharKind] from an unknown name or numeric value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnknownCharKind {
+	/// The name given to `FromStr`.
+	Name(String),
+	/// The value given to `TryFrom<u8>`.
+	Value(u8),
+}
+
+impl fmt::Display for UnknownCharKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			UnknownCharKind::Name(name) => write!(f, "unknown character kind `{}`", name),
+			UnknownCharKind::Value(value) => write!(f, "unknown character kind value {}", value),
+		}
+	}
+}
+
+impl std::error::Error for UnknownCharKind {}
+
+/// Number of characters of each [CharKind] in a string, returned by
+/// [kind_histogram].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct KindHistogram {
+	counts: [usize; ALL_KINDS.len()],
+}
+
+impl KindHistogram {
+	/// Creates the histogram from the count for each kind, indexed by the
+	/// kind.
+	pub(crate) fn from_counts(counts: [usize; ALL_KINDS.len()]) -> KindHistogram {
+		KindHistogram { counts }
+	}
+
+	/// Returns the number of characters of the kind.
+	pub fn count(&self, kind: CharKind) -> usize {
+		self.counts[kind as usize]
+	}
+
+	/// Returns the total number of characters.
+	pub fn total(&self) -> usize {
+		self.counts.iter().sum()
+	}
+
+	/// Returns the most common kind, or `None` for an empty string. Ties are
+	/// resolved by the declaration order of [CharKind].
+	pub fn dominant(&self) -> Option<CharKind> {
+		let mut dominant = None;
+		let mut max = 0;
+		for (kind, &count) in ALL_KINDS.iter().zip(self.counts.iter()) {
+			if count > max {
+				dominant = Some(*kind);
+				max = count;
+			}
+		}
+		dominant
+	}
+
+	/// Returns the fraction of the characters that are of the kind, from `0.0`
+	/// to `1.0`, or `0.0` for an empty string.
+	pub fn ratio(&self, kind: CharKind) -> f64 {
+		match self.total() {
+			0 => 0.0,
+			total => self.count(kind) as f64 / total as f64,
+		}
+	}
+
+	/// Returns an iterator over the kinds with at least one character and
+	/// their count, in declaration order.
+	pub fn iter(&self) -> impl Iterator<Item = (CharKind, usize)> + '_ {
+		ALL_KINDS
+			.iter()
+			.copied()
+			.zip(self.counts.iter().copied())
+			.filter(|x| x.1 > 0)
+	}
+}
+
+/// Returns the number of characters of each kind in the input.
+///
+/// ```
+/// use kana::*;
+///
+/// let histogram = kind_histogram("カタカナ語");
+/// assert_eq!(histogram.count(CharKind::Katakana), 4);
+/// assert_eq!(histogram.count(CharKind::Kanji), 1);
+/// assert_eq!(histogram.ratio(CharKind::Katakana), 0.8);
+/// ```
+pub fn kind_histogram<S: AsRef<str>>(input: S) -> KindHistogram {
+	count_by_kind(input)
+}
+
+/// Returns the most common kind of character in the input, or `None` if it
+/// is empty.
+///
+/// See [kind_histogram] for the count of each kind.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(dominant_kind("ヤマダ タロウ"), Some(CharKind::Katakana));
+/// assert_eq!(dominant_kind(""), None);
+/// ```
+pub fn dominant_kind<S: AsRef<str>>(input: S) -> Option<CharKind> {
+	kind_histogram(input).dominant()
+}
+
+/// A set of [CharKind] values, stored as a bitmask.
+///
+/// Sets can be built by combining kinds with `|`, and tested against a
+/// character with [matches](CharKindSet::matches):
+///
+/// ```
+/// use kana::*;
+///
+/// let set = CharKind::Hiragana | CharKind::Kanji | CharKind::BarLine;
+/// assert!(set.contains(CharKind::Kanji));
+/// assert!(set.matches('ー'));
+/// assert!(!set.matches('カ'));
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CharKindSet(u32);
+
+impl CharKindSet {
+	/// Returns an empty set.
+	pub const fn empty() -> CharKindSet {
+		CharKindSet(0)
+	}
+
+	/// Returns the set with all the kinds.
+	pub const fn all() -> CharKindSet {
+		CharKindSet((1 << ALL_KINDS.len()) - 1)
+	}
+
+	/// Returns true if the set contains the kind.
+	pub fn contains(self, kind: CharKind) -> bool {
+		self.0 & CharKindSet::from(kind).0 != 0
+	}
+
+	/// Returns true if the kind of the character is in the set.
+	pub fn matches(self, chr: char) -> bool {
+		self.contains(get_kind(chr))
+	}
+
+	/// Returns true if the set has no kinds.
+	pub fn is_empty(self) -> bool {
+		self.0 == 0
+	}
+
+	/// Adds the kind to the set.
+	pub fn insert(&mut self, kind: CharKind) {
+		self.0 |= CharKindSet::from(kind).0;
+	}
+
+	/// Removes the kind from the set.
+	pub fn remove(&mut self, kind: CharKind) {
+		self.0 &= !CharKindSet::from(kind).0;
+	}
+
+	/// Returns the kinds in either set.
+	pub fn union(self, other: CharKindSet) -> CharKindSet {
+		CharKindSet(self.0 | other.0)
+	}
+
+	/// Returns the kinds in both sets.
+	pub fn intersection(self, other: CharKindSet) -> CharKindSet {
+		CharKindSet(self.0 & other.0)
+	}
+
+	/// Returns the kinds in this set that are not in the other.
+	pub fn difference(self, other: CharKindSet) -> CharKindSet {
+		CharKindSet(self.0 & !other.0)
+	}
+
+	/// Returns an iterator over the kinds in the set, in declaration order.
+	pub fn iter(self) -> impl Iterator<Item = CharKind> {
+		ALL_KINDS
+			.iter()
+			.copied()
+			.filter(move |kind| self.contains(*kind))
+	}
+}
+
+impl From<CharKind> for CharKindSet {
+	fn from(kind: CharKind) -> CharKindSet {
+		CharKindSet(1 << kind as u32)
+	}
+}
+
+impl FromIterator<CharKind> for CharKindSet {
+	fn from_iter<I: IntoIterator<Item = CharKind>>(iter: I) -> CharKindSet {
+		let mut set = CharKindSet::empty();
+		for kind in iter {
+			set.insert(kind);
+		}
+		set
+	}
+}
+
+impl fmt::Debug for CharKindSet {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<T: Into<CharKindSet>> BitOr<T> for CharKindSet {
+	type Output = CharKindSet;
+
+	fn bitor(self, other: T) -> CharKindSet {
+		self.union(other.into())
+	}
+}
+
+impl<T: Into<CharKindSet>> BitAnd<T> for CharKindSet {
+	type Output = CharKindSet;
+
+	fn bitand(self, other: T) -> CharKindSet {
+		self.intersection(other.into())
+	}
+}
+
+impl<T: Into<CharKindSet>> Sub<T> for CharKindSet {
+	type Output = CharKindSet;
+
+	fn sub(self, other: T) -> CharKindSet {
+		self.difference(other.into())
+	}
+}
+
+impl<T: Into<CharKindSet>> BitOr<T> for CharKind {
+	type Output = CharKindSet;
+
+	fn bitor(self, other: T) -> CharKindSet {
+		CharKindSet::from(self).union(other.into())
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_get_kanji_block() {
+		let check = |chr: char, block: Option<KanjiBlock>| {
+			assert_eq!(
+				get_kanji_block(chr),
+				block,
+				"{} (U+{:04X})",
+				chr,
+				chr as u32
+			);
+		};
+		check('一', Some(KanjiBlock::Unified));
+		check('龯', Some(KanjiBlock::Unified));
+		check('㐀', Some(KanjiBlock::ExtensionA));
+		check('䶵', Some(KanjiBlock::ExtensionA));
+		check('𪛖', Some(KanjiBlock::ExtensionB));
+		check('𪜀', Some(KanjiBlock::ExtensionC));
+		check('𫝀', Some(KanjiBlock::ExtensionD));
+		check('\u{2B820}', Some(KanjiBlock::ExtensionE));
+		check('\u{2CEB0}', Some(KanjiBlock::ExtensionF));
+		check('\u{30000}', Some(KanjiBlock::ExtensionG));
+		check('\u{3134A}', Some(KanjiBlock::ExtensionG));
+		check('\u{31350}', Some(KanjiBlock::ExtensionH));
+		check('\u{323AF}', Some(KanjiBlock::ExtensionH));
+		check('\u{2EBF0}', Some(KanjiBlock::ExtensionI));
+		check('\u{2EE5D}', Some(KanjiBlock::ExtensionI));
+		check('\u{F900}', Some(KanjiBlock::Compatibility));
+		check('\u{2F800}', Some(KanjiBlock::CompatibilitySupplement));
+		check('々', None);
+		check('カ', None);
+		check('a', None);
+	}
+
+	#[test]
+	fn test_get_symbol_kind() {
+		let check = |input: &str, kind: Option<SymbolKind>| {
+			for chr in input.chars() {
+				assert_eq!(get_symbol_kind(chr), kind, "{} (U+{:04X})", chr, chr as u32);
+			}
+		};
+		check("㉑㉟㊱㊿㉈㉏", Some(SymbolKind::EnclosedAlphanumeric));
+		check("㌀㌔㍉㍗㍱㎏㎡㏄㍻㍿㋌㏿", Some(SymbolKind::SquaredUnit));
+		check("㈠㈱㈹㉃㊀㊤㊥㊦㊰", Some(SymbolKind::CircledKanji));
+		check("〄〇〒〓〠㈀㉠㋀㋐㋾㍘㏠⺀⼀￠", Some(SymbolKind::Misc));
+		check("漢かカ々。a", None);
+	}
+
+	#[test]
+	fn test_classify() {
+		assert_eq!(classify("").next(), None);
+
+		let mut iter = classify("aー、ｶ");
+		assert_eq!(iter.next(), Some(('a', CharKind::Romaji)));
+		assert_eq!(iter.as_str(), "ー、ｶ");
+		assert_eq!(iter.next_back(), Some(('ｶ', CharKind::KatakanaHalfWidth)));
+		assert_eq!(
+			iter.collect::<Vec<_>>(),
+			vec![
+				('ー', CharKind::BarLine),
+				('、', CharKind::JapanesePunctuation)
+			]
+		);
+	}
+
+	#[test]
+	fn test_kind_runs() {
+		assert_eq!(kind_runs("").next(), None);
+		assert_eq!(
+			kind_runs("a").collect::<Vec<_>>(),
+			vec![(CharKind::Romaji, "a")]
+		);
+		assert_eq!(
+			kind_runs("ラーメン屋abc 123、ｶﾅ").collect::<Vec<_>>(),
+			vec![
+				(CharKind::Katakana, "ラ"),
+				(CharKind::BarLine, "ー"),
+				(CharKind::Katakana, "メン"),
+				(CharKind::Kanji, "屋"),
+				(CharKind::Romaji, "abc"),
+				(CharKind::Space, " "),
+				(CharKind::Romaji, "123"),
+				(CharKind::JapanesePunctuation, "、"),
+				(CharKind::KatakanaHalfWidth, "ｶﾅ"),
+			]
+		);
+	}
+
+	#[test]
+	fn test_kind_histogram() {
+		let histogram = kind_histogram("");
+		assert_eq!(histogram.total(), 0);
+		assert_eq!(histogram.dominant(), None);
+		assert_eq!(histogram.ratio(CharKind::Kanji), 0.0);
+		assert_eq!(histogram.iter().count(), 0);
+
+		let histogram = kind_histogram("東京タワー 2019");
+		assert_eq!(histogram.total(), 10);
+		assert_eq!(histogram.dominant(), Some(CharKind::Romaji));
+		assert_eq!(
+			histogram.iter().collect::<Vec<_>>(),
+			vec![
+				(CharKind::Katakana, 2),
+				(CharKind::Kanji, 2),
+				(CharKind::BarLine, 1),
+				(CharKind::Romaji, 4),
+				(CharKind::Space, 1),
+			]
+		);
+
+		assert_eq!(dominant_kind("かな漢字"), Some(CharKind::Hiragana));
+		assert_eq!(dominant_kind("漢字かな"), Some(CharKind::Hiragana));
+		assert_eq!(dominant_kind("ﾔﾏﾀﾞ"), Some(CharKind::KatakanaHalfWidth));
+	}
+
+	#[test]
+	fn test_char_kind_names() {
+		for (index, &kind) in ALL_KINDS.iter().enumerate() {
+			assert_eq!(kind.to_string(), format!("{:?}", kind));
+			assert_eq!(kind.to_string().parse(), Ok(kind));
+			assert_eq!(kind.name().to_uppercase().parse(), Ok(kind));
+			assert_eq!(u8::from(kind) as usize, index);
+			assert_eq!(CharKind::try_from(u8::from(kind)), Ok(kind));
+		}
+		assert_eq!(
+			"".parse::<CharKind>(),
+			Err(UnknownCharKind::Name("".to_string()))
+		);
+		assert_eq!(
+			CharKind::try_from(ALL_KINDS.len() as u8),
+			Err(UnknownCharKind::Value(22))
+		);
+		assert_eq!(
+			UnknownCharKind::Name("x".to_string()).to_string(),
+			"unknown character kind `x`"
+		);
+		assert_eq!(
+			UnknownCharKind::Value(99).to_string(),
+			"unknown character kind value 99"
+		);
+	}
+
+	#[test]
+	fn test_char_kind_set() {
+		let empty = CharKindSet::empty();
+		assert!(empty.is_empty());
+		assert!(!empty.contains(CharKind::None));
+		assert!(!empty.matches('a'));
+		assert_eq!(CharKindSet::default(), empty);
+
+		let all = CharKindSet::all();
+		assert_eq!(all.iter().count(), ALL_KINDS.len());
+		assert!(all.matches('漢') && all.matches('a') && all.matches('\u{0}'));
+
+		let kana = CharKind::Hiragana | CharKind::Katakana;
+		assert!(kana.matches('か') && kana.matches('カ'));
+		assert!(!kana.matches('ー') && !kana.matches('ｶ'));
+
+		let mut set = kana | CharKind::BarLine;
+		assert!(set.matches('ー'));
+		set.remove(CharKind::BarLine);
+		assert_eq!(set, kana);
+		set.insert(CharKind::Kanji);
+		assert_eq!(set - CharKind::Kanji, kana);
+		assert_eq!(set & kana, kana);
+		assert_eq!((set & CharKind::Romaji).iter().collect::<Vec<_>>(), vec![]);
+		assert_eq!(
+			set.iter().collect::<Vec<_>>(),
+			vec![CharKind::Hiragana, CharKind::Katakana, CharKind::Kanji]
+		);
+		assert_eq!(
+			vec![CharKind::Kanji, CharKind::Hiragana, CharKind::Katakana]
+				.into_iter()
+				.collect::<CharKindSet>(),
+			set
+		);
+		assert_eq!(format!("{:?}", kana), "{Hiragana, Katakana}");
+	}
+}
@@ -0,0 +1,125 @@
+//! Line breaking following the basic Japanese line breaking rules (kinsoku
+//! shori).
+
+/// Returns true if the character cannot start a line, such as closing
+/// brackets, punctuation, small kana and the prolonged sound mark.
+///
+/// ```
+/// assert!(kana::is_line_start_forbidden('。'));
+/// assert!(kana::is_line_start_forbidden('ゃ'));
+/// assert!(!kana::is_line_start_forbidden('や'));
+/// ```
+pub fn is_line_start_forbidden(chr: char) -> bool {
+	matches!(
+		chr,
+		// Closing brackets and quotes
+		'）' | '〕' | '］' | '｝' | '〉' | '》' | '」' | '』' | '】' | '〙' | '〗' | '〟' | '｠'
+		| '’' | '”' | '»' | ')' | ']' | '}' | '｣'
+		// Punctuation
+		| '、' | '。' | '，' | '．' | '・' | '：' | '；' | '？' | '！' | '‼' | '⁇' | '⁈' | '⁉'
+		| '‐' | '゠' | '–' | '〜' | '～' | ',' | '.' | ':' | ';' | '?' | '!' | '｡' | '､' | '･'
+		// Marks
+		| 'ー' | 'ｰ' | '々' | '〻' | 'ゝ' | 'ゞ' | 'ヽ' | 'ヾ' | '゛' | '゜' | 'ﾞ' | 'ﾟ'
+		// Small kana
+		| 'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'っ' | 'ゃ' | 'ゅ' | 'ょ' | 'ゎ' | 'ゕ' | 'ゖ'
+		| 'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ' | 'ッ' | 'ャ' | 'ュ' | 'ョ' | 'ヮ' | 'ヵ' | 'ヶ'
+		| 'ｧ' | 'ｨ' | 'ｩ' | 'ｪ' | 'ｫ' | 'ｬ' | 'ｭ' | 'ｮ' | 'ｯ'
+		| '\u{31F0}'..='\u{31FF}'
+	)
+}
+
+/// Returns true if the character cannot end a line, which are the opening
+/// brackets and quotes.
+///
+/// ```
+/// assert!(kana::is_line_end_forbidden('「'));
+/// assert!(!kana::is_line_end_forbidden('」'));
+/// ```
+pub fn is_line_end_forbidden(chr: char) -> bool {
+	matches!(
+		chr,
+		'（' | '〔'
+			| '［' | '｛'
+			| '〈' | '《'
+			| '「' | '『'
+			| '【' | '〘'
+			| '〖' | '〝'
+			| '｟' | '‘'
+			| '“' | '«'
+			| '(' | '['
+			| '{' | '｢'
+	)
+}
+
+/// Breaks the text into lines of at most `max_chars` characters, without
+/// starting a line with a [forbidden character](is_line_start_forbidden) or
+/// ending it with [one](is_line_end_forbidden).
+///
+/// Breaks that would violate the rules are moved back, carrying characters
+/// to the next line. If that is not possible, because the entire line is
+/// made of forbidden characters, the line is allowed to go over the limit
+/// instead.
+///
+/// Existing line breaks are kept, without the line break characters. A
+/// `max_chars` of zero is handled as one.
+///
+/// ```
+/// let lines = kana::wrap_kinsoku("今日は晴れ。明日は雨。", 5);
+/// assert_eq!(lines, vec!["今日は晴", "れ。明日は", "雨。"]);
+/// ```
+pub fn wrap_kinsoku(text: &str, max_chars: usize) -> Vec<&str> {
+	let max_chars = max_chars.max(1);
+	let mut lines = Vec::new();
+	for line in text.lines() {
+		let chars = line.char_indices().collect::<Vec<_>>();
+		let offset = |index: usize| chars.get(index).map(|x| x.0).unwrap_or(line.len());
+		let is_valid = |index: usize| {
+			!is_line_start_forbidden(chars[index].1) && !is_line_end_forbidden(chars[index - 1].1)
+		};
+
+		let mut start = 0;
+		while chars.len() - start > max_chars {
+			let limit = start + max_chars;
+			let end = (start + 1..=limit)
+				.rev()
+				.find(|&index| is_valid(index))
+				.or_else(|| (limit + 1..chars.len()).find(|&index| is_valid(index)))
+				.unwrap_or(chars.len());
+			lines.push(&line[offset(start)..offset(end)]);
+			start = end;
+		}
+		if start < chars.len() || chars.is_empty() {
+			lines.push(&line[offset(start)..]);
+		}
+	}
+	lines
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_wrap_kinsoku() {
+		assert!(wrap_kinsoku("", 5).is_empty());
+		assert_eq!(wrap_kinsoku("あいう", 5), vec!["あいう"]);
+		assert_eq!(wrap_kinsoku("あいうえおか", 3), vec!["あいう", "えおか"]);
+		assert_eq!(wrap_kinsoku("あいう", 0), vec!["あ", "い", "う"]);
+		assert_eq!(wrap_kinsoku("あい\n\nう\r\n", 5), vec!["あい", "", "う"]);
+
+		// Line start
+		assert_eq!(
+			wrap_kinsoku("はい。いいえ。", 2),
+			vec!["は", "い。", "いい", "え。"]
+		);
+		assert_eq!(wrap_kinsoku("ちょっと", 2), vec!["ちょっ", "と"]);
+		assert_eq!(wrap_kinsoku("ラーメン", 1), vec!["ラー", "メ", "ン"]);
+		assert_eq!(wrap_kinsoku("ｼｮｰﾄ", 2), vec!["ｼｮｰ", "ﾄ"]);
+
+		// Line end
+		assert_eq!(wrap_kinsoku("あい「う」", 3), vec!["あい", "「う」"]);
+		assert_eq!(wrap_kinsoku("「『あ』」", 2), vec!["「『あ』」"]);
+	}
+}
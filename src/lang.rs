@@ -0,0 +1,110 @@
+//! Heuristic detection of the language of CJK text.
+//!
+//! The language is guessed from the scripts used: kana only appear in
+//! Japanese, Hangul in Korean and Bopomofo in Chinese, while kanji (Han
+//! characters) are shared by all three.
+
+use super::count::*;
+use super::kind::*;
+
+/// Language of a CJK text, returned by [detect_cjk_language].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CjkLanguage {
+	Japanese,
+	Chinese,
+	Korean,
+}
+
+/// Guessed language of a text, returned by [detect_cjk_language].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LanguageGuess {
+	/// The most likely language.
+	pub language: CjkLanguage,
+	/// Fraction of the CJK letters in the text that are consistent with the
+	/// language, from `0.0` to `1.0`.
+	pub confidence: f64,
+}
+
+/// Guesses the language of a CJK text, returning `None` if the text has no
+/// kana, Hangul, Bopomofo or kanji.
+///
+/// The language is the one whose specific script (kana, Hangul or Bopomofo)
+/// appears the most, with kanji counting towards any of them. Text written
+/// only in kanji is guessed as Chinese, since almost any Japanese sentence
+/// has some kana.
+///
+/// ```
+/// use kana::*;
+///
+/// let guess = detect_cjk_language("日本語のテキスト").unwrap();
+/// assert_eq!(guess.language, CjkLanguage::Japanese);
+/// assert_eq!(guess.confidence, 1.0);
+///
+/// let guess = detect_cjk_language("中文文本").unwrap();
+/// assert_eq!(guess.language, CjkLanguage::Chinese);
+///
+/// assert_eq!(detect_cjk_language("text"), None);
+/// ```
+pub fn detect_cjk_language<S: AsRef<str>>(input: S) -> Option<LanguageGuess> {
+	let counts = count_by_kind(input);
+	let kana = counts.count(CharKind::Hiragana)
+		+ counts.count(CharKind::Katakana)
+		+ counts.count(CharKind::KatakanaHalfWidth)
+		+ counts.count(CharKind::BarLine);
+	let hangul = counts.count(CharKind::Hangul);
+	let bopomofo = counts.count(CharKind::Bopomofo);
+	let kanji = counts.count(CharKind::Kanji);
+
+	let total = kana + hangul + bopomofo + kanji;
+	if total == 0 {
+		return None;
+	}
+
+	// Ties are resolved in the order of the candidates, with text that only
+	// has kanji defaulting to Chinese.
+	let candidates = [
+		(CjkLanguage::Japanese, kana),
+		(CjkLanguage::Korean, hangul),
+		(CjkLanguage::Chinese, bopomofo),
+	];
+	let (language, count) = candidates
+		.iter()
+		.fold((CjkLanguage::Chinese, 0), |best, &it| {
+			if it.1 > best.1 {
+				it
+			} else {
+				best
+			}
+		});
+	Some(LanguageGuess {
+		language,
+		confidence: (count + kanji) as f64 / total as f64,
+	})
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn guess(input: &str) -> Option<(CjkLanguage, f64)> {
+		detect_cjk_language(input).map(|x| (x.language, x.confidence))
+	}
+
+	#[test]
+	fn test_detect_cjk_language() {
+		assert_eq!(guess(""), None);
+		assert_eq!(guess("abc 123!"), None);
+		assert_eq!(guess("ひらがな"), Some((CjkLanguage::Japanese, 1.0)));
+		assert_eq!(guess("ｶﾀｶﾅ、ラーメン"), Some((CjkLanguage::Japanese, 1.0)));
+		assert_eq!(guess("東京に行く"), Some((CjkLanguage::Japanese, 1.0)));
+		assert_eq!(guess("한국어"), Some((CjkLanguage::Korean, 1.0)));
+		assert_eq!(guess("韓國語 한국어"), Some((CjkLanguage::Korean, 1.0)));
+		assert_eq!(guess("我是学生。"), Some((CjkLanguage::Chinese, 1.0)));
+		assert_eq!(guess("ㄅㄆㄇ"), Some((CjkLanguage::Chinese, 1.0)));
+		assert_eq!(guess("한국 かな"), Some((CjkLanguage::Japanese, 0.5)));
+		assert_eq!(guess("한국어 かな"), Some((CjkLanguage::Korean, 0.6)));
+		assert_eq!(guess("ㄅ中かな"), Some((CjkLanguage::Japanese, 0.75)));
+	}
+}
@@ -26,6 +26,7 @@ extern crate wasm_bindgen;
 mod wasm;
 
 mod constants;
+mod names;
 mod table;
 mod util;
 
@@ -35,12 +36,154 @@ mod ranges;
 mod is;
 pub use is::*;
 
+mod mora;
+pub use mora::*;
+
+mod meter;
+pub use meter::*;
+
+mod chunks;
+pub use chunks::*;
+
+mod ligature;
+pub use ligature::*;
+
+mod compose;
+pub use compose::*;
+
+mod width;
+pub use width::*;
+
+mod options;
+pub use options::*;
+
 mod to;
 pub use to::*;
 
 mod kind;
 pub use kind::*;
 
+mod class;
+pub use class::*;
+
+mod block;
+pub use block::*;
+
+mod count;
+pub use count::*;
+
+mod stats;
+pub use stats::*;
+
+mod lang;
+pub use lang::*;
+
+mod extract;
+pub use extract::*;
+
+mod spaces;
+pub use spaces::*;
+
+mod tokenize;
+pub use tokenize::*;
+
+mod sentences;
+pub use sentences::*;
+
+mod kinsoku;
+pub use kinsoku::*;
+
+mod info;
+pub use info::*;
+
+mod display;
+pub use display::*;
+
+mod report;
+pub use report::*;
+
+mod spans;
+pub use spans::*;
+
+mod trace;
+pub use trace::*;
+
+mod annotate;
+pub use annotate::*;
+
+mod ime;
+pub use ime::*;
+
+mod complete;
+pub use complete::*;
+
+mod candidates;
+pub use candidates::*;
+
+mod typo;
+pub use typo::*;
+
+mod joyo;
+pub use joyo::*;
+
+mod readability;
+pub use readability::*;
+
+mod fold;
+pub use fold::*;
+
+mod distance;
+pub use distance::*;
+
+mod phonetic;
+pub use phonetic::*;
+
+mod normalize;
+pub use normalize::*;
+
+mod confusable;
+pub use confusable::*;
+
+mod spoof;
+pub use spoof::*;
+
+mod spelling;
+pub use spelling::*;
+
+mod gojuon;
+pub use gojuon::*;
+
+mod validate;
+pub use validate::*;
+
+mod session;
+pub use session::*;
+
+mod emphasis;
+pub use emphasis::*;
+
+mod converter;
+pub use converter::*;
+
+mod stream;
+pub use stream::*;
+
+mod export;
+pub use export::*;
+
+mod reading;
+pub use reading::*;
+
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "cache")]
+pub use cache::*;
+
+#[cfg(feature = "readings")]
+mod readings;
+#[cfg(feature = "readings")]
+pub use readings::*;
+
 /// Expand hepburn style long vowels and katakana long marks `-` in the romaji
 /// string.
 pub fn expand_romaji<S: AsRef<str>>(input: S) -> String {
@@ -115,6 +258,11 @@ mod tests {
 		})
 	}
 
+	#[bench]
+	fn bench_count_kanji(b: &mut Bencher) {
+		b.iter(|| count_kanji(INPUT))
+	}
+
 	#[bench]
 	fn bench_get_kind(b: &mut Bencher) {
 		b.iter(|| {
@@ -185,10 +333,10 @@ mod tests {
 
 		const JAPANESE_PUNCTUATION: &'static str = concat!(
 			"゠・",
-			"　、。〃〈〉《》「」『』【】〔〕〖〗〘〙〚〛〜〝〞〟〰〽",
+			"、。〃〈〉《》「」『』【】〔〕〖〗〘〙〚〛〜〝〞〟〰〽",
 			"｟｠｡｢｣､･",
 		);
-		const JAPANESE_MARK: &'static str = concat!("゛゜ゝゞヽヾ", "々〆〱〲〳〴〵〻〼",);
+		const JAPANESE_MARK: &'static str = concat!("゛゜ゝゞヽヾ", "々〆〱〲〳〴〵〻〼", "ﾞﾟ");
 		const JAPANESE_SYMBOL: &'static str = concat!(
 			"〄〇〒〠〶〷〾〿〓￠￮",
 			"㈠㈡㈢㈣㈤㈥㈦㈧㈨㈩㈪㈫㈬㈭㈮㈯㈰㈱㈲㈳㈴㈵㈶㈷㈸㈹㈺㈻㈼㈽㈾㈿㉀㉁㉂㉃㊀㊁㊂㊃",
@@ -214,7 +362,7 @@ mod tests {
 			"⿌⿍⿎⿏⿐⿑⿒⿓⿔⿕",
 		);
 
-		const PUNCTUATION_ASCII: &'static str = " !\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+		const PUNCTUATION_ASCII: &'static str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
 		const KANJI: &'static str = concat!(
 			// Random assortment of kanji
 			"漢字日本語文字言語言葉",
@@ -238,8 +386,26 @@ mod tests {
 			"\u{2B820}\u{2CEAF}𫢸𫢹𫭼𫭽𫮃𫮄𫰜𫰛𫸩𬀩𬀪𬃊",
 			// Extension F
 			"\u{2CEB0}\u{2EBEF}",
+			// Extension G
+			"\u{30000}\u{3134A}",
+			// Extension H
+			"\u{31350}\u{323AF}",
+			// Extension I
+			"\u{2EBF0}\u{2EE5D}",
+		);
+		const HANGUL: &'static str = concat!(
+			"가각힣한국어ㄱㄴㅎㅏㅣㆎ",
+			"\u{1100}\u{11FF}\u{A960}\u{D7FB}\u{302E}\u{302F}",
+			"ﾡﾢﾾￂￚￜ",
 		);
-		const NONE: &'static str = concat!("〡〢〣〤〥〦〧〨〩〸〹〺ãç");
+		const BOPOMOFO: &'static str = "ㄅㄆㄇㄈㄩㄯㆠㆺ";
+		const CJK_PUNCTUATION: &'static str = "︐︑︒︓︙︰︱︵︶﹃﹄﹏﹐﹑﹒﹔﹗﹫";
+		const EMOJI: &'static str = "😀🙏🚀🤔🥺🫠🍣🎌👍🏻🇯🇵☔✨✅❌⌚⭐⭕🟠\u{200D}\u{FE0F}";
+		const KAOMOJI_SYMBOL: &'static str = "╯╰┻┳°□∀∇≧≦⊂∩⌒´¬¯·‿◕◡▽◉";
+		const SPACE: &'static str = " \t\n\r\u{A0}\u{2003}\u{2028}　";
+		const ROMAN_NUMERAL: &'static str = "ⅠⅡⅢⅣⅫⅬⅭⅮⅯⅰⅱⅻⅿↀↃↅↈ";
+		const ENCLOSED_DIGIT: &'static str = "①⑨⑩⑳⑴⒇⒈⒛";
+		const NONE: &'static str = concat!("〡〢〣〤〥〦〧〨〩〸〹〺ãç", "\u{2184}");
 
 		// Check the `CharKind` returned by `get_kind`:
 
@@ -260,9 +426,28 @@ mod tests {
 		check(JAPANESE_SYMBOL, CharKind::JapaneseSymbol);
 
 		check(PUNCTUATION_ASCII, CharKind::PunctuationASCII);
+		check(SPACE, CharKind::Space);
+
+		check(HANGUL, CharKind::Hangul);
+		check(BOPOMOFO, CharKind::Bopomofo);
+		check(CJK_PUNCTUATION, CharKind::CJKPunctuation);
+		check(EMOJI, CharKind::Emoji);
+		check(KAOMOJI_SYMBOL, CharKind::KaomojiSymbol);
+		check(ROMAN_NUMERAL, CharKind::RomanNumeral);
+		check(ENCLOSED_DIGIT, CharKind::EnclosedDigit);
 
 		check(NONE, CharKind::None);
 
+		// General symbols are not emoji nor kaomoji symbols
+		for chr in "○★☆☀❤─━│■".chars() {
+			let kind = get_kind(chr);
+			assert_ne!(kind, CharKind::Emoji, "{}", chr);
+			assert_ne!(kind, CharKind::KaomojiSymbol, "{}", chr);
+		}
+
+		// U+2184 is a letter, between the roman numerals
+		assert_ne!(get_kind('\u{2184}'), CharKind::RomanNumeral);
+
 		// Test the `is_xyz` functions:
 
 		for chr in HIRAGANA.chars() {
@@ -329,6 +514,7 @@ mod tests {
 				chr as u32,
 			)
 		}
+		assert!(is_japanese_punctuation('　'));
 
 		let all_marks = JAPANESE_MARK.to_string() + BAR_LINE;
 		for chr in all_marks.chars() {
@@ -0,0 +1,230 @@
+//! Circled katakana and squared katakana words.
+//!
+//! Those are compatibility characters (e.g. `㋐` and `㌔`) that expand to
+//! plain katakana.
+
+use std::borrow::Cow;
+
+use fnv::FnvHashMap;
+
+use super::chunks::*;
+use super::util::{char_in_range, katakana_to_hiragana};
+
+/// First and last circled katakana (`㋐` to `㋾`).
+const CIRCLED_START: u32 = 0x32D0;
+const CIRCLED_END: u32 = 0x32FE;
+
+/// First and last squared katakana words (`㌀` to `㍗`).
+const SQUARED_START: u32 = 0x3300;
+const SQUARED_END: u32 = 0x3357;
+
+/// Expansion of the circled katakana, in code point order.
+const CIRCLED_KATAKANA: &str = "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヰヱヲ";
+
+/// Expansion of the squared katakana words, in code point order.
+const SQUARED_KATAKANA: [&str; 88] = [
+	"アパート",
+	"アルファ",
+	"アンペア",
+	"アール",
+	"イニング",
+	"インチ",
+	"ウォン",
+	"エスクード",
+	"エーカー",
+	"オンス",
+	"オーム",
+	"カイリ",
+	"カラット",
+	"カロリー",
+	"ガロン",
+	"ガンマ",
+	"ギガ",
+	"ギニー",
+	"キュリー",
+	"ギルダー",
+	"キロ",
+	"キログラム",
+	"キロメートル",
+	"キロワット",
+	"グラム",
+	"グラムトン",
+	"クルゼイロ",
+	"クローネ",
+	"ケース",
+	"コルナ",
+	"コーポ",
+	"サイクル",
+	"サンチーム",
+	"シリング",
+	"センチ",
+	"セント",
+	"ダース",
+	"デシ",
+	"ドル",
+	"トン",
+	"ナノ",
+	"ノット",
+	"ハイツ",
+	"パーセント",
+	"パーツ",
+	"バーレル",
+	"ピアストル",
+	"ピクル",
+	"ピコ",
+	"ビル",
+	"ファラッド",
+	"フィート",
+	"ブッシェル",
+	"フラン",
+	"ヘクタール",
+	"ペソ",
+	"ペニヒ",
+	"ヘルツ",
+	"ペンス",
+	"ページ",
+	"ベータ",
+	"ポイント",
+	"ボルト",
+	"ホン",
+	"ポンド",
+	"ホール",
+	"ホーン",
+	"マイクロ",
+	"マイル",
+	"マッハ",
+	"マルク",
+	"マンション",
+	"ミクロン",
+	"ミリ",
+	"ミリバール",
+	"メガ",
+	"メガトン",
+	"メートル",
+	"ヤード",
+	"ヤール",
+	"ユアン",
+	"リットル",
+	"リラ",
+	"ルピー",
+	"ルーブル",
+	"レム",
+	"レントゲン",
+	"ワット",
+];
+
+/// Returns true if the character is a circled katakana or squared katakana
+/// word.
+#[inline]
+pub(crate) fn is_kana_ligature(chr: char) -> bool {
+	char_in_range(chr, CIRCLED_START, CIRCLED_END) || char_in_range(chr, SQUARED_START, SQUARED_END)
+}
+
+/// Returns the katakana for a circled katakana (e.g. `㋐` is `ア`) or squared
+/// katakana word (e.g. `㌔` is `キロ`).
+///
+/// Returns `None` for any other character.
+pub fn expand_kana_ligature(chr: char) -> Option<&'static str> {
+	let code = chr as u32;
+	if char_in_range(chr, CIRCLED_START, CIRCLED_END) {
+		let index = (code - CIRCLED_START) as usize * 3;
+		Some(&CIRCLED_KATAKANA[index..index + 3])
+	} else if char_in_range(chr, SQUARED_START, SQUARED_END) {
+		Some(SQUARED_KATAKANA[(code - SQUARED_START) as usize])
+	} else {
+		None
+	}
+}
+
+/// Replaces the circled katakana and squared katakana words in the input by
+/// their plain katakana.
+///
+/// Returns the input unchanged, without allocating, if there is nothing to
+/// replace.
+///
+/// ```
+/// assert_eq!(kana::expand_kana_ligatures("5㌔の㋒"), "5キロのウ");
+/// ```
+pub fn expand_kana_ligatures<'a>(input: &'a str) -> Cow<'a, str> {
+	if !input.chars().any(is_kana_ligature) {
+		return Cow::Borrowed(input);
+	}
+	let mut out = String::with_capacity(input.len() * 2);
+	for chr in input.chars() {
+		match expand_kana_ligature(chr) {
+			Some(text) => out.push_str(text),
+			None => out.push(chr),
+		}
+	}
+	Cow::Owned(out)
+}
+
+lazy_static! {
+	/// Hiragana conversion for each ligature.
+	static ref LIGATURE_HIRAGANA: FnvHashMap<char, &'static str> = {
+		let mut map = FnvHashMap::default();
+		for chr in ligatures() {
+			let text = expand_kana_ligature(chr).unwrap();
+			let text = text.chars().map(katakana_to_hiragana).collect::<String>();
+			map.insert(chr, &*Box::leak(text.into_boxed_str()));
+		}
+		map
+	};
+
+	/// Romaji conversion for each ligature and the last romaji chunk in it,
+	/// used for iteration marks.
+	static ref LIGATURE_ROMAJI: FnvHashMap<char, (&'static str, &'static str)> = {
+		let mut map = FnvHashMap::default();
+		for chr in ligatures() {
+			let mut chunks = RomajiChunks::new(expand_kana_ligature(chr).unwrap());
+			let mut text = String::new();
+			for chunk in chunks.by_ref() {
+				text.push_str(chunk.text);
+			}
+			map.insert(chr, (&*Box::leak(text.into_boxed_str()), chunks.state()));
+		}
+		map
+	};
+}
+
+fn ligatures() -> impl Iterator<Item = char> {
+	(CIRCLED_START..=CIRCLED_END)
+		.chain(SQUARED_START..=SQUARED_END)
+		.map(|code| std::char::from_u32(code).unwrap())
+}
+
+/// Returns the hiragana for a ligature character.
+pub(crate) fn ligature_hiragana(chr: char) -> &'static str {
+	LIGATURE_HIRAGANA[&chr]
+}
+
+/// Returns the romaji for a ligature character and its last romaji chunk.
+pub(crate) fn ligature_romaji(chr: char) -> (&'static str, &'static str) {
+	LIGATURE_ROMAJI[&chr]
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_expand_kana_ligature() {
+		assert_eq!(expand_kana_ligature('㋐'), Some("ア"));
+		assert_eq!(expand_kana_ligature('㋾'), Some("ヲ"));
+		assert_eq!(expand_kana_ligature('㌀'), Some("アパート"));
+		assert_eq!(expand_kana_ligature('㍗'), Some("ワット"));
+		assert_eq!(expand_kana_ligature('㋿'), None);
+		assert_eq!(expand_kana_ligature('ア'), None);
+
+		assert!(matches!(
+			expand_kana_ligatures("かな"),
+			Cow::Borrowed("かな")
+		));
+		assert_eq!(expand_kana_ligatures("㍉㋕"), "ミリカ");
+
+		assert_eq!(ligature_hiragana('㌔'), "きろ");
+		assert_eq!(ligature_romaji('㍑'), ("rittoru", "ru"));
+	}
+}
@@ -0,0 +1,157 @@
+//! Mora meter of Japanese poetry, such as haiku (5-7-5) and tanka
+//! (5-7-5-7-7).
+
+use super::mora::*;
+
+/// Options for [count_line_morae] and [check_meter_with].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MeterOptions {
+	/// Do not count the prolonged sound mark `ー` as a mora.
+	pub skip_choonpu: bool,
+	/// Do not count the small `っ` as a mora.
+	pub skip_sokuon: bool,
+}
+
+/// Fixed form of a poem, with the number of morae for each line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PoemForm {
+	/// Haiku or senryū, with three lines of 5, 7 and 5 morae.
+	Haiku,
+	/// Tanka, with five lines of 5, 7, 5, 7 and 7 morae.
+	Tanka,
+}
+
+impl PoemForm {
+	/// Returns the number of morae expected for each line.
+	pub fn pattern(&self) -> &'static [usize] {
+		match self {
+			PoemForm::Haiku => &[5, 7, 5],
+			PoemForm::Tanka => &[5, 7, 5, 7, 7],
+		}
+	}
+}
+
+/// Result of [check_meter], with the number of morae in each line of the
+/// poem.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeterCheck {
+	/// Number of morae in each line of the poem.
+	pub counts: Vec<usize>,
+	/// Number of morae expected for each line.
+	pub expected: &'static [usize],
+}
+
+impl MeterCheck {
+	/// Returns true if the poem has the expected number of lines and morae.
+	pub fn is_valid(&self) -> bool {
+		self.counts == self.expected
+	}
+
+	/// Returns the indexes of the lines with the wrong number of morae,
+	/// including any missing or extra lines.
+	pub fn invalid_lines(&self) -> Vec<usize> {
+		let lines = std::cmp::max(self.counts.len(), self.expected.len());
+		(0..lines)
+			.filter(|&index| self.counts.get(index) != self.expected.get(index))
+			.collect()
+	}
+}
+
+/// Returns the number of morae in a line of kana, as in
+/// [count_morae](fn.count_morae.html), using the given options.
+///
+/// ```
+/// let options = kana::MeterOptions {
+///     skip_sokuon: true,
+///     ..kana::MeterOptions::default()
+/// };
+/// assert_eq!(kana::count_line_morae("かっぱ", &Default::default()), 3);
+/// assert_eq!(kana::count_line_morae("かっぱ", &options), 2);
+/// ```
+pub fn count_line_morae(line: &str, options: &MeterOptions) -> usize {
+	morae(line)
+		.filter_map(get_mora_kind)
+		.filter(|&kind| match kind {
+			MoraKind::Choonpu => !options.skip_choonpu,
+			MoraKind::Sokuon => !options.skip_sokuon,
+			_ => true,
+		})
+		.count()
+}
+
+/// Counts the morae in each line of the poem and checks them against the
+/// form.
+///
+/// The poem must be written in kana, since the reading of kanji is not
+/// known. Lines are separated by line breaks, ignoring empty lines. A poem
+/// written in a single line is split at the spaces instead.
+///
+/// ```
+/// use kana::*;
+///
+/// let check = check_meter("ふるいけや かわずとびこむ みずのおと", PoemForm::Haiku);
+/// assert_eq!(check.counts, vec![5, 7, 5]);
+/// assert!(check.is_valid());
+/// ```
+pub fn check_meter(poem: &str, form: PoemForm) -> MeterCheck {
+	check_meter_with(poem, form, &MeterOptions::default())
+}
+
+/// Same as [check_meter] but with the given options.
+pub fn check_meter_with(poem: &str, form: PoemForm, options: &MeterOptions) -> MeterCheck {
+	let mut lines = poem
+		.lines()
+		.map(|line| line.trim())
+		.filter(|line| !line.is_empty())
+		.collect::<Vec<_>>();
+	if lines.len() == 1 {
+		lines = lines[0].split_whitespace().collect();
+	}
+	MeterCheck {
+		counts: lines
+			.iter()
+			.map(|line| count_line_morae(line, options))
+			.collect(),
+		expected: form.pattern(),
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_check_meter() {
+		let check = check_meter("", PoemForm::Haiku);
+		assert!(check.counts.is_empty());
+		assert!(!check.is_valid());
+		assert_eq!(check.invalid_lines(), vec![0, 1, 2]);
+
+		let haiku = "\nなつくさや\n  つわものどもが \n\nゆめのあと\n";
+		let check = check_meter(haiku, PoemForm::Haiku);
+		assert_eq!(check.counts, vec![5, 7, 5]);
+		assert!(check.is_valid());
+		assert!(check.invalid_lines().is_empty());
+		assert!(!check_meter(haiku, PoemForm::Tanka).is_valid());
+
+		let tanka = "しきしまの\u{3000}やまとごころを\u{3000}ひととはば\u{3000}\
+		             あさひににおう\u{3000}やまざくらばな";
+		let check = check_meter(tanka, PoemForm::Tanka);
+		assert_eq!(check.counts, vec![5, 7, 5, 7, 7]);
+		assert!(check.is_valid());
+
+		let check = check_meter("ちょっとまて ラーメンがのびる よるのそら", PoemForm::Haiku);
+		assert_eq!(check.counts, vec![5, 8, 5]);
+		assert_eq!(check.invalid_lines(), vec![1]);
+
+		let options = MeterOptions {
+			skip_choonpu: true,
+			skip_sokuon: true,
+		};
+		let check = check_meter_with("ちょっとまて ラーメンがのびる", PoemForm::Haiku, &options);
+		assert_eq!(check.counts, vec![4, 7]);
+		assert_eq!(check.invalid_lines(), vec![0, 2]);
+	}
+}
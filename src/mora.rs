@@ -0,0 +1,269 @@
+//! Mora segmentation of kana text.
+
+use super::info::{get_voicing, Voicing};
+
+/// Returns true if the character is a small kana that combines with the
+/// previous kana to form a single mora (e.g. the `ょ` in `きょ`).
+#[inline]
+#[rustfmt::skip]
+pub fn is_mora_glide(chr: char) -> bool {
+	matches!(
+		chr,
+		'ゃ' | 'ゅ' | 'ょ' | 'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'ゎ'
+			| 'ャ' | 'ュ' | 'ョ' | 'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ' | 'ヮ'
+			| 'ｬ' | 'ｭ' | 'ｮ' | 'ｧ' | 'ｨ' | 'ｩ' | 'ｪ' | 'ｫ'
+	)
+}
+
+/// Returns true for the voiced and semi-voiced marks that follow the kana
+/// they apply to, which are the halfwidth and combining marks.
+#[inline]
+pub(crate) fn is_voiced_mark(chr: char) -> bool {
+	matches!(chr, 'ﾞ' | 'ﾟ' | '\u{3099}' | '\u{309A}')
+}
+
+/// Returns true if the given byte position of the string is at a mora
+/// boundary.
+///
+/// Positions that are not at a character boundary are never considered a
+/// mora boundary.
+pub fn is_mora_boundary(input: &str, pos: usize) -> bool {
+	if pos == 0 || pos >= input.len() {
+		return pos <= input.len();
+	}
+	if !input.is_char_boundary(pos) {
+		return false;
+	}
+	let next = input[pos..].chars().next().unwrap();
+	let prev = input[..pos].chars().next_back().unwrap();
+	let prev_is_base = (super::is_kana(prev) && !is_mora_glide(prev)) || is_voiced_mark(prev);
+	!((is_mora_glide(next) && prev_is_base) || (is_voiced_mark(next) && super::is_kana(prev)))
+}
+
+/// Returns an iterator over the morae of the input, each as a slice of the
+/// input.
+///
+/// Small glides (e.g. `ょ` and `ｮ`) and halfwidth or combining voiced marks
+/// are kept with the preceding kana. Characters that are not kana are
+/// yielded individually.
+///
+/// ```
+/// let morae = kana::morae("きょうと").collect::<Vec<_>>();
+/// assert_eq!(morae, vec!["きょ", "う", "と"]);
+///
+/// let morae = kana::morae("ｼﾞｬｽﾞ").collect::<Vec<_>>();
+/// assert_eq!(morae, vec!["ｼﾞｬ", "ｽﾞ"]);
+/// ```
+pub fn morae<'a>(input: &'a str) -> Morae<'a> {
+	Morae::new(input)
+}
+
+/// Iterator over the morae of a string, returned by [morae].
+///
+/// Each mora is yielded as a slice of the input. Characters that are not kana
+/// are yielded individually.
+#[derive(Clone, Debug)]
+pub struct Morae<'a> {
+	input: &'a str,
+}
+
+impl<'a> Morae<'a> {
+	pub fn new(input: &'a str) -> Morae<'a> {
+		Morae { input }
+	}
+}
+
+impl<'a> Iterator for Morae<'a> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<&'a str> {
+		let mut chars = self.input.char_indices().peekable();
+		let (_, first) = chars.next()?;
+		let mut end = first.len_utf8();
+		if super::is_kana(first) && !is_mora_glide(first) {
+			if let Some(&(pos, chr)) = chars.peek() {
+				if is_voiced_mark(chr) {
+					end = pos + chr.len_utf8();
+					chars.next();
+				}
+			}
+			if let Some(&(pos, chr)) = chars.peek() {
+				if is_mora_glide(chr) {
+					end = pos + chr.len_utf8();
+				}
+			}
+		}
+		let (mora, rest) = self.input.split_at(end);
+		self.input = rest;
+		Some(mora)
+	}
+}
+
+/// Returns the number of morae in the kana of the input.
+///
+/// Small `ゃ`, `ゅ`, `ょ` and the other glides combine with the preceding
+/// kana (e.g. `きょ` is one mora), while `っ`, `ん`, the prolonged sound mark
+/// `ー` and the kana iteration marks (e.g. `ゝ`) count as one mora each.
+/// Characters other than kana, such as kanji and punctuation, are not
+/// counted.
+///
+/// ```
+/// assert_eq!(kana::count_morae("きょうと"), 3);
+/// assert_eq!(kana::count_morae("がっこう"), 4);
+/// assert_eq!(kana::count_morae("コーヒー、ください"), 8);
+/// ```
+pub fn count_morae<S: AsRef<str>>(input: S) -> usize {
+	Morae::new(input.as_ref())
+		.filter(|mora| {
+			let first = mora.chars().next().unwrap();
+			super::is_kana(first) || matches!(first, 'ゝ' | 'ゞ' | 'ヽ' | 'ヾ')
+		})
+		.count()
+}
+
+/// Kind of a mora, returned by [get_mora_kind].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MoraKind {
+	/// Unvoiced mora with a single kana (e.g. `か` and `あ`).
+	Plain,
+	/// Voiced mora (e.g. `が` and `ｶﾞ`).
+	Voiced,
+	/// Semi-voiced mora (e.g. `ぱ`).
+	SemiVoiced,
+	/// Contracted sound (yōon), which is a kana followed by a small glide
+	/// (e.g. `きょ`, `ぎょ` and `ファ`).
+	Yoon,
+	/// The small `っ` (sokuon), which doubles the following consonant.
+	Sokuon,
+	/// The prolonged sound mark `ー` (chōonpu).
+	Choonpu,
+	/// The syllabic `ん`.
+	SyllabicN,
+}
+
+/// Returns the kind of a single mora, as returned by [morae], or `None` if it
+/// is not kana.
+///
+/// A contracted mora is always `Yoon`, even if voiced (e.g. `ぎょ`). Kana
+/// iteration marks are `Plain` or `Voiced` (e.g. `ゝ` and `ゞ`).
+///
+/// ```
+/// use kana::*;
+///
+/// let kinds = morae("じゅっぷん").map(get_mora_kind).collect::<Vec<_>>();
+/// assert_eq!(kinds, vec![
+///     Some(MoraKind::Yoon),
+///     Some(MoraKind::Sokuon),
+///     Some(MoraKind::SemiVoiced),
+///     Some(MoraKind::SyllabicN),
+/// ]);
+/// ```
+pub fn get_mora_kind(mora: &str) -> Option<MoraKind> {
+	let mut chars = mora.chars();
+	let first = chars.next()?;
+	let kind = match first {
+		'っ' | 'ッ' | 'ｯ' => MoraKind::Sokuon,
+		'ー' | 'ｰ' => MoraKind::Choonpu,
+		'ん' | 'ン' | 'ﾝ' => MoraKind::SyllabicN,
+		'ゝ' | 'ヽ' => MoraKind::Plain,
+		'ゞ' | 'ヾ' => MoraKind::Voiced,
+		_ if !super::is_kana(first) => return None,
+		_ if mora.chars().count() > 1 && mora.ends_with(is_mora_glide) => MoraKind::Yoon,
+		_ if mora.contains(&['ﾞ', '\u{3099}'][..]) => MoraKind::Voiced,
+		_ if mora.contains(&['ﾟ', '\u{309A}'][..]) => MoraKind::SemiVoiced,
+		_ => match get_voicing(first) {
+			Some(Voicing::Voiced) => MoraKind::Voiced,
+			Some(Voicing::SemiVoiced) => MoraKind::SemiVoiced,
+			_ => MoraKind::Plain,
+		},
+	};
+	Some(kind)
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_count_morae() {
+		assert_eq!(count_morae(""), 0);
+		assert_eq!(count_morae("漢字 abc!"), 0);
+		assert_eq!(count_morae("しゃしん"), 3);
+		assert_eq!(count_morae("ちゅうごく"), 4);
+		assert_eq!(count_morae("きって"), 3);
+		assert_eq!(count_morae("ラーメン"), 4);
+		assert_eq!(count_morae("ファイル"), 3);
+		assert_eq!(count_morae("ゃあ"), 2);
+		assert_eq!(count_morae("いすゞ"), 3);
+		assert_eq!(count_morae("東京へ行きます"), 4);
+	}
+
+	#[test]
+	fn test_get_mora_kind() {
+		use MoraKind::*;
+
+		let kinds = |input: &str| morae(input).map(get_mora_kind).collect::<Vec<_>>();
+		assert_eq!(get_mora_kind(""), None);
+		assert_eq!(get_mora_kind("漢"), None);
+		assert_eq!(get_mora_kind("ﾞ"), None);
+		assert_eq!(
+			kinds("かがぱきょぎょっーん"),
+			vec![
+				Some(Plain),
+				Some(Voiced),
+				Some(SemiVoiced),
+				Some(Yoon),
+				Some(Yoon),
+				Some(Sokuon),
+				Some(Choonpu),
+				Some(SyllabicN),
+			]
+		);
+		assert_eq!(
+			kinds("ｶﾞﾊﾟｼｮｯｰﾝ"),
+			vec![
+				Some(Voiced),
+				Some(SemiVoiced),
+				Some(Yoon),
+				Some(Sokuon),
+				Some(Choonpu),
+				Some(SyllabicN),
+			]
+		);
+		assert_eq!(
+			kinds("か\u{3099}は\u{309A}ゝゞヴァゃ"),
+			vec![
+				Some(Voiced),
+				Some(SemiVoiced),
+				Some(Plain),
+				Some(Voiced),
+				Some(Yoon),
+				Some(Plain),
+			]
+		);
+	}
+
+	#[test]
+	fn test_morae() {
+		fn split(input: &str) -> Vec<&str> {
+			morae(input).collect()
+		}
+
+		assert!(split("").is_empty());
+		assert_eq!(split("しゅっぱつ"), vec!["しゅ", "っ", "ぱ", "つ"]);
+		assert_eq!(split("チェーン"), vec!["チェ", "ー", "ン"]);
+		assert_eq!(split("ｷｮｳﾄ"), vec!["ｷｮ", "ｳ", "ﾄ"]);
+		assert_eq!(split("ｷﾞｮｰｻﾞ"), vec!["ｷﾞｮ", "ｰ", "ｻﾞ"]);
+		assert_eq!(split("き\u{3099}ょ"), vec!["き\u{3099}ょ"]);
+		assert_eq!(split("漢ゃﾞa"), vec!["漢", "ゃ", "ﾞ", "a"]);
+
+		let input = "ｷﾞｮｰ";
+		let boundaries = (0..=input.len())
+			.filter(|&pos| is_mora_boundary(input, pos))
+			.collect::<Vec<_>>();
+		assert_eq!(boundaries, vec![0, 9, 12]);
+		assert_eq!(count_morae(input), 2);
+	}
+}
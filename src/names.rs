@@ -0,0 +1,1048 @@
+//! Unicode character names for the Japanese blocks, used by `char_name`.
+//!
+//! Generated from the Unicode Character Database (version 14.0.0). Unassigned
+//! code points have an empty name.
+
+/// Names for U+3000 to U+30FF (CJK Symbols and Punctuation, Hiragana and Katakana).
+#[rustfmt::skip]
+pub const CJK_SYMBOLS_AND_KANA: [&str; 256] = [
+	"IDEOGRAPHIC SPACE",
+	"IDEOGRAPHIC COMMA",
+	"IDEOGRAPHIC FULL STOP",
+	"DITTO MARK",
+	"JAPANESE INDUSTRIAL STANDARD SYMBOL",
+	"IDEOGRAPHIC ITERATION MARK",
+	"IDEOGRAPHIC CLOSING MARK",
+	"IDEOGRAPHIC NUMBER ZERO",
+	"LEFT ANGLE BRACKET",
+	"RIGHT ANGLE BRACKET",
+	"LEFT DOUBLE ANGLE BRACKET",
+	"RIGHT DOUBLE ANGLE BRACKET",
+	"LEFT CORNER BRACKET",
+	"RIGHT CORNER BRACKET",
+	"LEFT WHITE CORNER BRACKET",
+	"RIGHT WHITE CORNER BRACKET",
+	"LEFT BLACK LENTICULAR BRACKET",
+	"RIGHT BLACK LENTICULAR BRACKET",
+	"POSTAL MARK",
+	"GETA MARK",
+	"LEFT TORTOISE SHELL BRACKET",
+	"RIGHT TORTOISE SHELL BRACKET",
+	"LEFT WHITE LENTICULAR BRACKET",
+	"RIGHT WHITE LENTICULAR BRACKET",
+	"LEFT WHITE TORTOISE SHELL BRACKET",
+	"RIGHT WHITE TORTOISE SHELL BRACKET",
+	"LEFT WHITE SQUARE BRACKET",
+	"RIGHT WHITE SQUARE BRACKET",
+	"WAVE DASH",
+	"REVERSED DOUBLE PRIME QUOTATION MARK",
+	"DOUBLE PRIME QUOTATION MARK",
+	"LOW DOUBLE PRIME QUOTATION MARK",
+	"POSTAL MARK FACE",
+	"HANGZHOU NUMERAL ONE",
+	"HANGZHOU NUMERAL TWO",
+	"HANGZHOU NUMERAL THREE",
+	"HANGZHOU NUMERAL FOUR",
+	"HANGZHOU NUMERAL FIVE",
+	"HANGZHOU NUMERAL SIX",
+	"HANGZHOU NUMERAL SEVEN",
+	"HANGZHOU NUMERAL EIGHT",
+	"HANGZHOU NUMERAL NINE",
+	"IDEOGRAPHIC LEVEL TONE MARK",
+	"IDEOGRAPHIC RISING TONE MARK",
+	"IDEOGRAPHIC DEPARTING TONE MARK",
+	"IDEOGRAPHIC ENTERING TONE MARK",
+	"HANGUL SINGLE DOT TONE MARK",
+	"HANGUL DOUBLE DOT TONE MARK",
+	"WAVY DASH",
+	"VERTICAL KANA REPEAT MARK",
+	"VERTICAL KANA REPEAT WITH VOICED SOUND MARK",
+	"VERTICAL KANA REPEAT MARK UPPER HALF",
+	"VERTICAL KANA REPEAT WITH VOICED SOUND MARK UPPER HALF",
+	"VERTICAL KANA REPEAT MARK LOWER HALF",
+	"CIRCLED POSTAL MARK",
+	"IDEOGRAPHIC TELEGRAPH LINE FEED SEPARATOR SYMBOL",
+	"HANGZHOU NUMERAL TEN",
+	"HANGZHOU NUMERAL TWENTY",
+	"HANGZHOU NUMERAL THIRTY",
+	"VERTICAL IDEOGRAPHIC ITERATION MARK",
+	"MASU MARK",
+	"PART ALTERNATION MARK",
+	"IDEOGRAPHIC VARIATION INDICATOR",
+	"IDEOGRAPHIC HALF FILL SPACE",
+	"",
+	"HIRAGANA LETTER SMALL A",
+	"HIRAGANA LETTER A",
+	"HIRAGANA LETTER SMALL I",
+	"HIRAGANA LETTER I",
+	"HIRAGANA LETTER SMALL U",
+	"HIRAGANA LETTER U",
+	"HIRAGANA LETTER SMALL E",
+	"HIRAGANA LETTER E",
+	"HIRAGANA LETTER SMALL O",
+	"HIRAGANA LETTER O",
+	"HIRAGANA LETTER KA",
+	"HIRAGANA LETTER GA",
+	"HIRAGANA LETTER KI",
+	"HIRAGANA LETTER GI",
+	"HIRAGANA LETTER KU",
+	"HIRAGANA LETTER GU",
+	"HIRAGANA LETTER KE",
+	"HIRAGANA LETTER GE",
+	"HIRAGANA LETTER KO",
+	"HIRAGANA LETTER GO",
+	"HIRAGANA LETTER SA",
+	"HIRAGANA LETTER ZA",
+	"HIRAGANA LETTER SI",
+	"HIRAGANA LETTER ZI",
+	"HIRAGANA LETTER SU",
+	"HIRAGANA LETTER ZU",
+	"HIRAGANA LETTER SE",
+	"HIRAGANA LETTER ZE",
+	"HIRAGANA LETTER SO",
+	"HIRAGANA LETTER ZO",
+	"HIRAGANA LETTER TA",
+	"HIRAGANA LETTER DA",
+	"HIRAGANA LETTER TI",
+	"HIRAGANA LETTER DI",
+	"HIRAGANA LETTER SMALL TU",
+	"HIRAGANA LETTER TU",
+	"HIRAGANA LETTER DU",
+	"HIRAGANA LETTER TE",
+	"HIRAGANA LETTER DE",
+	"HIRAGANA LETTER TO",
+	"HIRAGANA LETTER DO",
+	"HIRAGANA LETTER NA",
+	"HIRAGANA LETTER NI",
+	"HIRAGANA LETTER NU",
+	"HIRAGANA LETTER NE",
+	"HIRAGANA LETTER NO",
+	"HIRAGANA LETTER HA",
+	"HIRAGANA LETTER BA",
+	"HIRAGANA LETTER PA",
+	"HIRAGANA LETTER HI",
+	"HIRAGANA LETTER BI",
+	"HIRAGANA LETTER PI",
+	"HIRAGANA LETTER HU",
+	"HIRAGANA LETTER BU",
+	"HIRAGANA LETTER PU",
+	"HIRAGANA LETTER HE",
+	"HIRAGANA LETTER BE",
+	"HIRAGANA LETTER PE",
+	"HIRAGANA LETTER HO",
+	"HIRAGANA LETTER BO",
+	"HIRAGANA LETTER PO",
+	"HIRAGANA LETTER MA",
+	"HIRAGANA LETTER MI",
+	"HIRAGANA LETTER MU",
+	"HIRAGANA LETTER ME",
+	"HIRAGANA LETTER MO",
+	"HIRAGANA LETTER SMALL YA",
+	"HIRAGANA LETTER YA",
+	"HIRAGANA LETTER SMALL YU",
+	"HIRAGANA LETTER YU",
+	"HIRAGANA LETTER SMALL YO",
+	"HIRAGANA LETTER YO",
+	"HIRAGANA LETTER RA",
+	"HIRAGANA LETTER RI",
+	"HIRAGANA LETTER RU",
+	"HIRAGANA LETTER RE",
+	"HIRAGANA LETTER RO",
+	"HIRAGANA LETTER SMALL WA",
+	"HIRAGANA LETTER WA",
+	"HIRAGANA LETTER WI",
+	"HIRAGANA LETTER WE",
+	"HIRAGANA LETTER WO",
+	"HIRAGANA LETTER N",
+	"HIRAGANA LETTER VU",
+	"HIRAGANA LETTER SMALL KA",
+	"HIRAGANA LETTER SMALL KE",
+	"",
+	"",
+	"COMBINING KATAKANA-HIRAGANA VOICED SOUND MARK",
+	"COMBINING KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK",
+	"KATAKANA-HIRAGANA VOICED SOUND MARK",
+	"KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK",
+	"HIRAGANA ITERATION MARK",
+	"HIRAGANA VOICED ITERATION MARK",
+	"HIRAGANA DIGRAPH YORI",
+	"KATAKANA-HIRAGANA DOUBLE HYPHEN",
+	"KATAKANA LETTER SMALL A",
+	"KATAKANA LETTER A",
+	"KATAKANA LETTER SMALL I",
+	"KATAKANA LETTER I",
+	"KATAKANA LETTER SMALL U",
+	"KATAKANA LETTER U",
+	"KATAKANA LETTER SMALL E",
+	"KATAKANA LETTER E",
+	"KATAKANA LETTER SMALL O",
+	"KATAKANA LETTER O",
+	"KATAKANA LETTER KA",
+	"KATAKANA LETTER GA",
+	"KATAKANA LETTER KI",
+	"KATAKANA LETTER GI",
+	"KATAKANA LETTER KU",
+	"KATAKANA LETTER GU",
+	"KATAKANA LETTER KE",
+	"KATAKANA LETTER GE",
+	"KATAKANA LETTER KO",
+	"KATAKANA LETTER GO",
+	"KATAKANA LETTER SA",
+	"KATAKANA LETTER ZA",
+	"KATAKANA LETTER SI",
+	"KATAKANA LETTER ZI",
+	"KATAKANA LETTER SU",
+	"KATAKANA LETTER ZU",
+	"KATAKANA LETTER SE",
+	"KATAKANA LETTER ZE",
+	"KATAKANA LETTER SO",
+	"KATAKANA LETTER ZO",
+	"KATAKANA LETTER TA",
+	"KATAKANA LETTER DA",
+	"KATAKANA LETTER TI",
+	"KATAKANA LETTER DI",
+	"KATAKANA LETTER SMALL TU",
+	"KATAKANA LETTER TU",
+	"KATAKANA LETTER DU",
+	"KATAKANA LETTER TE",
+	"KATAKANA LETTER DE",
+	"KATAKANA LETTER TO",
+	"KATAKANA LETTER DO",
+	"KATAKANA LETTER NA",
+	"KATAKANA LETTER NI",
+	"KATAKANA LETTER NU",
+	"KATAKANA LETTER NE",
+	"KATAKANA LETTER NO",
+	"KATAKANA LETTER HA",
+	"KATAKANA LETTER BA",
+	"KATAKANA LETTER PA",
+	"KATAKANA LETTER HI",
+	"KATAKANA LETTER BI",
+	"KATAKANA LETTER PI",
+	"KATAKANA LETTER HU",
+	"KATAKANA LETTER BU",
+	"KATAKANA LETTER PU",
+	"KATAKANA LETTER HE",
+	"KATAKANA LETTER BE",
+	"KATAKANA LETTER PE",
+	"KATAKANA LETTER HO",
+	"KATAKANA LETTER BO",
+	"KATAKANA LETTER PO",
+	"KATAKANA LETTER MA",
+	"KATAKANA LETTER MI",
+	"KATAKANA LETTER MU",
+	"KATAKANA LETTER ME",
+	"KATAKANA LETTER MO",
+	"KATAKANA LETTER SMALL YA",
+	"KATAKANA LETTER YA",
+	"KATAKANA LETTER SMALL YU",
+	"KATAKANA LETTER YU",
+	"KATAKANA LETTER SMALL YO",
+	"KATAKANA LETTER YO",
+	"KATAKANA LETTER RA",
+	"KATAKANA LETTER RI",
+	"KATAKANA LETTER RU",
+	"KATAKANA LETTER RE",
+	"KATAKANA LETTER RO",
+	"KATAKANA LETTER SMALL WA",
+	"KATAKANA LETTER WA",
+	"KATAKANA LETTER WI",
+	"KATAKANA LETTER WE",
+	"KATAKANA LETTER WO",
+	"KATAKANA LETTER N",
+	"KATAKANA LETTER VU",
+	"KATAKANA LETTER SMALL KA",
+	"KATAKANA LETTER SMALL KE",
+	"KATAKANA LETTER VA",
+	"KATAKANA LETTER VI",
+	"KATAKANA LETTER VE",
+	"KATAKANA LETTER VO",
+	"KATAKANA MIDDLE DOT",
+	"KATAKANA-HIRAGANA PROLONGED SOUND MARK",
+	"KATAKANA ITERATION MARK",
+	"KATAKANA VOICED ITERATION MARK",
+	"KATAKANA DIGRAPH KOTO",
+];
+
+/// Names for U+31F0 to U+31FF (Katakana Phonetic Extensions).
+#[rustfmt::skip]
+pub const KATAKANA_EXTENSIONS: [&str; 16] = [
+	"KATAKANA LETTER SMALL KU",
+	"KATAKANA LETTER SMALL SI",
+	"KATAKANA LETTER SMALL SU",
+	"KATAKANA LETTER SMALL TO",
+	"KATAKANA LETTER SMALL NU",
+	"KATAKANA LETTER SMALL HA",
+	"KATAKANA LETTER SMALL HI",
+	"KATAKANA LETTER SMALL HU",
+	"KATAKANA LETTER SMALL HE",
+	"KATAKANA LETTER SMALL HO",
+	"KATAKANA LETTER SMALL MU",
+	"KATAKANA LETTER SMALL RA",
+	"KATAKANA LETTER SMALL RI",
+	"KATAKANA LETTER SMALL RU",
+	"KATAKANA LETTER SMALL RE",
+	"KATAKANA LETTER SMALL RO",
+];
+
+/// Names for U+3200 to U+33FF (Enclosed CJK Letters and Months and CJK Compatibility).
+#[rustfmt::skip]
+pub const ENCLOSED_AND_COMPATIBILITY: [&str; 512] = [
+	"PARENTHESIZED HANGUL KIYEOK",
+	"PARENTHESIZED HANGUL NIEUN",
+	"PARENTHESIZED HANGUL TIKEUT",
+	"PARENTHESIZED HANGUL RIEUL",
+	"PARENTHESIZED HANGUL MIEUM",
+	"PARENTHESIZED HANGUL PIEUP",
+	"PARENTHESIZED HANGUL SIOS",
+	"PARENTHESIZED HANGUL IEUNG",
+	"PARENTHESIZED HANGUL CIEUC",
+	"PARENTHESIZED HANGUL CHIEUCH",
+	"PARENTHESIZED HANGUL KHIEUKH",
+	"PARENTHESIZED HANGUL THIEUTH",
+	"PARENTHESIZED HANGUL PHIEUPH",
+	"PARENTHESIZED HANGUL HIEUH",
+	"PARENTHESIZED HANGUL KIYEOK A",
+	"PARENTHESIZED HANGUL NIEUN A",
+	"PARENTHESIZED HANGUL TIKEUT A",
+	"PARENTHESIZED HANGUL RIEUL A",
+	"PARENTHESIZED HANGUL MIEUM A",
+	"PARENTHESIZED HANGUL PIEUP A",
+	"PARENTHESIZED HANGUL SIOS A",
+	"PARENTHESIZED HANGUL IEUNG A",
+	"PARENTHESIZED HANGUL CIEUC A",
+	"PARENTHESIZED HANGUL CHIEUCH A",
+	"PARENTHESIZED HANGUL KHIEUKH A",
+	"PARENTHESIZED HANGUL THIEUTH A",
+	"PARENTHESIZED HANGUL PHIEUPH A",
+	"PARENTHESIZED HANGUL HIEUH A",
+	"PARENTHESIZED HANGUL CIEUC U",
+	"PARENTHESIZED KOREAN CHARACTER OJEON",
+	"PARENTHESIZED KOREAN CHARACTER O HU",
+	"",
+	"PARENTHESIZED IDEOGRAPH ONE",
+	"PARENTHESIZED IDEOGRAPH TWO",
+	"PARENTHESIZED IDEOGRAPH THREE",
+	"PARENTHESIZED IDEOGRAPH FOUR",
+	"PARENTHESIZED IDEOGRAPH FIVE",
+	"PARENTHESIZED IDEOGRAPH SIX",
+	"PARENTHESIZED IDEOGRAPH SEVEN",
+	"PARENTHESIZED IDEOGRAPH EIGHT",
+	"PARENTHESIZED IDEOGRAPH NINE",
+	"PARENTHESIZED IDEOGRAPH TEN",
+	"PARENTHESIZED IDEOGRAPH MOON",
+	"PARENTHESIZED IDEOGRAPH FIRE",
+	"PARENTHESIZED IDEOGRAPH WATER",
+	"PARENTHESIZED IDEOGRAPH WOOD",
+	"PARENTHESIZED IDEOGRAPH METAL",
+	"PARENTHESIZED IDEOGRAPH EARTH",
+	"PARENTHESIZED IDEOGRAPH SUN",
+	"PARENTHESIZED IDEOGRAPH STOCK",
+	"PARENTHESIZED IDEOGRAPH HAVE",
+	"PARENTHESIZED IDEOGRAPH SOCIETY",
+	"PARENTHESIZED IDEOGRAPH NAME",
+	"PARENTHESIZED IDEOGRAPH SPECIAL",
+	"PARENTHESIZED IDEOGRAPH FINANCIAL",
+	"PARENTHESIZED IDEOGRAPH CONGRATULATION",
+	"PARENTHESIZED IDEOGRAPH LABOR",
+	"PARENTHESIZED IDEOGRAPH REPRESENT",
+	"PARENTHESIZED IDEOGRAPH CALL",
+	"PARENTHESIZED IDEOGRAPH STUDY",
+	"PARENTHESIZED IDEOGRAPH SUPERVISE",
+	"PARENTHESIZED IDEOGRAPH ENTERPRISE",
+	"PARENTHESIZED IDEOGRAPH RESOURCE",
+	"PARENTHESIZED IDEOGRAPH ALLIANCE",
+	"PARENTHESIZED IDEOGRAPH FESTIVAL",
+	"PARENTHESIZED IDEOGRAPH REST",
+	"PARENTHESIZED IDEOGRAPH SELF",
+	"PARENTHESIZED IDEOGRAPH REACH",
+	"CIRCLED IDEOGRAPH QUESTION",
+	"CIRCLED IDEOGRAPH KINDERGARTEN",
+	"CIRCLED IDEOGRAPH SCHOOL",
+	"CIRCLED IDEOGRAPH KOTO",
+	"CIRCLED NUMBER TEN ON BLACK SQUARE",
+	"CIRCLED NUMBER TWENTY ON BLACK SQUARE",
+	"CIRCLED NUMBER THIRTY ON BLACK SQUARE",
+	"CIRCLED NUMBER FORTY ON BLACK SQUARE",
+	"CIRCLED NUMBER FIFTY ON BLACK SQUARE",
+	"CIRCLED NUMBER SIXTY ON BLACK SQUARE",
+	"CIRCLED NUMBER SEVENTY ON BLACK SQUARE",
+	"CIRCLED NUMBER EIGHTY ON BLACK SQUARE",
+	"PARTNERSHIP SIGN",
+	"CIRCLED NUMBER TWENTY ONE",
+	"CIRCLED NUMBER TWENTY TWO",
+	"CIRCLED NUMBER TWENTY THREE",
+	"CIRCLED NUMBER TWENTY FOUR",
+	"CIRCLED NUMBER TWENTY FIVE",
+	"CIRCLED NUMBER TWENTY SIX",
+	"CIRCLED NUMBER TWENTY SEVEN",
+	"CIRCLED NUMBER TWENTY EIGHT",
+	"CIRCLED NUMBER TWENTY NINE",
+	"CIRCLED NUMBER THIRTY",
+	"CIRCLED NUMBER THIRTY ONE",
+	"CIRCLED NUMBER THIRTY TWO",
+	"CIRCLED NUMBER THIRTY THREE",
+	"CIRCLED NUMBER THIRTY FOUR",
+	"CIRCLED NUMBER THIRTY FIVE",
+	"CIRCLED HANGUL KIYEOK",
+	"CIRCLED HANGUL NIEUN",
+	"CIRCLED HANGUL TIKEUT",
+	"CIRCLED HANGUL RIEUL",
+	"CIRCLED HANGUL MIEUM",
+	"CIRCLED HANGUL PIEUP",
+	"CIRCLED HANGUL SIOS",
+	"CIRCLED HANGUL IEUNG",
+	"CIRCLED HANGUL CIEUC",
+	"CIRCLED HANGUL CHIEUCH",
+	"CIRCLED HANGUL KHIEUKH",
+	"CIRCLED HANGUL THIEUTH",
+	"CIRCLED HANGUL PHIEUPH",
+	"CIRCLED HANGUL HIEUH",
+	"CIRCLED HANGUL KIYEOK A",
+	"CIRCLED HANGUL NIEUN A",
+	"CIRCLED HANGUL TIKEUT A",
+	"CIRCLED HANGUL RIEUL A",
+	"CIRCLED HANGUL MIEUM A",
+	"CIRCLED HANGUL PIEUP A",
+	"CIRCLED HANGUL SIOS A",
+	"CIRCLED HANGUL IEUNG A",
+	"CIRCLED HANGUL CIEUC A",
+	"CIRCLED HANGUL CHIEUCH A",
+	"CIRCLED HANGUL KHIEUKH A",
+	"CIRCLED HANGUL THIEUTH A",
+	"CIRCLED HANGUL PHIEUPH A",
+	"CIRCLED HANGUL HIEUH A",
+	"CIRCLED KOREAN CHARACTER CHAMKO",
+	"CIRCLED KOREAN CHARACTER JUEUI",
+	"CIRCLED HANGUL IEUNG U",
+	"KOREAN STANDARD SYMBOL",
+	"CIRCLED IDEOGRAPH ONE",
+	"CIRCLED IDEOGRAPH TWO",
+	"CIRCLED IDEOGRAPH THREE",
+	"CIRCLED IDEOGRAPH FOUR",
+	"CIRCLED IDEOGRAPH FIVE",
+	"CIRCLED IDEOGRAPH SIX",
+	"CIRCLED IDEOGRAPH SEVEN",
+	"CIRCLED IDEOGRAPH EIGHT",
+	"CIRCLED IDEOGRAPH NINE",
+	"CIRCLED IDEOGRAPH TEN",
+	"CIRCLED IDEOGRAPH MOON",
+	"CIRCLED IDEOGRAPH FIRE",
+	"CIRCLED IDEOGRAPH WATER",
+	"CIRCLED IDEOGRAPH WOOD",
+	"CIRCLED IDEOGRAPH METAL",
+	"CIRCLED IDEOGRAPH EARTH",
+	"CIRCLED IDEOGRAPH SUN",
+	"CIRCLED IDEOGRAPH STOCK",
+	"CIRCLED IDEOGRAPH HAVE",
+	"CIRCLED IDEOGRAPH SOCIETY",
+	"CIRCLED IDEOGRAPH NAME",
+	"CIRCLED IDEOGRAPH SPECIAL",
+	"CIRCLED IDEOGRAPH FINANCIAL",
+	"CIRCLED IDEOGRAPH CONGRATULATION",
+	"CIRCLED IDEOGRAPH LABOR",
+	"CIRCLED IDEOGRAPH SECRET",
+	"CIRCLED IDEOGRAPH MALE",
+	"CIRCLED IDEOGRAPH FEMALE",
+	"CIRCLED IDEOGRAPH SUITABLE",
+	"CIRCLED IDEOGRAPH EXCELLENT",
+	"CIRCLED IDEOGRAPH PRINT",
+	"CIRCLED IDEOGRAPH ATTENTION",
+	"CIRCLED IDEOGRAPH ITEM",
+	"CIRCLED IDEOGRAPH REST",
+	"CIRCLED IDEOGRAPH COPY",
+	"CIRCLED IDEOGRAPH CORRECT",
+	"CIRCLED IDEOGRAPH HIGH",
+	"CIRCLED IDEOGRAPH CENTRE",
+	"CIRCLED IDEOGRAPH LOW",
+	"CIRCLED IDEOGRAPH LEFT",
+	"CIRCLED IDEOGRAPH RIGHT",
+	"CIRCLED IDEOGRAPH MEDICINE",
+	"CIRCLED IDEOGRAPH RELIGION",
+	"CIRCLED IDEOGRAPH STUDY",
+	"CIRCLED IDEOGRAPH SUPERVISE",
+	"CIRCLED IDEOGRAPH ENTERPRISE",
+	"CIRCLED IDEOGRAPH RESOURCE",
+	"CIRCLED IDEOGRAPH ALLIANCE",
+	"CIRCLED IDEOGRAPH NIGHT",
+	"CIRCLED NUMBER THIRTY SIX",
+	"CIRCLED NUMBER THIRTY SEVEN",
+	"CIRCLED NUMBER THIRTY EIGHT",
+	"CIRCLED NUMBER THIRTY NINE",
+	"CIRCLED NUMBER FORTY",
+	"CIRCLED NUMBER FORTY ONE",
+	"CIRCLED NUMBER FORTY TWO",
+	"CIRCLED NUMBER FORTY THREE",
+	"CIRCLED NUMBER FORTY FOUR",
+	"CIRCLED NUMBER FORTY FIVE",
+	"CIRCLED NUMBER FORTY SIX",
+	"CIRCLED NUMBER FORTY SEVEN",
+	"CIRCLED NUMBER FORTY EIGHT",
+	"CIRCLED NUMBER FORTY NINE",
+	"CIRCLED NUMBER FIFTY",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR JANUARY",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR FEBRUARY",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR MARCH",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR APRIL",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR MAY",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR JUNE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR JULY",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR AUGUST",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR SEPTEMBER",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR OCTOBER",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR NOVEMBER",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DECEMBER",
+	"SQUARE HG",
+	"SQUARE ERG",
+	"SQUARE EV",
+	"LIMITED LIABILITY SIGN",
+	"CIRCLED KATAKANA A",
+	"CIRCLED KATAKANA I",
+	"CIRCLED KATAKANA U",
+	"CIRCLED KATAKANA E",
+	"CIRCLED KATAKANA O",
+	"CIRCLED KATAKANA KA",
+	"CIRCLED KATAKANA KI",
+	"CIRCLED KATAKANA KU",
+	"CIRCLED KATAKANA KE",
+	"CIRCLED KATAKANA KO",
+	"CIRCLED KATAKANA SA",
+	"CIRCLED KATAKANA SI",
+	"CIRCLED KATAKANA SU",
+	"CIRCLED KATAKANA SE",
+	"CIRCLED KATAKANA SO",
+	"CIRCLED KATAKANA TA",
+	"CIRCLED KATAKANA TI",
+	"CIRCLED KATAKANA TU",
+	"CIRCLED KATAKANA TE",
+	"CIRCLED KATAKANA TO",
+	"CIRCLED KATAKANA NA",
+	"CIRCLED KATAKANA NI",
+	"CIRCLED KATAKANA NU",
+	"CIRCLED KATAKANA NE",
+	"CIRCLED KATAKANA NO",
+	"CIRCLED KATAKANA HA",
+	"CIRCLED KATAKANA HI",
+	"CIRCLED KATAKANA HU",
+	"CIRCLED KATAKANA HE",
+	"CIRCLED KATAKANA HO",
+	"CIRCLED KATAKANA MA",
+	"CIRCLED KATAKANA MI",
+	"CIRCLED KATAKANA MU",
+	"CIRCLED KATAKANA ME",
+	"CIRCLED KATAKANA MO",
+	"CIRCLED KATAKANA YA",
+	"CIRCLED KATAKANA YU",
+	"CIRCLED KATAKANA YO",
+	"CIRCLED KATAKANA RA",
+	"CIRCLED KATAKANA RI",
+	"CIRCLED KATAKANA RU",
+	"CIRCLED KATAKANA RE",
+	"CIRCLED KATAKANA RO",
+	"CIRCLED KATAKANA WA",
+	"CIRCLED KATAKANA WI",
+	"CIRCLED KATAKANA WE",
+	"CIRCLED KATAKANA WO",
+	"SQUARE ERA NAME REIWA",
+	"SQUARE APAATO",
+	"SQUARE ARUHUA",
+	"SQUARE ANPEA",
+	"SQUARE AARU",
+	"SQUARE ININGU",
+	"SQUARE INTI",
+	"SQUARE UON",
+	"SQUARE ESUKUUDO",
+	"SQUARE EEKAA",
+	"SQUARE ONSU",
+	"SQUARE OOMU",
+	"SQUARE KAIRI",
+	"SQUARE KARATTO",
+	"SQUARE KARORII",
+	"SQUARE GARON",
+	"SQUARE GANMA",
+	"SQUARE GIGA",
+	"SQUARE GINII",
+	"SQUARE KYURII",
+	"SQUARE GIRUDAA",
+	"SQUARE KIRO",
+	"SQUARE KIROGURAMU",
+	"SQUARE KIROMEETORU",
+	"SQUARE KIROWATTO",
+	"SQUARE GURAMU",
+	"SQUARE GURAMUTON",
+	"SQUARE KURUZEIRO",
+	"SQUARE KUROONE",
+	"SQUARE KEESU",
+	"SQUARE KORUNA",
+	"SQUARE KOOPO",
+	"SQUARE SAIKURU",
+	"SQUARE SANTIIMU",
+	"SQUARE SIRINGU",
+	"SQUARE SENTI",
+	"SQUARE SENTO",
+	"SQUARE DAASU",
+	"SQUARE DESI",
+	"SQUARE DORU",
+	"SQUARE TON",
+	"SQUARE NANO",
+	"SQUARE NOTTO",
+	"SQUARE HAITU",
+	"SQUARE PAASENTO",
+	"SQUARE PAATU",
+	"SQUARE BAARERU",
+	"SQUARE PIASUTORU",
+	"SQUARE PIKURU",
+	"SQUARE PIKO",
+	"SQUARE BIRU",
+	"SQUARE HUARADDO",
+	"SQUARE HUIITO",
+	"SQUARE BUSSYERU",
+	"SQUARE HURAN",
+	"SQUARE HEKUTAARU",
+	"SQUARE PESO",
+	"SQUARE PENIHI",
+	"SQUARE HERUTU",
+	"SQUARE PENSU",
+	"SQUARE PEEZI",
+	"SQUARE BEETA",
+	"SQUARE POINTO",
+	"SQUARE BORUTO",
+	"SQUARE HON",
+	"SQUARE PONDO",
+	"SQUARE HOORU",
+	"SQUARE HOON",
+	"SQUARE MAIKURO",
+	"SQUARE MAIRU",
+	"SQUARE MAHHA",
+	"SQUARE MARUKU",
+	"SQUARE MANSYON",
+	"SQUARE MIKURON",
+	"SQUARE MIRI",
+	"SQUARE MIRIBAARU",
+	"SQUARE MEGA",
+	"SQUARE MEGATON",
+	"SQUARE MEETORU",
+	"SQUARE YAADO",
+	"SQUARE YAARU",
+	"SQUARE YUAN",
+	"SQUARE RITTORU",
+	"SQUARE RIRA",
+	"SQUARE RUPII",
+	"SQUARE RUUBURU",
+	"SQUARE REMU",
+	"SQUARE RENTOGEN",
+	"SQUARE WATTO",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR ZERO",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR ONE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWO",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR THREE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR FOUR",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR FIVE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR SIX",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR SEVEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR EIGHT",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR NINE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR ELEVEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWELVE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR THIRTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR FOURTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR FIFTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR SIXTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR SEVENTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR EIGHTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR NINETEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY-ONE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY-TWO",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY-THREE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR HOUR TWENTY-FOUR",
+	"SQUARE HPA",
+	"SQUARE DA",
+	"SQUARE AU",
+	"SQUARE BAR",
+	"SQUARE OV",
+	"SQUARE PC",
+	"SQUARE DM",
+	"SQUARE DM SQUARED",
+	"SQUARE DM CUBED",
+	"SQUARE IU",
+	"SQUARE ERA NAME HEISEI",
+	"SQUARE ERA NAME SYOUWA",
+	"SQUARE ERA NAME TAISYOU",
+	"SQUARE ERA NAME MEIZI",
+	"SQUARE CORPORATION",
+	"SQUARE PA AMPS",
+	"SQUARE NA",
+	"SQUARE MU A",
+	"SQUARE MA",
+	"SQUARE KA",
+	"SQUARE KB",
+	"SQUARE MB",
+	"SQUARE GB",
+	"SQUARE CAL",
+	"SQUARE KCAL",
+	"SQUARE PF",
+	"SQUARE NF",
+	"SQUARE MU F",
+	"SQUARE MU G",
+	"SQUARE MG",
+	"SQUARE KG",
+	"SQUARE HZ",
+	"SQUARE KHZ",
+	"SQUARE MHZ",
+	"SQUARE GHZ",
+	"SQUARE THZ",
+	"SQUARE MU L",
+	"SQUARE ML",
+	"SQUARE DL",
+	"SQUARE KL",
+	"SQUARE FM",
+	"SQUARE NM",
+	"SQUARE MU M",
+	"SQUARE MM",
+	"SQUARE CM",
+	"SQUARE KM",
+	"SQUARE MM SQUARED",
+	"SQUARE CM SQUARED",
+	"SQUARE M SQUARED",
+	"SQUARE KM SQUARED",
+	"SQUARE MM CUBED",
+	"SQUARE CM CUBED",
+	"SQUARE M CUBED",
+	"SQUARE KM CUBED",
+	"SQUARE M OVER S",
+	"SQUARE M OVER S SQUARED",
+	"SQUARE PA",
+	"SQUARE KPA",
+	"SQUARE MPA",
+	"SQUARE GPA",
+	"SQUARE RAD",
+	"SQUARE RAD OVER S",
+	"SQUARE RAD OVER S SQUARED",
+	"SQUARE PS",
+	"SQUARE NS",
+	"SQUARE MU S",
+	"SQUARE MS",
+	"SQUARE PV",
+	"SQUARE NV",
+	"SQUARE MU V",
+	"SQUARE MV",
+	"SQUARE KV",
+	"SQUARE MV MEGA",
+	"SQUARE PW",
+	"SQUARE NW",
+	"SQUARE MU W",
+	"SQUARE MW",
+	"SQUARE KW",
+	"SQUARE MW MEGA",
+	"SQUARE K OHM",
+	"SQUARE M OHM",
+	"SQUARE AM",
+	"SQUARE BQ",
+	"SQUARE CC",
+	"SQUARE CD",
+	"SQUARE C OVER KG",
+	"SQUARE CO",
+	"SQUARE DB",
+	"SQUARE GY",
+	"SQUARE HA",
+	"SQUARE HP",
+	"SQUARE IN",
+	"SQUARE KK",
+	"SQUARE KM CAPITAL",
+	"SQUARE KT",
+	"SQUARE LM",
+	"SQUARE LN",
+	"SQUARE LOG",
+	"SQUARE LX",
+	"SQUARE MB SMALL",
+	"SQUARE MIL",
+	"SQUARE MOL",
+	"SQUARE PH",
+	"SQUARE PM",
+	"SQUARE PPM",
+	"SQUARE PR",
+	"SQUARE SR",
+	"SQUARE SV",
+	"SQUARE WB",
+	"SQUARE V OVER M",
+	"SQUARE A OVER M",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY ONE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWO",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY THREE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY FOUR",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY FIVE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY SIX",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY SEVEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY EIGHT",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY NINE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY ELEVEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWELVE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY THIRTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY FOURTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY FIFTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY SIXTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY SEVENTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY EIGHTEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY NINETEEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-ONE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-TWO",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-THREE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-FOUR",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-FIVE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-SIX",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-SEVEN",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-EIGHT",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY TWENTY-NINE",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY THIRTY",
+	"IDEOGRAPHIC TELEGRAPH SYMBOL FOR DAY THIRTY-ONE",
+	"SQUARE GAL",
+];
+
+/// Names for U+FF00 to U+FFEF (Halfwidth and Fullwidth Forms).
+#[rustfmt::skip]
+pub const HALFWIDTH_AND_FULLWIDTH: [&str; 240] = [
+	"",
+	"FULLWIDTH EXCLAMATION MARK",
+	"FULLWIDTH QUOTATION MARK",
+	"FULLWIDTH NUMBER SIGN",
+	"FULLWIDTH DOLLAR SIGN",
+	"FULLWIDTH PERCENT SIGN",
+	"FULLWIDTH AMPERSAND",
+	"FULLWIDTH APOSTROPHE",
+	"FULLWIDTH LEFT PARENTHESIS",
+	"FULLWIDTH RIGHT PARENTHESIS",
+	"FULLWIDTH ASTERISK",
+	"FULLWIDTH PLUS SIGN",
+	"FULLWIDTH COMMA",
+	"FULLWIDTH HYPHEN-MINUS",
+	"FULLWIDTH FULL STOP",
+	"FULLWIDTH SOLIDUS",
+	"FULLWIDTH DIGIT ZERO",
+	"FULLWIDTH DIGIT ONE",
+	"FULLWIDTH DIGIT TWO",
+	"FULLWIDTH DIGIT THREE",
+	"FULLWIDTH DIGIT FOUR",
+	"FULLWIDTH DIGIT FIVE",
+	"FULLWIDTH DIGIT SIX",
+	"FULLWIDTH DIGIT SEVEN",
+	"FULLWIDTH DIGIT EIGHT",
+	"FULLWIDTH DIGIT NINE",
+	"FULLWIDTH COLON",
+	"FULLWIDTH SEMICOLON",
+	"FULLWIDTH LESS-THAN SIGN",
+	"FULLWIDTH EQUALS SIGN",
+	"FULLWIDTH GREATER-THAN SIGN",
+	"FULLWIDTH QUESTION MARK",
+	"FULLWIDTH COMMERCIAL AT",
+	"FULLWIDTH LATIN CAPITAL LETTER A",
+	"FULLWIDTH LATIN CAPITAL LETTER B",
+	"FULLWIDTH LATIN CAPITAL LETTER C",
+	"FULLWIDTH LATIN CAPITAL LETTER D",
+	"FULLWIDTH LATIN CAPITAL LETTER E",
+	"FULLWIDTH LATIN CAPITAL LETTER F",
+	"FULLWIDTH LATIN CAPITAL LETTER G",
+	"FULLWIDTH LATIN CAPITAL LETTER H",
+	"FULLWIDTH LATIN CAPITAL LETTER I",
+	"FULLWIDTH LATIN CAPITAL LETTER J",
+	"FULLWIDTH LATIN CAPITAL LETTER K",
+	"FULLWIDTH LATIN CAPITAL LETTER L",
+	"FULLWIDTH LATIN CAPITAL LETTER M",
+	"FULLWIDTH LATIN CAPITAL LETTER N",
+	"FULLWIDTH LATIN CAPITAL LETTER O",
+	"FULLWIDTH LATIN CAPITAL LETTER P",
+	"FULLWIDTH LATIN CAPITAL LETTER Q",
+	"FULLWIDTH LATIN CAPITAL LETTER R",
+	"FULLWIDTH LATIN CAPITAL LETTER S",
+	"FULLWIDTH LATIN CAPITAL LETTER T",
+	"FULLWIDTH LATIN CAPITAL LETTER U",
+	"FULLWIDTH LATIN CAPITAL LETTER V",
+	"FULLWIDTH LATIN CAPITAL LETTER W",
+	"FULLWIDTH LATIN CAPITAL LETTER X",
+	"FULLWIDTH LATIN CAPITAL LETTER Y",
+	"FULLWIDTH LATIN CAPITAL LETTER Z",
+	"FULLWIDTH LEFT SQUARE BRACKET",
+	"FULLWIDTH REVERSE SOLIDUS",
+	"FULLWIDTH RIGHT SQUARE BRACKET",
+	"FULLWIDTH CIRCUMFLEX ACCENT",
+	"FULLWIDTH LOW LINE",
+	"FULLWIDTH GRAVE ACCENT",
+	"FULLWIDTH LATIN SMALL LETTER A",
+	"FULLWIDTH LATIN SMALL LETTER B",
+	"FULLWIDTH LATIN SMALL LETTER C",
+	"FULLWIDTH LATIN SMALL LETTER D",
+	"FULLWIDTH LATIN SMALL LETTER E",
+	"FULLWIDTH LATIN SMALL LETTER F",
+	"FULLWIDTH LATIN SMALL LETTER G",
+	"FULLWIDTH LATIN SMALL LETTER H",
+	"FULLWIDTH LATIN SMALL LETTER I",
+	"FULLWIDTH LATIN SMALL LETTER J",
+	"FULLWIDTH LATIN SMALL LETTER K",
+	"FULLWIDTH LATIN SMALL LETTER L",
+	"FULLWIDTH LATIN SMALL LETTER M",
+	"FULLWIDTH LATIN SMALL LETTER N",
+	"FULLWIDTH LATIN SMALL LETTER O",
+	"FULLWIDTH LATIN SMALL LETTER P",
+	"FULLWIDTH LATIN SMALL LETTER Q",
+	"FULLWIDTH LATIN SMALL LETTER R",
+	"FULLWIDTH LATIN SMALL LETTER S",
+	"FULLWIDTH LATIN SMALL LETTER T",
+	"FULLWIDTH LATIN SMALL LETTER U",
+	"FULLWIDTH LATIN SMALL LETTER V",
+	"FULLWIDTH LATIN SMALL LETTER W",
+	"FULLWIDTH LATIN SMALL LETTER X",
+	"FULLWIDTH LATIN SMALL LETTER Y",
+	"FULLWIDTH LATIN SMALL LETTER Z",
+	"FULLWIDTH LEFT CURLY BRACKET",
+	"FULLWIDTH VERTICAL LINE",
+	"FULLWIDTH RIGHT CURLY BRACKET",
+	"FULLWIDTH TILDE",
+	"FULLWIDTH LEFT WHITE PARENTHESIS",
+	"FULLWIDTH RIGHT WHITE PARENTHESIS",
+	"HALFWIDTH IDEOGRAPHIC FULL STOP",
+	"HALFWIDTH LEFT CORNER BRACKET",
+	"HALFWIDTH RIGHT CORNER BRACKET",
+	"HALFWIDTH IDEOGRAPHIC COMMA",
+	"HALFWIDTH KATAKANA MIDDLE DOT",
+	"HALFWIDTH KATAKANA LETTER WO",
+	"HALFWIDTH KATAKANA LETTER SMALL A",
+	"HALFWIDTH KATAKANA LETTER SMALL I",
+	"HALFWIDTH KATAKANA LETTER SMALL U",
+	"HALFWIDTH KATAKANA LETTER SMALL E",
+	"HALFWIDTH KATAKANA LETTER SMALL O",
+	"HALFWIDTH KATAKANA LETTER SMALL YA",
+	"HALFWIDTH KATAKANA LETTER SMALL YU",
+	"HALFWIDTH KATAKANA LETTER SMALL YO",
+	"HALFWIDTH KATAKANA LETTER SMALL TU",
+	"HALFWIDTH KATAKANA-HIRAGANA PROLONGED SOUND MARK",
+	"HALFWIDTH KATAKANA LETTER A",
+	"HALFWIDTH KATAKANA LETTER I",
+	"HALFWIDTH KATAKANA LETTER U",
+	"HALFWIDTH KATAKANA LETTER E",
+	"HALFWIDTH KATAKANA LETTER O",
+	"HALFWIDTH KATAKANA LETTER KA",
+	"HALFWIDTH KATAKANA LETTER KI",
+	"HALFWIDTH KATAKANA LETTER KU",
+	"HALFWIDTH KATAKANA LETTER KE",
+	"HALFWIDTH KATAKANA LETTER KO",
+	"HALFWIDTH KATAKANA LETTER SA",
+	"HALFWIDTH KATAKANA LETTER SI",
+	"HALFWIDTH KATAKANA LETTER SU",
+	"HALFWIDTH KATAKANA LETTER SE",
+	"HALFWIDTH KATAKANA LETTER SO",
+	"HALFWIDTH KATAKANA LETTER TA",
+	"HALFWIDTH KATAKANA LETTER TI",
+	"HALFWIDTH KATAKANA LETTER TU",
+	"HALFWIDTH KATAKANA LETTER TE",
+	"HALFWIDTH KATAKANA LETTER TO",
+	"HALFWIDTH KATAKANA LETTER NA",
+	"HALFWIDTH KATAKANA LETTER NI",
+	"HALFWIDTH KATAKANA LETTER NU",
+	"HALFWIDTH KATAKANA LETTER NE",
+	"HALFWIDTH KATAKANA LETTER NO",
+	"HALFWIDTH KATAKANA LETTER HA",
+	"HALFWIDTH KATAKANA LETTER HI",
+	"HALFWIDTH KATAKANA LETTER HU",
+	"HALFWIDTH KATAKANA LETTER HE",
+	"HALFWIDTH KATAKANA LETTER HO",
+	"HALFWIDTH KATAKANA LETTER MA",
+	"HALFWIDTH KATAKANA LETTER MI",
+	"HALFWIDTH KATAKANA LETTER MU",
+	"HALFWIDTH KATAKANA LETTER ME",
+	"HALFWIDTH KATAKANA LETTER MO",
+	"HALFWIDTH KATAKANA LETTER YA",
+	"HALFWIDTH KATAKANA LETTER YU",
+	"HALFWIDTH KATAKANA LETTER YO",
+	"HALFWIDTH KATAKANA LETTER RA",
+	"HALFWIDTH KATAKANA LETTER RI",
+	"HALFWIDTH KATAKANA LETTER RU",
+	"HALFWIDTH KATAKANA LETTER RE",
+	"HALFWIDTH KATAKANA LETTER RO",
+	"HALFWIDTH KATAKANA LETTER WA",
+	"HALFWIDTH KATAKANA LETTER N",
+	"HALFWIDTH KATAKANA VOICED SOUND MARK",
+	"HALFWIDTH KATAKANA SEMI-VOICED SOUND MARK",
+	"HALFWIDTH HANGUL FILLER",
+	"HALFWIDTH HANGUL LETTER KIYEOK",
+	"HALFWIDTH HANGUL LETTER SSANGKIYEOK",
+	"HALFWIDTH HANGUL LETTER KIYEOK-SIOS",
+	"HALFWIDTH HANGUL LETTER NIEUN",
+	"HALFWIDTH HANGUL LETTER NIEUN-CIEUC",
+	"HALFWIDTH HANGUL LETTER NIEUN-HIEUH",
+	"HALFWIDTH HANGUL LETTER TIKEUT",
+	"HALFWIDTH HANGUL LETTER SSANGTIKEUT",
+	"HALFWIDTH HANGUL LETTER RIEUL",
+	"HALFWIDTH HANGUL LETTER RIEUL-KIYEOK",
+	"HALFWIDTH HANGUL LETTER RIEUL-MIEUM",
+	"HALFWIDTH HANGUL LETTER RIEUL-PIEUP",
+	"HALFWIDTH HANGUL LETTER RIEUL-SIOS",
+	"HALFWIDTH HANGUL LETTER RIEUL-THIEUTH",
+	"HALFWIDTH HANGUL LETTER RIEUL-PHIEUPH",
+	"HALFWIDTH HANGUL LETTER RIEUL-HIEUH",
+	"HALFWIDTH HANGUL LETTER MIEUM",
+	"HALFWIDTH HANGUL LETTER PIEUP",
+	"HALFWIDTH HANGUL LETTER SSANGPIEUP",
+	"HALFWIDTH HANGUL LETTER PIEUP-SIOS",
+	"HALFWIDTH HANGUL LETTER SIOS",
+	"HALFWIDTH HANGUL LETTER SSANGSIOS",
+	"HALFWIDTH HANGUL LETTER IEUNG",
+	"HALFWIDTH HANGUL LETTER CIEUC",
+	"HALFWIDTH HANGUL LETTER SSANGCIEUC",
+	"HALFWIDTH HANGUL LETTER CHIEUCH",
+	"HALFWIDTH HANGUL LETTER KHIEUKH",
+	"HALFWIDTH HANGUL LETTER THIEUTH",
+	"HALFWIDTH HANGUL LETTER PHIEUPH",
+	"HALFWIDTH HANGUL LETTER HIEUH",
+	"",
+	"",
+	"",
+	"HALFWIDTH HANGUL LETTER A",
+	"HALFWIDTH HANGUL LETTER AE",
+	"HALFWIDTH HANGUL LETTER YA",
+	"HALFWIDTH HANGUL LETTER YAE",
+	"HALFWIDTH HANGUL LETTER EO",
+	"HALFWIDTH HANGUL LETTER E",
+	"",
+	"",
+	"HALFWIDTH HANGUL LETTER YEO",
+	"HALFWIDTH HANGUL LETTER YE",
+	"HALFWIDTH HANGUL LETTER O",
+	"HALFWIDTH HANGUL LETTER WA",
+	"HALFWIDTH HANGUL LETTER WAE",
+	"HALFWIDTH HANGUL LETTER OE",
+	"",
+	"",
+	"HALFWIDTH HANGUL LETTER YO",
+	"HALFWIDTH HANGUL LETTER U",
+	"HALFWIDTH HANGUL LETTER WEO",
+	"HALFWIDTH HANGUL LETTER WE",
+	"HALFWIDTH HANGUL LETTER WI",
+	"HALFWIDTH HANGUL LETTER YU",
+	"",
+	"",
+	"HALFWIDTH HANGUL LETTER EU",
+	"HALFWIDTH HANGUL LETTER YI",
+	"HALFWIDTH HANGUL LETTER I",
+	"",
+	"",
+	"",
+	"FULLWIDTH CENT SIGN",
+	"FULLWIDTH POUND SIGN",
+	"FULLWIDTH NOT SIGN",
+	"FULLWIDTH MACRON",
+	"FULLWIDTH BROKEN BAR",
+	"FULLWIDTH YEN SIGN",
+	"FULLWIDTH WON SIGN",
+	"",
+	"HALFWIDTH FORMS LIGHT VERTICAL",
+	"HALFWIDTH LEFTWARDS ARROW",
+	"HALFWIDTH UPWARDS ARROW",
+	"HALFWIDTH RIGHTWARDS ARROW",
+	"HALFWIDTH DOWNWARDS ARROW",
+	"HALFWIDTH BLACK SQUARE",
+	"HALFWIDTH WHITE CIRCLE",
+	"",
+];
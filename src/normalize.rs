@@ -0,0 +1,130 @@
+//! Normalization of phonetically equivalent kana spellings for searching.
+
+use super::distance::long_vowels;
+use super::fold::fold_for_search;
+use super::mora::*;
+
+/// Options for [normalize_for_search_with], which disable each of the rules.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SearchNormalizeOptions {
+	/// Keep the long vowels as written, instead of writing them with `ー`
+	/// (e.g. `おう` and `おお` to `おー`).
+	pub keep_long_vowels: bool,
+	/// Keep `うぉ` instead of replacing it by `を`.
+	pub keep_wo: bool,
+	/// Keep `ゔ` instead of replacing it by the `b` row (e.g. `ゔぁ` to `ば`).
+	pub keep_vu: bool,
+	/// Keep repeated sokuon (e.g. `っっ`) instead of collapsing them to a
+	/// single `っ`.
+	pub keep_repeated_sokuon: bool,
+}
+
+/// Returns the `b` row kana for a mora starting with `ゔ`.
+fn vu_to_bu(mora: &str) -> Option<&'static str> {
+	let kana = match mora {
+		"ゔぁ" => "ば",
+		"ゔぃ" => "び",
+		"ゔ" => "ぶ",
+		"ゔぇ" => "べ",
+		"ゔぉ" => "ぼ",
+		"ゔゃ" => "びゃ",
+		"ゔゅ" => "びゅ",
+		"ゔょ" => "びょ",
+		_ => return None,
+	};
+	Some(kana)
+}
+
+/// Normalizes the input into a search key where the phonetically equivalent
+/// spellings are the same.
+///
+/// The input is first [folded](fn.fold_for_search.html) into hiragana, and
+/// then:
+///
+/// - Long vowels are written with `ー`, whether written with a vowel or `ー`
+///   (e.g. `こうひい` and `コーヒー` to `こーひー`).
+/// - `うぉ` is replaced by `を` (e.g. `ウォ` and `ヲ`).
+/// - `ゔ` is replaced by the `b` row (e.g. `ヴァイオリン` and `バイオリン`).
+/// - Repeated sokuon are collapsed to one (e.g. `あっっ` to `あっ`).
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(normalize_for_search("ヴァイオリン"), normalize_for_search("ばいおりん"));
+/// assert_eq!(normalize_for_search("とうきょう"), "とーきょー");
+/// assert_eq!(normalize_for_search("ウォッカ"), normalize_for_search("ヲッカ"));
+/// ```
+pub fn normalize_for_search<S: AsRef<str>>(input: S) -> String {
+	normalize_for_search_with(input, &SearchNormalizeOptions::default())
+}
+
+/// Same as [normalize_for_search] but with the given options.
+///
+/// ```
+/// use kana::*;
+///
+/// let options = SearchNormalizeOptions {
+///     keep_long_vowels: true,
+///     ..SearchNormalizeOptions::default()
+/// };
+/// assert_eq!(normalize_for_search_with("ヴィーナス", &options), "びーなす");
+/// assert_eq!(normalize_for_search_with("びいなす", &options), "びいなす");
+/// ```
+pub fn normalize_for_search_with<S: AsRef<str>>(
+	input: S,
+	options: &SearchNormalizeOptions,
+) -> String {
+	let input = fold_for_search(input);
+	let morae = Morae::new(&input).collect::<Vec<_>>();
+	let long = long_vowels(&morae);
+
+	let mut out = String::with_capacity(input.len());
+	for (mora, long) in morae.into_iter().zip(long) {
+		if long && !options.keep_long_vowels {
+			out.push('ー');
+		} else if mora == "っ" && out.ends_with('っ') && !options.keep_repeated_sokuon {
+			continue;
+		} else if mora == "うぉ" && !options.keep_wo {
+			out.push('を');
+		} else if let Some(kana) = vu_to_bu(mora).filter(|_| !options.keep_vu) {
+			out.push_str(kana);
+		} else {
+			out.push_str(mora);
+		}
+	}
+	out
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_normalize_for_search() {
+		assert_eq!(normalize_for_search(""), "");
+		assert_eq!(normalize_for_search("コーヒー"), "こーひー");
+		assert_eq!(normalize_for_search("こうひい"), "こーひー");
+		assert_eq!(normalize_for_search("せんせい"), "せんせー");
+		assert_eq!(normalize_for_search("おおさか"), "おーさか");
+		assert_eq!(
+			normalize_for_search("ヴ ヴァ ヴィ ヴェ ヴォ ヴュ"),
+			"ぶ ば び べ ぼ びゅ"
+		);
+		assert_eq!(normalize_for_search("ウォ ｦ"), "を を");
+		assert_eq!(normalize_for_search("あっっっ！"), "あっ!");
+		assert_eq!(normalize_for_search("ＡＢＣ 東京"), "abc 東京");
+
+		let options = SearchNormalizeOptions {
+			keep_long_vowels: true,
+			keep_wo: true,
+			keep_vu: true,
+			keep_repeated_sokuon: true,
+		};
+		assert_eq!(
+			normalize_for_search_with("こうひい ウォ ヴァ あっっ", &options),
+			"こうひい うぉ ゔぁ あっっ"
+		);
+	}
+}
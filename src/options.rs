@@ -0,0 +1,239 @@
+//! Options for the conversion functions.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Target script for a conversion.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConversionTarget {
+	Hiragana,
+	Katakana,
+	Romaji,
+}
+
+/// Policy for characters that cannot be converted by the conversion functions.
+///
+/// A character is considered unknown if there is no conversion for it and it
+/// is not already valid in the target script. For the kana conversions that is
+/// any character other than kana, Japanese marks and punctuation or whitespace
+/// (notably this includes kanji). For romaji, any non-ASCII character.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OnUnknown {
+	/// Unknown characters are passed through unchanged. This is the default.
+	#[default]
+	Passthrough,
+	/// Unknown characters are removed from the output.
+	Skip,
+	/// Unknown characters are replaced by the given character.
+	ReplaceWith(char),
+	/// The conversion fails with a [ConversionError].
+	Error,
+}
+
+/// Katakana that are kept as is when converting to hiragana.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PreserveKatakana {
+	/// All katakana are converted. This is the default.
+	#[default]
+	Never,
+	/// The small `ヶ` and `ヵ` are kept, since those are almost always used as
+	/// counters or in place names (e.g. `霞ヶ関`, `一ヵ月`) and not as kana.
+	SmallKe,
+	/// Same as `SmallKe` and also any katakana between two kanji, as part of
+	/// a compound (e.g. `竹ノ塚`).
+	InKanjiCompounds,
+}
+
+/// Romaji for the prolonged sound mark `ー`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ProlongedMark {
+	/// The mark is converted to a `-` (e.g. `ビール` to `bi-ru`). This is the
+	/// default.
+	#[default]
+	Hyphen,
+	/// The previous vowel is repeated (e.g. `ビール` to `biiru`).
+	Repeat,
+	/// The previous vowel is written with a macron (e.g. `ビール` to `bīru`).
+	Macron,
+}
+
+/// Romaji for a `ん` followed by a vowel or `y`, which would be ambiguous
+/// when written as a plain `n` (e.g. `きんよう` is not `きにょう`).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NSeparator {
+	/// An apostrophe follows the `n` (e.g. `kin'you`). This is the default.
+	#[default]
+	Apostrophe,
+	/// A hyphen follows the `n` (e.g. `kin-you`).
+	Hyphen,
+	/// The `n` is doubled (e.g. `kinnyou`), as typed in an IME.
+	///
+	/// Note that [to_hiragana](fn.to_hiragana.html) does not read it back
+	/// the same way (e.g. `kinnyou` is `きんにょう`).
+	DoubleN,
+}
+
+/// Hiragana for the prolonged sound mark `ー`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KanaProlongedMark {
+	/// The mark is kept (e.g. `トーキョー` to `とーきょー`). This is the default.
+	#[default]
+	Keep,
+	/// The mark is replaced by the conventional spelling of the long vowel,
+	/// using `う` after an `o` and `い` after an `e` (e.g. `トーキョー` to
+	/// `とうきょう`).
+	Conventional,
+	/// The previous vowel is repeated (e.g. `トーキョー` to `とおきょお`).
+	Repeat,
+}
+
+/// Options for the conversion functions, such as
+/// [to_hiragana_with](fn.to_hiragana_with.html).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConversionOptions {
+	/// Policy for characters that cannot be converted.
+	pub on_unknown: OnUnknown,
+
+	/// Katakana that are kept as is when converting to hiragana.
+	pub preserve_katakana: PreserveKatakana,
+
+	/// Use `ー` for the long vowels in romaji when converting to katakana,
+	/// as is usual for loanwords (e.g. `koohii` and `kōhī` to `コーヒー`).
+	///
+	/// Long vowels are doubled vowels, vowels with a macron or circumflex and
+	/// the `ou` and `ei` pairs. Vowels that are not marked as long in the
+	/// romaji are not changed (e.g. `ramen` is still `ラメン`).
+	pub loanword_long_vowels: bool,
+
+	/// Romaji for the prolonged sound mark `ー` when converting to romaji.
+	///
+	/// A mark that does not follow a vowel is always converted to `-`.
+	pub prolonged_mark: ProlongedMark,
+
+	/// Collapse long vowels into a vowel with a macron when converting to
+	/// romaji, as used in dictionary headwords (e.g. `とうきょう` to `tōkyō`).
+	///
+	/// The collapsed sequences are `おう` and `おお` to `ō`, `うう` to `ū`,
+	/// `ああ` to `ā` and `ええ` and `えい` to `ē`. Since `ii` is the usual
+	/// spelling for `いい`, it is not collapsed.
+	pub collapse_long_vowels: bool,
+
+	/// Hiragana for the prolonged sound mark `ー` when converting to
+	/// hiragana.
+	///
+	/// A mark that does not follow a vowel (e.g. after `ん`) is always kept.
+	pub hiragana_prolonged_mark: KanaProlongedMark,
+
+	/// Convert the romaji `wi` and `we` to the archaic `ゐ` and `ゑ` (or `ヰ`
+	/// and `ヱ`) instead of `うぃ` and `うぇ`, as used for classical texts and
+	/// names.
+	pub archaic_wi_we: bool,
+
+	/// Romaji for a `ん` that is followed by a vowel or `y` when converting
+	/// to romaji.
+	pub n_separator: NSeparator,
+
+	/// Guarantee that the output of the romaji conversion contains only ASCII
+	/// characters, as required for file names and URLs.
+	///
+	/// Long vowels with a macron or circumflex are written as doubled vowels
+	/// (e.g. `ō` to `oo`), including the ones from [ProlongedMark::Macron] and
+	/// `collapse_long_vowels`, the Japanese quotes are written as ASCII
+	/// quotes (e.g. `「」` to `''` and `『』` to `""`), and full-width ASCII
+	/// is converted to ASCII.
+	///
+	/// Other non-ASCII characters (e.g. kanji) are handled by `on_unknown`,
+	/// except that [OnUnknown::Passthrough] and a non-ASCII replacement skip
+	/// the character instead.
+	pub ascii_only: bool,
+
+	/// Expand the circled, parenthesized and full stop digits to plain digits
+	/// when converting to romaji (e.g. `①` to `1`, `⑴` to `(1)` and `⒈` to
+	/// `1.`).
+	///
+	/// Those are always expanded with `ascii_only`.
+	pub expand_enclosed_digits: bool,
+}
+
+/// Set when the global default options are not the [ConversionOptions]
+/// default, so that reading them can be skipped otherwise.
+static HAS_DEFAULT_OPTIONS: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+	/// Global default options set by [set_default_options].
+	static ref DEFAULT_OPTIONS: RwLock<ConversionOptions> = RwLock::new(ConversionOptions::default());
+}
+
+/// Sets the global default options used by the conversion functions that do
+/// not take options, such as [to_hiragana](fn.to_hiragana.html).
+///
+/// This is meant to be called once when the application starts. It is safe
+/// to call from any thread, and affects all threads.
+///
+/// Since those functions cannot fail, [OnUnknown::Error] is handled as
+/// [OnUnknown::Passthrough] by them.
+///
+/// ```
+/// use kana::*;
+///
+/// set_default_options(ConversionOptions {
+///     on_unknown: OnUnknown::ReplaceWith('?'),
+///     ..ConversionOptions::default()
+/// });
+/// assert_eq!(to_romaji("漢字のかな"), "??nokana");
+/// assert_eq!(default_options().on_unknown, OnUnknown::ReplaceWith('?'));
+///
+/// set_default_options(ConversionOptions::default());
+/// assert_eq!(to_romaji("漢字のかな"), "漢字nokana");
+/// ```
+pub fn set_default_options(options: ConversionOptions) {
+	let mut default = DEFAULT_OPTIONS.write().unwrap();
+	*default = options;
+	HAS_DEFAULT_OPTIONS.store(options != ConversionOptions::default(), Ordering::Release);
+}
+
+/// Returns the global default options set by [set_default_options].
+pub fn default_options() -> ConversionOptions {
+	if HAS_DEFAULT_OPTIONS.load(Ordering::Acquire) {
+		*DEFAULT_OPTIONS.read().unwrap()
+	} else {
+		ConversionOptions::default()
+	}
+}
+
+/// Returns the options for the conversion functions that do not take options
+/// and cannot fail, based on [default_options].
+pub(crate) fn infallible_options() -> ConversionOptions {
+	let mut options = default_options();
+	if options.on_unknown == OnUnknown::Error {
+		options.on_unknown = OnUnknown::Passthrough;
+	}
+	options
+}
+
+/// Error for a conversion that failed because of an unknown character when
+/// using [OnUnknown::Error].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConversionError {
+	/// The unknown character.
+	pub chr: char,
+	/// Byte offset of the character in the input.
+	pub offset: usize,
+}
+
+impl fmt::Display for ConversionError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"cannot convert `{}` (U+{:04X}) at offset {}",
+			self.chr, self.chr as u32, self.offset
+		)
+	}
+}
+
+impl std::error::Error for ConversionError {}
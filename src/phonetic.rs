@@ -0,0 +1,99 @@
+//! Phonetic keys for comparing readings by sound.
+
+use super::distance::long_vowels;
+use super::fold::fold_for_search;
+use super::mora::*;
+use super::options::ConversionOptions;
+use super::to::to_hiragana_with;
+
+/// Returns the short vowel for a romaji long vowel written with a macron or
+/// circumflex, since the long vowels are collapsed anyway.
+fn short_vowel(chr: char) -> char {
+	match chr {
+		'ā' | 'â' => 'a',
+		'ī' | 'î' => 'i',
+		'ū' | 'û' => 'u',
+		'ē' | 'ê' => 'e',
+		'ō' | 'ô' => 'o',
+		_ => chr,
+	}
+}
+
+/// Returns the hiragana for a kana with the same sound.
+fn homophone(chr: char) -> char {
+	match chr {
+		'ぢ' => 'じ',
+		'づ' => 'ず',
+		'を' => 'お',
+		'ゐ' => 'い',
+		'ゑ' => 'え',
+		_ => chr,
+	}
+}
+
+/// Returns a phonetic key for the kana or romaji input, such that readings
+/// that sound the same have the same key.
+///
+/// The input is converted to hiragana (as in [fold_for_search] and
+/// [to_hiragana](fn.to_hiragana.html)), and then:
+///
+/// - `ぢ` and `づ` are replaced by `じ` and `ず`, as are the archaic `を`,
+///   `ゐ` and `ゑ` by `お`, `い` and `え`.
+/// - Long vowels are collapsed into the short vowel, whether written with
+///   `ー`, a vowel or a romaji macron (e.g. `おお`, `おう`, `おー` and `ō` are
+///   all `お`).
+/// - The sokuon `っ` is removed.
+///
+/// The key is meant for comparing and grouping readings (e.g. removing
+/// duplicates), not for display.
+///
+/// ```
+/// assert_eq!(kana::phonetic_key("おおさか"), kana::phonetic_key("おうさか"));
+/// assert_eq!(kana::phonetic_key("ちぢむ"), kana::phonetic_key("チジム"));
+/// assert_eq!(kana::phonetic_key("Tōkyō"), kana::phonetic_key("とうきょう"));
+/// assert_eq!(kana::phonetic_key("きって"), "きて");
+/// ```
+pub fn phonetic_key<S: AsRef<str>>(input: S) -> String {
+	let input = fold_for_search(input)
+		.chars()
+		.map(short_vowel)
+		.collect::<String>();
+	// This cannot fail, since the default options pass unknown characters
+	// through.
+	let hiragana = to_hiragana_with(&input, &ConversionOptions::default()).unwrap();
+	let morae = Morae::new(&hiragana).collect::<Vec<_>>();
+	let long = long_vowels(&morae);
+
+	let mut key = String::with_capacity(hiragana.len());
+	for (mora, long) in morae.into_iter().zip(long) {
+		if !long && mora != "っ" {
+			key.extend(mora.chars().map(homophone));
+		}
+	}
+	key
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_phonetic_key() {
+		assert_eq!(phonetic_key(""), "");
+		assert_eq!(phonetic_key("おおさか"), "おさか");
+		assert_eq!(phonetic_key("オーサカ"), "おさか");
+		assert_eq!(phonetic_key("oosaka"), "おさか");
+		assert_eq!(phonetic_key("ŌSAKA"), "おさか");
+		assert_eq!(phonetic_key("ôsaka"), "おさか");
+		assert_eq!(phonetic_key("ｵｳｻｶ"), "おさか");
+		assert_eq!(phonetic_key("せんせい"), "せんせ");
+		assert_eq!(phonetic_key("ぢゃ づ を"), "じゃ ず お");
+		assert_eq!(phonetic_key("がっこう"), "がこ");
+		assert_eq!(phonetic_key("らあめん"), phonetic_key("ラーメン"));
+		assert_ne!(phonetic_key("らいめん"), phonetic_key("ラーメン"));
+		assert_ne!(phonetic_key("かき"), phonetic_key("がき"));
+		assert_eq!(phonetic_key("東京"), "東京");
+	}
+}
@@ -84,15 +84,21 @@ macro_rules! kanji_range {
 			| '\u{2B820}'..='\u{2CEAF}'
 			// CJK Unified Ideographs Extension F (none renders)
 			| '\u{2CEB0}'..='\u{2EBEF}'
+			// CJK Unified Ideographs Extension I
+			| '\u{2EBF0}'..='\u{2EE5D}'
+			// CJK Unified Ideographs Extension G
+			| '\u{30000}'..='\u{3134A}'
+			// CJK Unified Ideographs Extension H
+			| '\u{31350}'..='\u{323AF}'
 		)
 	};
 }
 
-/// Pattern for ASCII punctuation.
+/// Pattern for ASCII punctuation. The space is in [space_range].
 macro_rules! ascii_punctuation_range {
 	() => {
 		(
-			' ' | '`' | '~' | '!' | '@' | '#' | '$' | '%' | '^' | '&' | '*'
+			'`' | '~' | '!' | '@' | '#' | '$' | '%' | '^' | '&' | '*'
 			| '(' | ')' | '-' | '_' | '=' | '+' | '[' | ']' | '{' | '}' | ';'
 			| ':' | '<' | '>' | ',' | '.' | '/' | '?' | '\'' | '"' | '|' | '\\'
 		)
@@ -120,15 +126,13 @@ macro_rules! roman_punctuation_range {
 	};
 }
 
-/// Pattern for japanese punctuation characters, including space.
+/// Pattern for japanese punctuation characters. The U+3000 Ideographic Space
+/// is in [space_range].
 macro_rules! japanese_punctuation_range {
 	() => {
 		(
-			// U+3000 Ideographic Space
-			'　'
-
 			// Most of these are from "CJK Symbols and Punctuation"
-			| '｟'..='･'
+			'｟'..='･'
 			| '、' | '。' | '〃' | '〈' | '〉' | '《' | '》' | '「' | '」' | '『'
 			| '』' | '【' | '】' | '〔' | '〕' | '〖' | '〗' | '〘' | '〙' | '〚'
 			| '〛' | '〜' | '〝' | '〞' | '〟' | '〰' | '〽' | '゠' | '・'
@@ -143,7 +147,7 @@ macro_rules! japanese_mark_range {
 	() => {
 		(
 			'々' | '〆' | '〱' | '〲' | '〳' | '〴' | '〵' | '〻' | '〼' | '゛'
-			| '゜' | 'ゝ' | 'ゞ' | 'ヽ' | 'ヾ'
+			| '゜' | 'ゝ' | 'ゞ' | 'ヽ' | 'ヾ' | 'ﾞ' | 'ﾟ'
 		)
 	};
 }
@@ -157,3 +161,157 @@ macro_rules! japanese_symbol_range {
 		)
 	};
 }
+
+/// Pattern for Korean Hangul characters, including the jamo and halfwidth
+/// forms.
+macro_rules! hangul_range {
+	() => {
+		(
+			// Hangul Jamo
+			'\u{1100}'..='\u{11FF}'
+			// Hangul Compatibility Jamo
+			| '\u{3131}'..='\u{318E}'
+			// Hangul Jamo Extended-A and Extended-B
+			| '\u{A960}'..='\u{A97C}' | '\u{D7B0}'..='\u{D7FB}'
+			// Hangul Syllables (`가` to `힣`)
+			| '\u{AC00}'..='\u{D7A3}'
+			// Halfwidth Hangul letters
+			| '\u{FFA0}'..='\u{FFDC}'
+			// Hangul single and double dot tone marks
+			| '\u{302E}' | '\u{302F}'
+		)
+	};
+}
+
+/// Pattern for Chinese Bopomofo characters (`ㄅ` to `ㄯ` and the extended
+/// letters).
+macro_rules! bopomofo_range {
+	() => {
+		('\u{3105}'..='\u{312F}' | '\u{31A0}'..='\u{31BF}')
+	};
+}
+
+/// Pattern for CJK punctuation forms not used in Japanese, mostly found in
+/// Chinese and Korean texts.
+macro_rules! cjk_punctuation_range {
+	() => {
+		(
+			// Vertical Forms (e.g. `︐`, `︙`)
+			'\u{FE10}'..='\u{FE19}'
+			// CJK Compatibility Forms (e.g. `︵`, `﹃`)
+			| '\u{FE30}'..='\u{FE4F}'
+			// Small Form Variants (e.g. `﹐`, `﹗`)
+			| '\u{FE50}'..='\u{FE52}' | '\u{FE54}'..='\u{FE66}' | '\u{FE68}'..='\u{FE6B}'
+		)
+	};
+}
+
+/// Pattern for emoji, which are the characters with the Unicode
+/// `Emoji_Presentation` property (Unicode 16.0), plus the zero width joiner
+/// and the variation selector 16 used inside emoji sequences.
+///
+/// Characters that are only displayed as emoji when followed by the variation
+/// selector 16 (e.g. `★` and `❤`) are not included.
+macro_rules! emoji_range {
+	() => {
+		(
+			// Miscellaneous Technical
+			'\u{231A}'..='\u{231B}' | '\u{23E9}'..='\u{23EC}' | '\u{23F0}' | '\u{23F3}'
+			// Geometric Shapes
+			| '\u{25FD}'..='\u{25FE}'
+			// Miscellaneous Symbols
+			| '\u{2614}'..='\u{2615}' | '\u{2648}'..='\u{2653}' | '\u{267F}' | '\u{2693}'
+			| '\u{26A1}' | '\u{26AA}'..='\u{26AB}' | '\u{26BD}'..='\u{26BE}'
+			| '\u{26C4}'..='\u{26C5}' | '\u{26CE}' | '\u{26D4}' | '\u{26EA}'
+			| '\u{26F2}'..='\u{26F3}' | '\u{26F5}' | '\u{26FA}' | '\u{26FD}'
+			// Dingbats
+			| '\u{2705}' | '\u{270A}'..='\u{270B}' | '\u{2728}' | '\u{274C}' | '\u{274E}'
+			| '\u{2753}'..='\u{2755}' | '\u{2757}' | '\u{2795}'..='\u{2797}' | '\u{27B0}'
+			| '\u{27BF}'
+			// Miscellaneous Symbols and Arrows
+			| '\u{2B1B}'..='\u{2B1C}' | '\u{2B50}' | '\u{2B55}'
+			// Mahjong, playing cards and enclosed alphanumerics and ideographs
+			| '\u{1F004}' | '\u{1F0CF}' | '\u{1F18E}' | '\u{1F191}'..='\u{1F19A}'
+			| '\u{1F1E6}'..='\u{1F1FF}' | '\u{1F201}' | '\u{1F21A}' | '\u{1F22F}'
+			| '\u{1F232}'..='\u{1F236}' | '\u{1F238}'..='\u{1F23A}' | '\u{1F250}'..='\u{1F251}'
+			// Miscellaneous Symbols and Pictographs
+			| '\u{1F300}'..='\u{1F320}' | '\u{1F32D}'..='\u{1F335}' | '\u{1F337}'..='\u{1F37C}'
+			| '\u{1F37E}'..='\u{1F393}' | '\u{1F3A0}'..='\u{1F3CA}' | '\u{1F3CF}'..='\u{1F3D3}'
+			| '\u{1F3E0}'..='\u{1F3F0}' | '\u{1F3F4}' | '\u{1F3F8}'..='\u{1F43E}' | '\u{1F440}'
+			| '\u{1F442}'..='\u{1F4FC}' | '\u{1F4FF}'..='\u{1F53D}' | '\u{1F54B}'..='\u{1F54E}'
+			| '\u{1F550}'..='\u{1F567}' | '\u{1F57A}' | '\u{1F595}'..='\u{1F596}' | '\u{1F5A4}'
+			| '\u{1F5FB}'..='\u{1F5FF}'
+			// Emoticons
+			| '\u{1F600}'..='\u{1F64F}'
+			// Transport and Map Symbols
+			| '\u{1F680}'..='\u{1F6C5}' | '\u{1F6CC}' | '\u{1F6D0}'..='\u{1F6D2}'
+			| '\u{1F6D5}'..='\u{1F6D7}' | '\u{1F6DC}'..='\u{1F6DF}' | '\u{1F6EB}'..='\u{1F6EC}'
+			| '\u{1F6F4}'..='\u{1F6FC}'
+			// Geometric Shapes Extended
+			| '\u{1F7E0}'..='\u{1F7EB}' | '\u{1F7F0}'
+			// Supplemental Symbols and Pictographs
+			| '\u{1F90C}'..='\u{1F93A}' | '\u{1F93C}'..='\u{1F945}' | '\u{1F947}'..='\u{1F9FF}'
+			// Symbols and Pictographs Extended-A
+			| '\u{1FA70}'..='\u{1FA7C}' | '\u{1FA80}'..='\u{1FA89}' | '\u{1FA8F}'..='\u{1FAC6}'
+			| '\u{1FACE}'..='\u{1FADC}' | '\u{1FADF}'..='\u{1FAE9}' | '\u{1FAF0}'..='\u{1FAF8}'
+			// Zero width joiner and variation selector 16, used in sequences
+			| '\u{200D}' | '\u{FE0F}'
+		)
+	};
+}
+
+/// Pattern for the symbols commonly used as building blocks of kaomoji (e.g.
+/// the `╯`, `°` and `□` in `(╯°□°）╯`).
+///
+/// This is a list of face parts, so general symbols such as `○`, `★` and box
+/// drawing lines (e.g. `─`) are not included.
+macro_rules! kaomoji_range {
+	() => {
+		(
+			// Latin-1 symbols (e.g. `¬` in `(¬_¬)` and `´` in `(´・ω・`)`)
+			'¨' | '¬' | '¯' | '°' | '´' | '·'
+			// Undertie and character tie (e.g. `(◕‿◕)`)
+			| '‿' | '⁀'
+			// Mathematical operators (e.g. `(´∀｀)`, `(≧▽≦)` and `⊂(・﹏・⊂)`)
+			| '∀' | '∇' | '∩' | '≦' | '≧' | '⊂' | '⊃'
+			// Arc (e.g. `(⌒▽⌒)`)
+			| '⌒'
+			// Arms and flipped tables (e.g. `(╯°□°）╯︵ ┻━┻`)
+			| '╭' | '╮' | '╯' | '╰' | '┻' | '┳'
+			// Eyes and mouths (e.g. `(°□°)`, `(◉_◉)` and `(◠‿◠)`)
+			| '□' | '▽' | '◉' | '◔' | '◕' | '◠' | '◡'
+		)
+	};
+}
+
+/// Pattern for whitespace characters, which is the same as
+/// `char::is_whitespace`.
+macro_rules! space_range {
+	() => {
+		(
+			// ASCII whitespace and space
+			'\t'..='\r' | ' '
+			// Other Unicode spaces and line separators
+			| '\u{85}' | '\u{A0}' | '\u{1680}' | '\u{2000}'..='\u{200A}'
+			| '\u{2028}' | '\u{2029}' | '\u{202F}' | '\u{205F}'
+			// U+3000 Ideographic Space
+			| '　'
+		)
+	};
+}
+
+/// Pattern for the roman numeral characters from the Number Forms block (e.g.
+/// `Ⅻ` and `ⅻ`). This excludes U+2184 (`ↄ`), which is a letter.
+macro_rules! roman_numeral_range {
+	() => {
+		('\u{2160}'..='\u{2183}' | '\u{2185}'..='\u{2188}')
+	};
+}
+
+/// Pattern for the circled (`①` to `⑳`), parenthesized (`⑴` to `⒇`) and
+/// full stop (`⒈` to `⒛`) digits.
+macro_rules! enclosed_digit_range {
+	() => {
+		('\u{2460}'..='\u{249B}')
+	};
+}
@@ -0,0 +1,166 @@
+//! Readability metrics for Japanese text.
+
+use super::joyo::*;
+use super::kind::*;
+use super::tokenize::*;
+
+/// Readability metrics for a text, returned by [readability].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Readability {
+	/// Ratio of kanji in the Japanese letters (kanji and kana) of the text,
+	/// from `0.0` to `1.0`.
+	pub kanji_ratio: f64,
+
+	/// Ratio of jōyō kanji (see [is_joyo_kanji]) in the kanji of the text,
+	/// from `0.0` to `1.0`. This is `1.0` for a text without kanji.
+	pub joyo_coverage: f64,
+
+	/// Average length of the words in the text, in characters.
+	///
+	/// Words are the kanji, kana, roman and number tokens from [tokenize],
+	/// so kanji are counted together with their okurigana.
+	pub average_word_length: f64,
+
+	/// Number of words in the text.
+	pub words: usize,
+
+	/// Difficulty score from `0.0` (easiest) to `100.0`, combining the kanji
+	/// ratio (50%), the ratio of kanji outside the jōyō list (20%) and the
+	/// average word length (30%, saturating at five characters).
+	pub score: f64,
+}
+
+/// Returns the readability metrics for the text.
+///
+/// ```
+/// let easy = kana::readability("これは ほんです。");
+/// let hard = kana::readability("憲法改正手続");
+/// assert!(easy.score < hard.score);
+/// assert_eq!(easy.kanji_ratio, 0.0);
+/// assert_eq!(hard.kanji_ratio, 1.0);
+///
+/// let rare = kana::readability("薔薇の花");
+/// assert!(rare.joyo_coverage < 1.0);
+/// ```
+pub fn readability<S: AsRef<str>>(text: S) -> Readability {
+	let text = text.as_ref();
+	let mut kanji = 0;
+	let mut joyo = 0;
+	let mut marks = 0;
+	let mut kana = 0;
+	for (chr, kind) in classify(text) {
+		match kind {
+			CharKind::Kanji => {
+				kanji += 1;
+				if is_joyo_kanji(chr) {
+					joyo += 1;
+				}
+			}
+			CharKind::JapaneseMark if chr == '々' => marks += 1,
+			CharKind::JapaneseMark if matches!(chr, 'ゝ' | 'ゞ' | 'ヽ' | 'ヾ') => kana += 1,
+			CharKind::Hiragana | CharKind::Katakana | CharKind::KatakanaHalfWidth => kana += 1,
+			CharKind::BarLine => kana += 1,
+			_ => {}
+		}
+	}
+
+	let mut words = 0;
+	let mut word_chars = 0;
+	for token in tokenize(text) {
+		match token.kind {
+			TokenKind::Kanji
+			| TokenKind::Hiragana
+			| TokenKind::Katakana
+			| TokenKind::Roman
+			| TokenKind::Number => {
+				words += 1;
+				word_chars += token.text.chars().count();
+			}
+			_ => {}
+		}
+	}
+
+	// the iteration mark `々` counts as a kanji, but not for the coverage
+	let kanji_ratio = if kanji + marks + kana > 0 {
+		(kanji + marks) as f64 / (kanji + marks + kana) as f64
+	} else {
+		0.0
+	};
+	let joyo_coverage = if kanji > 0 {
+		joyo as f64 / kanji as f64
+	} else {
+		1.0
+	};
+	let average_word_length = if words > 0 {
+		word_chars as f64 / words as f64
+	} else {
+		0.0
+	};
+	let length_score = ((average_word_length - 1.0) / 4.0).clamp(0.0, 1.0);
+	Readability {
+		kanji_ratio,
+		joyo_coverage,
+		average_word_length,
+		words,
+		score: 100.0 * (0.5 * kanji_ratio + 0.2 * (1.0 - joyo_coverage) + 0.3 * length_score),
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn near(a: f64, b: f64) -> bool {
+		(a - b).abs() < 1e-9
+	}
+
+	#[test]
+	fn test_readability() {
+		let empty = Readability {
+			joyo_coverage: 1.0,
+			..Readability::default()
+		};
+		assert_eq!(readability(""), empty);
+		assert_eq!(readability("。、 !"), empty);
+
+		let r = readability("漢字とカタカナ");
+		assert!(near(r.kanji_ratio, 2.0 / 7.0));
+		assert!(near(r.joyo_coverage, 1.0));
+		assert_eq!(r.words, 2);
+		assert!(near(r.average_word_length, 3.5));
+
+		let r = readability("人々は ABC を");
+		assert!(near(r.kanji_ratio, 0.5));
+		assert!(near(r.joyo_coverage, 1.0));
+		assert_eq!(r.words, 3);
+		assert!(near(r.average_word_length, 7.0 / 3.0));
+	}
+
+	#[test]
+	fn test_readability_components() {
+		// kanji ratio
+		assert!(near(readability("ねこ").kanji_ratio, 0.0));
+		assert!(near(readability("猫とネコ").kanji_ratio, 0.25));
+		assert!(near(readability("日々").kanji_ratio, 1.0));
+
+		// jōyō coverage, ignoring `々`
+		assert!(near(readability("薔薇と猫").joyo_coverage, 1.0 / 3.0));
+		assert!(near(readability("薔薇").joyo_coverage, 0.0));
+		assert!(near(readability("日々").joyo_coverage, 1.0));
+		assert!(near(readability("ねこ").joyo_coverage, 1.0));
+
+		// word length, from the tokens
+		let r = readability("ラーメンを食べる、2020年");
+		assert_eq!(r.words, 5);
+		assert!(near(r.average_word_length, 13.0 / 5.0));
+
+		// score
+		assert!(near(readability("ね").score, 0.0));
+		assert!(near(readability("日本国憲法").score, 80.0));
+		assert!(near(readability("魑魍魎").score, 50.0 + 20.0 + 15.0));
+		assert!(readability("ねこ").score < readability("猫").score);
+		assert!(readability("猫").score < readability("鼠").score);
+	}
+}
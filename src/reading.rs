@@ -0,0 +1,482 @@
+//! Matching of kana readings against words written with kanji.
+//!
+//! The alignment here is based only on the okurigana and other kana in the
+//! word. With the `readings` feature, [rank_reading_candidates] also uses the
+//! kanji readings data to rank the candidates.
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use super::is::*;
+use super::mora::*;
+use super::util::katakana_to_hiragana;
+
+/// Aligned segment of a word and its reading, returned by [align_reading].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReadingSegment {
+	/// Byte range of the segment in the word.
+	pub text: Range<usize>,
+	/// Byte range of the segment in the reading.
+	pub reading: Range<usize>,
+	/// True if the segment is a kanji run, false for kana or other text that
+	/// must match the reading exactly.
+	pub is_kanji: bool,
+}
+
+/// Returns true for characters that are read as part of a kanji run.
+fn is_kanji_like(chr: char) -> bool {
+	is_kanji(chr) || chr == '々' || chr == '〆' || chr == 'ヶ' || chr == 'ヵ'
+}
+
+/// Aligns a word written with kanji to its kana reading.
+///
+/// The kana in the word (e.g. the okurigana in `食べる`) must match the
+/// reading, while each run of kanji takes the part of the reading between
+/// them. Returns `None` if the word cannot be read as the given reading.
+///
+/// Kana are compared ignoring the difference between hiragana and katakana.
+/// When more than one alignment is possible, the kanji runs take the shortest
+/// possible reading from left to right.
+///
+/// ```
+/// let segments = kana::align_reading("食べ物", "たべもの").unwrap();
+/// let parts = segments
+///     .iter()
+///     .map(|x| (&"食べ物"[x.text.clone()], &"たべもの"[x.reading.clone()]))
+///     .collect::<Vec<_>>();
+/// assert_eq!(parts, vec![("食", "た"), ("べ", "べ"), ("物", "もの")]);
+///
+/// assert!(kana::align_reading("食べる", "たべた").is_none());
+/// ```
+pub fn align_reading(word: &str, reading: &str) -> Option<Vec<ReadingSegment>> {
+	let mut runs = Vec::new();
+	let mut chars = word.char_indices().peekable();
+	while let Some((start, chr)) = chars.next() {
+		let kanji = is_kanji_like(chr);
+		let mut end = start + chr.len_utf8();
+		while let Some(&(pos, next)) = chars.peek() {
+			if kanji != is_kanji_like(next) || !kanji {
+				break;
+			}
+			end = pos + next.len_utf8();
+			chars.next();
+		}
+		runs.push((start..end, kanji));
+	}
+
+	let mut segments = Vec::with_capacity(runs.len());
+	let mut failed = HashSet::new();
+	if align(word, &runs, reading, 0, &mut segments, &mut failed) {
+		// merge consecutive kana segments
+		let mut merged: Vec<ReadingSegment> = Vec::with_capacity(segments.len());
+		for segment in segments {
+			if let Some(last) = merged.last_mut() {
+				if !last.is_kanji && !segment.is_kanji {
+					last.text.end = segment.text.end;
+					last.reading.end = segment.reading.end;
+					continue;
+				}
+			}
+			merged.push(segment);
+		}
+		Some(merged)
+	} else {
+		None
+	}
+}
+
+/// Aligns the remaining runs of the word with the reading from `pos`, pushing
+/// the aligned segments.
+///
+/// The alignments that failed are kept in `failed`, by the number of runs
+/// remaining and the reading position, so that each one is tried only once.
+/// Without this, backtracking is exponential in the number of kanji runs.
+fn align(
+	word: &str,
+	runs: &[(Range<usize>, bool)],
+	reading: &str,
+	pos: usize,
+	segments: &mut Vec<ReadingSegment>,
+	failed: &mut HashSet<(usize, usize)>,
+) -> bool {
+	if failed.contains(&(runs.len(), pos)) {
+		return false;
+	}
+	let aligned = align_run(word, runs, reading, pos, segments, failed);
+	if !aligned {
+		failed.insert((runs.len(), pos));
+	}
+	aligned
+}
+
+/// Aligns the first of the runs for [align].
+fn align_run(
+	word: &str,
+	runs: &[(Range<usize>, bool)],
+	reading: &str,
+	pos: usize,
+	segments: &mut Vec<ReadingSegment>,
+	failed: &mut HashSet<(usize, usize)>,
+) -> bool {
+	let (text, kanji) = match runs.first() {
+		Some(run) => run.clone(),
+		None => return pos == reading.len(),
+	};
+
+	if kanji {
+		let mut end = pos;
+		for chr in reading[pos..].chars() {
+			if !is_kana(chr) {
+				break;
+			}
+			end += chr.len_utf8();
+			segments.push(ReadingSegment {
+				text: text.clone(),
+				reading: pos..end,
+				is_kanji: true,
+			});
+			if align(word, &runs[1..], reading, end, segments, failed) {
+				return true;
+			}
+			segments.pop();
+		}
+		false
+	} else {
+		let expected = word[text.clone()].chars().next().unwrap();
+		match reading[pos..].chars().next() {
+			Some(chr) if katakana_to_hiragana(chr) == katakana_to_hiragana(expected) => {
+				let end = pos + chr.len_utf8();
+				segments.push(ReadingSegment {
+					text,
+					reading: pos..end,
+					is_kanji: false,
+				});
+				if align(word, &runs[1..], reading, end, segments, failed) {
+					return true;
+				}
+				segments.pop();
+				false
+			}
+			_ => false,
+		}
+	}
+}
+
+/// Ruby (furigana) layout for a single base character of a word, returned by
+/// [ruby_segments].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RubySegment {
+	/// Byte range of the base character in the word.
+	pub base: Range<usize>,
+	/// Byte range of the ruby text for the character in the reading. This is
+	/// empty for kana, which do not need ruby.
+	pub ruby: Range<usize>,
+	/// Number of morae in the ruby text, or in the base character itself for
+	/// kana.
+	pub morae: usize,
+	/// Index of the kanji run in the word that contains the character. This
+	/// is `None` for kana.
+	///
+	/// Since the reading of each kanji in a run is not known, layout engines
+	/// may prefer to lay the ruby over the whole run instead (group ruby).
+	pub group: Option<usize>,
+}
+
+/// Returns the ruby layout for each base character of a word, given its
+/// reading, or `None` if the word cannot be read as the reading.
+///
+/// The word is aligned to the reading with [align_reading]. The reading of a
+/// kanji run is then distributed between its characters by mora count, with
+/// earlier characters taking any remaining morae. This gives the mora counts
+/// needed to distribute the ruby glyphs proportionally over the base text,
+/// for instance in vertical layout.
+///
+/// ```
+/// let word = "東京都";
+/// let reading = "とうきょうと";
+/// let segments = kana::ruby_segments(word, reading).unwrap();
+/// let layout = segments
+///     .iter()
+///     .map(|x| (&word[x.base.clone()], &reading[x.ruby.clone()], x.morae))
+///     .collect::<Vec<_>>();
+/// assert_eq!(layout, vec![("東", "とう", 2), ("京", "きょう", 2), ("都", "と", 1)]);
+/// ```
+pub fn ruby_segments(word: &str, reading: &str) -> Option<Vec<RubySegment>> {
+	let mut out = Vec::new();
+	let mut group = 0;
+	for segment in align_reading(word, reading)? {
+		let base = &word[segment.text.clone()];
+		if !segment.is_kanji {
+			for (pos, chr) in base.char_indices() {
+				let start = segment.text.start + pos;
+				let morae = if is_mora_glide(chr) { 0 } else { 1 };
+				out.push(RubySegment {
+					base: start..start + chr.len_utf8(),
+					ruby: segment.reading.start..segment.reading.start,
+					morae,
+					group: None,
+				});
+			}
+			continue;
+		}
+
+		let morae = Morae::new(&reading[segment.reading.clone()]).collect::<Vec<_>>();
+		let count = base.chars().count();
+		let mut ruby_pos = segment.reading.start;
+		let mut next_mora = 0;
+		for (index, (pos, chr)) in base.char_indices().enumerate() {
+			let share = morae.len() / count + if index < morae.len() % count { 1 } else { 0 };
+			let ruby_end = ruby_pos
+				+ morae[next_mora..next_mora + share]
+					.iter()
+					.map(|x| x.len())
+					.sum::<usize>();
+			next_mora += share;
+			let start = segment.text.start + pos;
+			out.push(RubySegment {
+				base: start..start + chr.len_utf8(),
+				ruby: ruby_pos..ruby_end,
+				morae: share,
+				group: Some(group),
+			});
+			ruby_pos = ruby_end;
+		}
+		group += 1;
+	}
+	Some(out)
+}
+
+/// Filters and ranks the candidate words that can be read as the given
+/// reading, such as the entries from a dictionary for an IME candidate list.
+///
+/// Candidates are matched using [align_reading]. Candidates with more kana
+/// matching the reading (e.g. okurigana) rank first, followed by the ones
+/// with a reading length closer to two characters per kanji. The order of
+/// the input is kept for candidates that rank the same.
+///
+/// Note that without reading data any kanji can take any part of the reading,
+/// so candidates are only filtered by their kana (e.g. `滑る` is kept below,
+/// even though it is read `すべる`).
+///
+/// With the `readings` feature, candidates with all of their kanji in the
+/// readings data (see [kanji_readings]) rank first if each kanji run can be
+/// read from the readings of its kanji, and last otherwise. Candidates with
+/// kanji that are not in the data rank in between. Words with special
+/// readings (e.g. `今日` for `きょう`) are kept, but rank last.
+///
+/// ```
+/// let candidates = ["食べる", "滑る", "食う", "喰べる", "統べる"];
+/// let ranked = kana::rank_reading_candidates("たべる", &candidates);
+/// assert_eq!(ranked, vec!["食べる", "喰べる", "統べる", "滑る"]);
+/// ```
+pub fn rank_reading_candidates<'a, S: AsRef<str>>(
+	reading: &str,
+	candidates: &'a [S],
+) -> Vec<&'a str> {
+	let mut matches = candidates
+		.iter()
+		.map(|x| x.as_ref())
+		.filter_map(|word| {
+			let segments = align_reading(word, reading)?;
+			let mut known = Some(true);
+			let mut kana = 0;
+			let mut distance = 0;
+			for segment in segments.iter() {
+				let count = reading[segment.reading.clone()].chars().count();
+				if segment.is_kanji {
+					let kanji = word[segment.text.clone()].chars().count();
+					distance += (count as isize - 2 * kanji as isize).abs();
+					known = match (known, match_readings(word, reading, segment)) {
+						(Some(false), _) | (_, Some(false)) => Some(false),
+						(Some(true), Some(true)) => Some(true),
+						_ => None,
+					};
+				} else {
+					kana += count;
+				}
+			}
+			let rank = match known {
+				Some(true) => 0,
+				None => 1,
+				Some(false) => 2,
+			};
+			Some((word, rank, kana, distance))
+		})
+		.collect::<Vec<_>>();
+	matches.sort_by(|a, b| a.1.cmp(&b.1).then(b.2.cmp(&a.2)).then(a.3.cmp(&b.3)));
+	matches.into_iter().map(|x| x.0).collect()
+}
+
+/// Returns true if the kanji run of the segment can be read from the kanji
+/// readings data, or `None` if any of the kanji is not in the data.
+#[cfg(feature = "readings")]
+fn match_readings(word: &str, reading: &str, segment: &ReadingSegment) -> Option<bool> {
+	let text = &word[segment.text.clone()];
+	super::readings::match_kanji_readings(text, &reading[segment.reading.clone()])
+}
+
+/// Without the `readings` feature, no kanji is in the readings data.
+#[cfg(not(feature = "readings"))]
+fn match_readings(_word: &str, _reading: &str, _segment: &ReadingSegment) -> Option<bool> {
+	None
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parts<'a>(word: &'a str, reading: &'a str) -> Option<Vec<(&'a str, &'a str)>> {
+		align_reading(word, reading).map(|segments| {
+			segments
+				.iter()
+				.map(|x| (&word[x.text.clone()], &reading[x.reading.clone()]))
+				.collect()
+		})
+	}
+
+	#[test]
+	fn test_align_reading() {
+		assert_eq!(parts("", ""), Some(vec![]));
+		assert_eq!(parts("漢字", "かんじ"), Some(vec![("漢字", "かんじ")]));
+		assert_eq!(
+			parts("食べる", "たべる"),
+			Some(vec![("食", "た"), ("べる", "べる")])
+		);
+		assert_eq!(
+			parts("お手洗い", "おてあらい"),
+			Some(vec![("お", "お"), ("手洗", "てあら"), ("い", "い")])
+		);
+		assert_eq!(parts("人々", "ひとびと"), Some(vec![("人々", "ひとびと")]));
+		assert_eq!(
+			parts("コーヒー豆", "こーひーまめ"),
+			Some(vec![("コーヒー", "こーひー"), ("豆", "まめ")])
+		);
+		assert_eq!(parts("食べる", "たべた"), None);
+		assert_eq!(parts("食べる", "べる"), None);
+		assert_eq!(parts("漢字", "かんじ、かんじ"), None);
+		assert_eq!(parts("漢字", "kanji"), None);
+
+		// Long kanji readings
+		assert_eq!(
+			parts("承る", "うけたまわる"),
+			Some(vec![("承", "うけたまわ"), ("る", "る")])
+		);
+		assert_eq!(parts("志", "こころざし"), Some(vec![("志", "こころざし")]));
+		assert_eq!(parts("詔", "みことのり"), Some(vec![("詔", "みことのり")]));
+		assert_eq!(
+			parts("志す", "こころざす"),
+			Some(vec![("志", "こころざ"), ("す", "す")])
+		);
+
+		// Must not backtrack exponentially
+		let word = "字あ".repeat(40);
+		let reading = "ああああ".repeat(40) + "ん";
+		assert_eq!(align_reading(&word, &reading), None);
+		let reading = "ああ".repeat(40);
+		assert_eq!(align_reading(&word, &reading).map(|x| x.len()), Some(80));
+	}
+
+	#[test]
+	fn test_ruby_segments() {
+		fn layout<'a>(
+			word: &'a str,
+			reading: &'a str,
+		) -> Vec<(&'a str, &'a str, usize, Option<usize>)> {
+			ruby_segments(word, reading)
+				.unwrap()
+				.iter()
+				.map(|x| {
+					(
+						&word[x.base.clone()],
+						&reading[x.ruby.clone()],
+						x.morae,
+						x.group,
+					)
+				})
+				.collect()
+		}
+
+		assert_eq!(layout("", ""), vec![]);
+		assert_eq!(
+			layout("食べ物", "たべもの"),
+			vec![
+				("食", "た", 1, Some(0)),
+				("べ", "", 1, None),
+				("物", "もの", 2, Some(1))
+			]
+		);
+		assert_eq!(
+			layout("今日", "きょう"),
+			vec![("今", "きょ", 1, Some(0)), ("日", "う", 1, Some(0))]
+		);
+		assert_eq!(
+			layout("大人しい", "おとなしい"),
+			vec![
+				("大", "おと", 2, Some(0)),
+				("人", "な", 1, Some(0)),
+				("し", "", 1, None),
+				("い", "", 1, None),
+			]
+		);
+		assert_eq!(
+			layout("ジャム瓶", "じゃむびん"),
+			vec![
+				("ジ", "", 1, None),
+				("ャ", "", 0, None),
+				("ム", "", 1, None),
+				("瓶", "びん", 2, Some(0)),
+			]
+		);
+		assert_eq!(ruby_segments("食べる", "たべた"), None);
+	}
+
+	#[test]
+	#[cfg(not(feature = "readings"))]
+	fn test_rank_reading_candidates() {
+		let empty: [&str; 0] = [];
+		assert!(rank_reading_candidates("たべる", &empty).is_empty());
+
+		let candidates = vec!["生", "生きる", "行きる", "息", "粋", "意気込み"];
+		assert_eq!(
+			rank_reading_candidates("いき", &candidates),
+			vec!["生", "息", "粋"]
+		);
+		assert_eq!(
+			rank_reading_candidates("いきごみ", &candidates),
+			vec!["意気込み", "生", "息", "粋"]
+		);
+		assert_eq!(
+			rank_reading_candidates("いきる", &candidates),
+			vec!["生きる", "行きる", "生", "息", "粋"]
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "readings")]
+	fn test_rank_reading_candidates_with_readings() {
+		let candidates = vec!["飴", "空", "雨"];
+		assert_eq!(
+			rank_reading_candidates("あめ", &candidates),
+			vec!["雨", "飴", "空"]
+		);
+
+		let candidates = vec!["今日", "京", "強"];
+		assert_eq!(
+			rank_reading_candidates("きょう", &candidates),
+			vec!["京", "強", "今日"]
+		);
+
+		let candidates = vec!["手神", "手紙"];
+		assert_eq!(
+			rank_reading_candidates("てがみ", &candidates),
+			vec!["手紙", "手神"]
+		);
+		let candidates = vec!["日々", "人々"];
+		assert_eq!(
+			rank_reading_candidates("ひとびと", &candidates),
+			vec!["人々", "日々"]
+		);
+	}
+}
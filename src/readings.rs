@@ -0,0 +1,368 @@
+//! Kanji readings data, used to rank the candidates of
+//! [rank_reading_candidates](super::rank_reading_candidates).
+//!
+//! Only available with the `readings` feature.
+
+use fnv::FnvHashMap;
+
+use super::util::{decompose_voiced, katakana_to_hiragana};
+
+/// Readings of the first and second grade kyōiku kanji, with the on'yomi
+/// first. Readings are in hiragana and kun'yomi do not include okurigana.
+#[rustfmt::skip]
+const KANJI_READINGS: &[(char, &str)] = &[
+	// First grade
+	('一', "いち いつ ひと"),
+	('右', "う ゆう みぎ"),
+	('雨', "う あめ あま"),
+	('円', "えん まる"),
+	('王', "おう"),
+	('音', "おん いん おと ね"),
+	('下', "か げ した しも もと さ くだ お"),
+	('火', "か ひ ほ"),
+	('花', "か はな"),
+	('貝', "ばい かい"),
+	('学', "がく まな"),
+	('気', "き け"),
+	('九', "きゅう く ここの"),
+	('休', "きゅう やす"),
+	('玉', "ぎょく たま"),
+	('金', "きん こん かね かな"),
+	('空', "くう そら あ から"),
+	('月', "げつ がつ つき"),
+	('犬', "けん いぬ"),
+	('見', "けん み"),
+	('五', "ご いつ"),
+	('口', "こう く くち"),
+	('校', "こう"),
+	('左', "さ ひだり"),
+	('三', "さん み"),
+	('山', "さん やま"),
+	('子', "し す こ"),
+	('四', "し よ よん"),
+	('糸', "し いと"),
+	('字', "じ あざ"),
+	('耳', "じ みみ"),
+	('七', "しち なな なの"),
+	('車', "しゃ くるま"),
+	('手', "しゅ て た"),
+	('十', "じゅう じっ じゅっ とお と"),
+	('出', "しゅつ すい で だ"),
+	('女', "じょ にょ にょう おんな め"),
+	('小', "しょう ちい こ お"),
+	('上', "じょう しょう うえ うわ かみ あ のぼ"),
+	('森', "しん もり"),
+	('人', "じん にん ひと"),
+	('水', "すい みず"),
+	('正', "せい しょう ただ まさ"),
+	('生', "せい しょう い う は き なま お"),
+	('青', "せい しょう あお"),
+	('夕', "せき ゆう"),
+	('石', "せき しゃく こく いし"),
+	('赤', "せき しゃく あか"),
+	('千', "せん ち"),
+	('川', "せん かわ"),
+	('先', "せん さき"),
+	('早', "そう さっ はや"),
+	('草', "そう くさ"),
+	('足', "そく あし た"),
+	('村', "そん むら"),
+	('大', "だい たい おお"),
+	('男', "だん なん おとこ"),
+	('竹', "ちく たけ"),
+	('中', "ちゅう じゅう なか"),
+	('虫', "ちゅう むし"),
+	('町', "ちょう まち"),
+	('天', "てん あめ あま"),
+	('田', "でん た"),
+	('土', "ど と つち"),
+	('二', "に ふた"),
+	('日', "にち じつ ひ か"),
+	('入', "にゅう い はい"),
+	('年', "ねん とし"),
+	('白', "はく びゃく しろ しら"),
+	('八', "はち や やっ よう"),
+	('百', "ひゃく"),
+	('文', "ぶん もん ふみ"),
+	('木', "ぼく もく き こ"),
+	('本', "ほん もと"),
+	('名', "めい みょう な"),
+	('目', "もく ぼく め ま"),
+	('立', "りつ りゅう た"),
+	('力', "りょく りき ちから"),
+	('林', "りん はやし"),
+	('六', "ろく む むっ むい"),
+	// Second grade
+	('引', "いん ひ"),
+	('羽', "う は わ"),
+	('雲', "うん くも"),
+	('園', "えん その"),
+	('遠', "えん おん とお"),
+	('何', "か なに なん"),
+	('科', "か"),
+	('夏', "か げ なつ"),
+	('家', "か け いえ や"),
+	('歌', "か うた"),
+	('画', "が かく"),
+	('回', "かい え まわ"),
+	('会', "かい え あ"),
+	('海', "かい うみ"),
+	('絵', "かい え"),
+	('外', "がい げ そと ほか はず"),
+	('角', "かく かど つの"),
+	('楽', "がく らく たの"),
+	('活', "かつ"),
+	('間', "かん けん あいだ ま"),
+	('丸', "がん まる"),
+	('岩', "がん いわ"),
+	('顔', "がん かお"),
+	('汽', "き"),
+	('記', "き しる"),
+	('帰', "き かえ"),
+	('弓', "きゅう ゆみ"),
+	('牛', "ぎゅう うし"),
+	('魚', "ぎょ うお さかな"),
+	('京', "きょう けい"),
+	('強', "きょう ごう つよ し"),
+	('教', "きょう おし おそ"),
+	('近', "きん ちか"),
+	('兄', "けい きょう あに"),
+	('形', "けい ぎょう かた かたち"),
+	('計', "けい はか"),
+	('元', "げん がん もと"),
+	('言', "げん ごん い こと"),
+	('原', "げん はら"),
+	('戸', "こ と"),
+	('古', "こ ふる"),
+	('午', "ご"),
+	('後', "ご こう のち うし あと おく"),
+	('語', "ご かた"),
+	('工', "こう く"),
+	('公', "こう おおやけ"),
+	('広', "こう ひろ"),
+	('交', "こう まじ ま か"),
+	('光', "こう ひかり ひか"),
+	('考', "こう かんが"),
+	('行', "こう ぎょう あん い ゆ おこな"),
+	('高', "こう たか"),
+	('黄', "こう おう き"),
+	('合', "ごう がっ かっ あ"),
+	('谷', "こく たに"),
+	('国', "こく くに"),
+	('黒', "こく くろ"),
+	('今', "こん きん いま"),
+	('才', "さい"),
+	('細', "さい ほそ こま"),
+	('作', "さく さ つく"),
+	('算', "さん"),
+	('止', "し と"),
+	('市', "し いち"),
+	('矢', "し や"),
+	('姉', "し あね"),
+	('思', "し おも"),
+	('紙', "し かみ"),
+	('寺', "じ てら"),
+	('自', "じ し みずか"),
+	('時', "じ とき"),
+	('室', "しつ むろ"),
+	('社', "しゃ やしろ"),
+	('弱', "じゃく よわ"),
+	('首', "しゅ くび"),
+	('秋', "しゅう あき"),
+	('週', "しゅう"),
+	('春', "しゅん はる"),
+	('書', "しょ か"),
+	('少', "しょう すく すこ"),
+	('場', "じょう ば"),
+	('色', "しょく しき いろ"),
+	('食', "しょく じき く た"),
+	('心', "しん こころ"),
+	('新', "しん あたら あら にい"),
+	('親', "しん おや した"),
+	('図', "ず と はか"),
+	('数', "すう す かず かぞ"),
+	('西', "せい さい にし"),
+	('声', "せい しょう こえ こわ"),
+	('星', "せい しょう ほし"),
+	('晴', "せい は"),
+	('切', "せつ さい き"),
+	('雪', "せつ ゆき"),
+	('船', "せん ふね ふな"),
+	('線', "せん"),
+	('前', "ぜん まえ"),
+	('組', "そ くみ く"),
+	('走', "そう はし"),
+	('多', "た おお"),
+	('太', "たい た ふと"),
+	('体', "たい てい からだ"),
+	('台', "だい たい"),
+	('地', "ち じ"),
+	('池', "ち いけ"),
+	('知', "ち し"),
+	('茶', "ちゃ さ"),
+	('昼', "ちゅう ひる"),
+	('長', "ちょう なが"),
+	('鳥', "ちょう とり"),
+	('朝', "ちょう あさ"),
+	('直', "ちょく じき ただ なお"),
+	('通', "つう つ とお かよ"),
+	('弟', "てい だい で おとうと"),
+	('店', "てん みせ"),
+	('点', "てん"),
+	('電', "でん"),
+	('刀', "とう かたな"),
+	('冬', "とう ふゆ"),
+	('当', "とう あ"),
+	('東', "とう ひがし"),
+	('答', "とう こた"),
+	('頭', "とう ず と あたま かしら"),
+	('同', "どう おな"),
+	('道', "どう とう みち"),
+	('読', "どく とく とう よ"),
+	('内', "ない だい うち"),
+	('南', "なん な みなみ"),
+	('肉', "にく"),
+	('馬', "ば うま ま"),
+	('売', "ばい う"),
+	('買', "ばい か"),
+	('麦', "ばく むぎ"),
+	('半', "はん なか"),
+	('番', "ばん"),
+	('父', "ふ ちち"),
+	('風', "ふう ふ かぜ かざ"),
+	('分', "ぶん ふん ぶ わ"),
+	('聞', "ぶん もん き"),
+	('米', "べい まい こめ"),
+	('歩', "ほ ぶ ふ ある あゆ"),
+	('母', "ぼ はは"),
+	('方', "ほう かた"),
+	('北', "ほく きた"),
+	('毎', "まい"),
+	('妹', "まい いもうと"),
+	('万', "まん ばん"),
+	('明', "めい みょう あ あか あき"),
+	('鳴', "めい な"),
+	('毛', "もう け"),
+	('門', "もん かど"),
+	('夜', "や よ よる"),
+	('野', "や の"),
+	('友', "ゆう とも"),
+	('用', "よう もち"),
+	('曜', "よう"),
+	('来', "らい く き こ"),
+	('里', "り さと"),
+	('理', "り"),
+	('話', "わ はな はなし"),
+];
+
+lazy_static! {
+	static ref READINGS: FnvHashMap<char, Vec<&'static str>> = KANJI_READINGS
+		.iter()
+		.map(|&(chr, readings)| (chr, readings.split(' ').collect()))
+		.collect();
+}
+
+/// Returns the readings of a kanji in hiragana, with the on'yomi first, or
+/// `None` if the kanji is not in the readings data.
+///
+/// The data currently covers the kanji taught in the first two grades of
+/// elementary school. Kun'yomi are given without okurigana (e.g. `た` for
+/// `食べる`).
+///
+/// Only available with the `readings` feature.
+///
+/// ```
+/// assert_eq!(kana::kanji_readings('雨'), Some(&["う", "あめ", "あま"][..]));
+/// assert_eq!(kana::kanji_readings('鬱'), None);
+/// ```
+pub fn kanji_readings(chr: char) -> Option<&'static [&'static str]> {
+	READINGS.get(&chr).map(|readings| readings.as_slice())
+}
+
+/// Returns the length of the reading of a kanji at the start of `text`, or
+/// `None` if the text does not start with it.
+///
+/// Kanji after the first can be voiced (rendaku, e.g. `がみ` in `てがみ`)
+/// and kanji before the last can end in a `っ` (e.g. `がっ` in `がっこう`).
+fn match_reading(reading: &str, text: &str, first: bool, last: bool) -> Option<usize> {
+	let count = reading.chars().count();
+	let mut len = 0;
+	let mut chars = text.chars();
+	for (index, expected) in reading.chars().enumerate() {
+		let chr = katakana_to_hiragana(chars.next()?);
+		let voiced = index == 0 && !first && decompose_voiced(chr).map(|x| x.0) == Some(expected);
+		let geminated = index == count - 1
+			&& !last && chr == 'っ'
+			&& matches!(expected, 'つ' | 'く' | 'ち' | 'き');
+		if chr != expected && !voiced && !geminated {
+			return None;
+		}
+		len += chr.len_utf8();
+	}
+	Some(len)
+}
+
+/// Returns true if the kanji from `index` can be read as the text, with each
+/// kanji taking one of its readings.
+fn match_kanji(kanji: &[char], index: usize, text: &str) -> bool {
+	if index == kanji.len() {
+		return text.is_empty();
+	}
+	let chr = match kanji[index] {
+		'々' if index > 0 => kanji[index - 1],
+		chr => chr,
+	};
+	kanji_readings(chr).unwrap_or(&[]).iter().any(|reading| {
+		let first = index == 0;
+		let last = index == kanji.len() - 1;
+		match match_reading(reading, text, first, last) {
+			Some(len) => match_kanji(kanji, index + 1, &text[len..]),
+			None => false,
+		}
+	})
+}
+
+/// Returns true if the run of kanji can be read as the text using the
+/// readings data, or `None` if any of the kanji is not in the data.
+pub(crate) fn match_kanji_readings(kanji: &str, text: &str) -> Option<bool> {
+	let kanji = kanji.chars().collect::<Vec<_>>();
+	for (index, &chr) in kanji.iter().enumerate() {
+		if !(chr == '々' && index > 0) {
+			kanji_readings(chr)?;
+		}
+	}
+	Some(match_kanji(&kanji, 0, text))
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_match_kanji_readings() {
+		assert_eq!(match_kanji_readings("雨", "あめ"), Some(true));
+		assert_eq!(match_kanji_readings("雨", "そら"), Some(false));
+		assert_eq!(match_kanji_readings("鬱", "うつ"), None);
+		assert_eq!(match_kanji_readings("手紙", "てがみ"), Some(true));
+		assert_eq!(match_kanji_readings("学校", "がっこう"), Some(true));
+		assert_eq!(match_kanji_readings("人々", "ひとびと"), Some(true));
+		assert_eq!(match_kanji_readings("森林", "しんりん"), Some(true));
+
+		// Rendaku and gemination only happen inside the run
+		assert_eq!(match_kanji_readings("紙", "がみ"), Some(false));
+		assert_eq!(match_kanji_readings("学", "がっ"), Some(false));
+
+		// Readings that are not made of the reading of each kanji
+		assert_eq!(match_kanji_readings("今日", "きょう"), Some(false));
+
+		for &(chr, readings) in KANJI_READINGS.iter() {
+			assert!(kanji_readings(chr).is_some(), "{}", chr);
+			assert!(readings
+				.chars()
+				.all(|x| x == ' ' || ('ぁ'..='ん').contains(&x)));
+		}
+		assert_eq!(READINGS.len(), KANJI_READINGS.len());
+	}
+}
@@ -0,0 +1,55 @@
+//! Reports describing the result of a conversion.
+
+use std::ops::Range;
+
+use super::chunks::*;
+
+/// Report describing a conversion, returned by functions such as
+/// [to_romaji_report](fn.to_romaji_report.html).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConversionReport {
+	/// Byte ranges of the input that were passed through unchanged, in order.
+	///
+	/// Consecutive characters are merged into a single range.
+	pub unconverted: Vec<Range<usize>>,
+
+	/// Number of chunks of the input that were converted.
+	pub converted_chunks: usize,
+
+	/// Keys of the conversion table entries used by the conversion and how
+	/// many times each one was used, in order of first use.
+	pub table_entries: Vec<(&'static str, usize)>,
+}
+
+impl ConversionReport {
+	/// Returns true if the entire input was converted.
+	pub fn is_complete(&self) -> bool {
+		self.unconverted.is_empty()
+	}
+
+	/// Adds a converted chunk to the report.
+	pub(crate) fn add(&mut self, chunk: &Chunk) {
+		match chunk.kind {
+			ChunkKind::Passthrough => {
+				if let Some(last) = self.unconverted.last_mut() {
+					if last.end == chunk.src.start {
+						last.end = chunk.src.end;
+						return;
+					}
+				}
+				self.unconverted.push(chunk.src.clone());
+			}
+			ChunkKind::Rule => {
+				self.converted_chunks += 1;
+			}
+			ChunkKind::Table(key) => {
+				self.converted_chunks += 1;
+				if let Some(entry) = self.table_entries.iter_mut().find(|x| x.0 == key) {
+					entry.1 += 1;
+				} else {
+					self.table_entries.push((key, 1));
+				}
+			}
+		}
+	}
+}
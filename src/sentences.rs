@@ -0,0 +1,143 @@
+//! Splitting of Japanese text into sentences.
+
+use super::is::is_space;
+
+/// Returns true for the characters that end a sentence.
+fn is_terminator(chr: char) -> bool {
+	matches!(chr, '。' | '｡' | '！' | '？' | '‼' | '⁇' | '⁈' | '⁉')
+}
+
+/// Returns true for the opening quotes and brackets.
+fn is_opening(chr: char) -> bool {
+	matches!(
+		chr,
+		'「' | '『' | '（' | '〔' | '［' | '｛' | '〈' | '《' | '【' | '〖' | '〘' | '｢'
+	)
+}
+
+/// Returns true for the closing quotes and brackets.
+fn is_closing(chr: char) -> bool {
+	matches!(
+		chr,
+		'」' | '』' | '）' | '〕' | '］' | '｝' | '〉' | '》' | '】' | '〗' | '〙' | '｣'
+	)
+}
+
+/// Returns an iterator over the sentences in the text.
+///
+/// Sentences end at `。`, `！`, `？` and `⁉` (including runs such as `！？`),
+/// keeping any closing quotes or brackets that follow (e.g. `」` and `）`)
+/// with the sentence. Text inside quotes or brackets is never split, so
+/// quoted speech stays in the sentence that contains it, except that a
+/// quote ending in a terminator and followed by another quote, whitespace or
+/// the end of the text is its own sentence (e.g. `「はい。」「いいえ。」`).
+///
+/// Whitespace between sentences is skipped.
+///
+/// ```
+/// let text = "「行こう。」と言った。本当？ はい！";
+/// let sentences = kana::split_sentences(text).collect::<Vec<_>>();
+/// assert_eq!(sentences, vec!["「行こう。」と言った。", "本当？", "はい！"]);
+/// ```
+pub fn split_sentences<'a>(text: &'a str) -> Sentences<'a> {
+	Sentences { text, offset: 0 }
+}
+
+/// Iterator returned by [split_sentences].
+#[derive(Clone, Debug)]
+pub struct Sentences<'a> {
+	text: &'a str,
+	offset: usize,
+}
+
+impl<'a> Iterator for Sentences<'a> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rest = &self.text[self.offset..];
+		let start = self.offset + (rest.len() - rest.trim_start_matches(is_space).len());
+		if start == self.text.len() {
+			self.offset = start;
+			return None;
+		}
+
+		let mut chars = self.text[start..].char_indices().peekable();
+		let mut end = self.text.len();
+		let mut depth = 0_usize;
+		let mut quote_terminated = false;
+		while let Some((index, chr)) = chars.next() {
+			if is_opening(chr) {
+				depth += 1;
+				quote_terminated = false;
+			} else if is_closing(chr) {
+				depth = depth.saturating_sub(1);
+				let next = chars.peek().map(|x| x.1);
+				if depth == 0
+					&& quote_terminated
+					&& next.map(|x| is_opening(x) || is_space(x)).unwrap_or(true)
+				{
+					end = start + index + chr.len_utf8();
+					break;
+				}
+			} else if is_terminator(chr) && depth > 0 {
+				quote_terminated = true;
+			} else if is_terminator(chr) {
+				// Keep the following terminators and closing brackets.
+				end = start + index + chr.len_utf8();
+				while let Some(&(index, chr)) = chars.peek() {
+					if !is_terminator(chr) && !is_closing(chr) {
+						break;
+					}
+					end = start + index + chr.len_utf8();
+					chars.next();
+				}
+				break;
+			} else {
+				quote_terminated = false;
+			}
+		}
+
+		self.offset = end;
+		Some(self.text[start..end].trim_end_matches(is_space))
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn split(text: &str) -> Vec<&str> {
+		split_sentences(text).collect()
+	}
+
+	#[test]
+	fn test_split_sentences() {
+		assert!(split("").is_empty());
+		assert!(split(" \n　").is_empty());
+		assert_eq!(split("はい"), vec!["はい"]);
+		assert_eq!(split("はい。 "), vec!["はい。"]);
+		assert_eq!(split("はい。いいえ。"), vec!["はい。", "いいえ。"]);
+		assert_eq!(
+			split("本当！？うそ⁉　ええ｡"),
+			vec!["本当！？", "うそ⁉", "ええ｡"]
+		);
+		assert_eq!(
+			split("彼は「もう帰る。また明日！」と言った。"),
+			vec!["彼は「もう帰る。また明日！」と言った。"]
+		);
+		assert_eq!(
+			split("（笑）。本当に（たぶん。）\n次。"),
+			vec!["（笑）。", "本当に（たぶん。）", "次。"]
+		);
+		assert_eq!(
+			split("「はい。」「いいえ。」\nそう。"),
+			vec!["「はい。」", "「いいえ。」", "そう。"]
+		);
+		assert_eq!(split("「終わり」「始まり」"), vec!["「終わり」「始まり」"]);
+		assert_eq!(split("「はい。」と"), vec!["「はい。」と"]);
+		assert_eq!(split("ええ。」まだ"), vec!["ええ。」", "まだ"]);
+		assert_eq!(split("」」。ok"), vec!["」」。", "ok"]);
+	}
+}
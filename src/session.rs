@@ -0,0 +1,179 @@
+//! Incremental conversion of text that is edited over time.
+
+use super::chunks::*;
+use super::options::*;
+use super::util::*;
+
+/// Chunk of the current conversion in a [ConversionSession].
+#[derive(Copy, Clone, Debug)]
+struct SessionChunk {
+	/// End of the chunk in the input.
+	src_end: usize,
+	/// End of the chunk in the output.
+	out_end: usize,
+	/// State of the conversion after the chunk.
+	state: &'static str,
+}
+
+/// Conversion of a text that changes over time, such as the contents of a
+/// text input while the user is typing.
+///
+/// Each update only converts the input from the point where it changed and
+/// reports where the output changed.
+#[derive(Clone, Debug)]
+pub struct ConversionSession {
+	target: ConversionTarget,
+	input: String,
+	output: String,
+	chunks: Vec<SessionChunk>,
+}
+
+impl ConversionSession {
+	/// Creates a new session with an empty input.
+	pub fn new(target: ConversionTarget) -> ConversionSession {
+		ConversionSession {
+			target,
+			input: String::new(),
+			output: String::new(),
+			chunks: Vec::new(),
+		}
+	}
+
+	/// Current input of the session.
+	pub fn input(&self) -> &str {
+		&self.input
+	}
+
+	/// Output for the current input.
+	pub fn output(&self) -> &str {
+		&self.output
+	}
+
+	/// Updates the input of the session.
+	///
+	/// Returns the byte offset in the output from where it changed. The output
+	/// before this offset is the same as before the update.
+	pub fn update<S: AsRef<str>>(&mut self, input: S) -> usize {
+		let input = input.as_ref();
+
+		// Chunks ending before the changed position may still depend on the
+		// input that follows them, so we keep only the chunks that are far
+		// enough from the change.
+		let lookahead = max_lookahead();
+		let common = common_prefix_len(&self.input, input);
+		let keep = self
+			.chunks
+			.iter()
+			.take_while(|x| x.src_end + lookahead <= common)
+			.count();
+		self.chunks.truncate(keep);
+
+		let (pos, out_end, state) = match self.chunks.last() {
+			Some(last) => (last.src_end, last.out_end, last.state),
+			None => (0, 0, ""),
+		};
+		let old_output = self.output.split_off(out_end);
+
+		match self.target {
+			ConversionTarget::Hiragana => {
+				for chunk in HiraganaChunks::resume(input, pos) {
+					self.output.push_str(chunk.text);
+					self.chunks.push(SessionChunk {
+						src_end: chunk.src.end,
+						out_end: self.output.len(),
+						state: "",
+					});
+				}
+			}
+			ConversionTarget::Katakana => {
+				for chunk in KatakanaChunks::resume(input, pos) {
+					self.output.push_str(chunk.text);
+					self.chunks.push(SessionChunk {
+						src_end: chunk.src.end,
+						out_end: self.output.len(),
+						state: "",
+					});
+				}
+			}
+			ConversionTarget::Romaji => {
+				let mut chunks = RomajiChunks::resume(input, pos, state);
+				while let Some(chunk) = chunks.next() {
+					self.output.push_str(chunk.text);
+					self.chunks.push(SessionChunk {
+						src_end: chunk.src.end,
+						out_end: self.output.len(),
+						state: chunks.state(),
+					});
+				}
+			}
+		}
+
+		self.input.clear();
+		self.input.push_str(input);
+		out_end + common_prefix_len(&old_output, &self.output[out_end..])
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_conversion_session() {
+		fn check(target: ConversionTarget, inputs: &[&str]) {
+			let mut session = ConversionSession::new(target);
+			let mut output = String::new();
+			for input in inputs {
+				let changed = session.update(input);
+				let expected = match target {
+					ConversionTarget::Hiragana => super::super::to_hiragana(input),
+					ConversionTarget::Katakana => super::super::to_katakana(input),
+					ConversionTarget::Romaji => super::super::to_romaji(input),
+				};
+				assert_eq!(session.output(), expected, "input `{}`", input);
+				assert_eq!(session.input(), *input);
+				assert_eq!(&output[..changed], &expected[..changed]);
+				output = expected;
+			}
+		}
+
+		let typing = [
+			"k",
+			"ka",
+			"kan",
+			"kana",
+			"kana ",
+			"kana k",
+			"kana ka",
+			"kana kan",
+			"kana kanj",
+			"kana kanji",
+			"kana kanji",
+			"kana kanj",
+			"kana kan",
+			"",
+		];
+		check(ConversionTarget::Hiragana, &typing);
+		check(ConversionTarget::Katakana, &typing);
+
+		let kana = [
+			"か",
+			"かっ",
+			"かっぱ",
+			"かっぱゝ",
+			"かっぱゞ",
+			"ゝかっぱゞ",
+			"かっぱゞ",
+			"かっ",
+		];
+		check(ConversionTarget::Romaji, &kana);
+
+		let mut session = ConversionSession::new(ConversionTarget::Hiragana);
+		assert_eq!(session.update("shinbun wo yomu"), 0);
+		assert_eq!(session.output(), "しんぶん を よむ");
+		let changed = session.update("shinbun wo yonda");
+		assert_eq!(&session.output()[changed..], "んだ");
+	}
+}
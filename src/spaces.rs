@@ -0,0 +1,145 @@
+//! Trimming and normalization of whitespace in Japanese text.
+
+use super::info::{get_width, CharWidth};
+use super::is::is_space;
+
+/// Options for [normalize_spaces_with].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SpaceOptions {
+	/// Collapse each run of spaces to a U+3000 Ideographic Space, instead of
+	/// an ASCII space.
+	pub ideographic: bool,
+	/// Remove the spaces between two full-width characters (e.g. the space
+	/// in `東京 タワー`), instead of collapsing them.
+	pub remove_between_fullwidth: bool,
+}
+
+/// Returns true for the zero-width characters that are commonly found as
+/// stray whitespace in Japanese text.
+fn is_zero_width_space(chr: char) -> bool {
+	matches!(chr, '\u{200B}' | '\u{FEFF}')
+}
+
+/// Returns true for the whitespace that is collapsed by [normalize_spaces],
+/// which is any whitespace other than line breaks.
+fn is_blank(chr: char) -> bool {
+	let line_break = matches!(
+		chr,
+		'\n' | '\u{0B}' | '\u{0C}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}'
+	);
+	is_space(chr) && !line_break
+}
+
+/// Trims the leading and trailing whitespace from the input, including the
+/// U+3000 Ideographic Space.
+///
+/// Unlike `str::trim`, this also trims the zero-width space (U+200B) and the
+/// byte order mark (U+FEFF), which are common in text copied from web pages
+/// and files.
+///
+/// ```
+/// assert_eq!(kana::trim_japanese("\u{FEFF}　東京タワー　\n"), "東京タワー");
+/// ```
+pub fn trim_japanese(input: &str) -> &str {
+	input.trim_matches(|chr| is_space(chr) || is_zero_width_space(chr))
+}
+
+/// Trims the input as in [trim_japanese] and collapses each run of spaces,
+/// mixing any of the ASCII, ideographic and other whitespace, to a single
+/// ASCII space. Line breaks are kept, and the zero-width spaces inside the
+/// text are removed.
+///
+/// ```
+/// assert_eq!(kana::normalize_spaces(" 山田 　太郎　 "), "山田 太郎");
+/// ```
+pub fn normalize_spaces<S: AsRef<str>>(input: S) -> String {
+	normalize_spaces_with(input, &SpaceOptions::default())
+}
+
+/// Same as [normalize_spaces] but with the given options.
+///
+/// ```
+/// use kana::*;
+///
+/// let options = SpaceOptions {
+///     ideographic: true,
+///     ..SpaceOptions::default()
+/// };
+/// assert_eq!(normalize_spaces_with("山田  太郎", &options), "山田　太郎");
+///
+/// let options = SpaceOptions {
+///     remove_between_fullwidth: true,
+///     ..SpaceOptions::default()
+/// };
+/// assert_eq!(
+///     normalize_spaces_with("東京 タワー と Tokyo Tower", &options),
+///     "東京タワーと Tokyo Tower"
+/// );
+/// ```
+pub fn normalize_spaces_with<S: AsRef<str>>(input: S, options: &SpaceOptions) -> String {
+	let input = trim_japanese(input.as_ref());
+	let is_fullwidth = |chr: Option<char>| chr.map(get_width) == Some(CharWidth::Full);
+
+	let mut out = String::with_capacity(input.len());
+	let mut chars = input.chars().peekable();
+	let mut prev = None;
+	while let Some(chr) = chars.next() {
+		if is_zero_width_space(chr) {
+			continue;
+		}
+		if !is_blank(chr) {
+			out.push(chr);
+			prev = Some(chr);
+			continue;
+		}
+		while chars
+			.peek()
+			.map(|&chr| is_blank(chr) || is_zero_width_space(chr))
+			.unwrap_or(false)
+		{
+			chars.next();
+		}
+		let next = chars.peek().copied();
+		if options.remove_between_fullwidth && is_fullwidth(prev) && is_fullwidth(next) {
+			continue;
+		}
+		out.push(if options.ideographic { '\u{3000}' } else { ' ' });
+	}
+	out
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_normalize_spaces() {
+		assert_eq!(trim_japanese(""), "");
+		assert_eq!(trim_japanese(" \u{3000}\u{200B}\t"), "");
+		assert_eq!(trim_japanese("　あ　い　"), "あ　い");
+
+		assert_eq!(normalize_spaces(""), "");
+		assert_eq!(normalize_spaces("　\u{200B} "), "");
+		assert_eq!(normalize_spaces("a \t\u{A0}b"), "a b");
+		assert_eq!(normalize_spaces("あ\u{200B}い\u{FEFF}う"), "あいう");
+		assert_eq!(normalize_spaces("あ \u{200B} い"), "あ い");
+		assert_eq!(normalize_spaces("一行目　\n　二行目"), "一行目 \n 二行目");
+
+		let options = SpaceOptions {
+			ideographic: true,
+			remove_between_fullwidth: true,
+		};
+		assert_eq!(normalize_spaces_with("山田　太郎", &options), "山田太郎");
+		assert_eq!(
+			normalize_spaces_with("ＡＢＣ 　ＤＥＦ", &options),
+			"ＡＢＣＤＥＦ"
+		);
+		assert_eq!(
+			normalize_spaces_with("漢字 kanji  かな", &options),
+			"漢字　kanji　かな"
+		);
+		assert_eq!(normalize_spaces_with("ｶﾀ ｶﾅ", &options), "ｶﾀ　ｶﾅ");
+	}
+}
@@ -0,0 +1,273 @@
+//! Alignment between the input and output of a conversion.
+
+use std::ops::Range;
+
+use super::chunks::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Unit for the offsets in a [SpanMap].
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SpanUnit {
+	/// Offsets in bytes of the UTF-8 text. This is the default.
+	#[default]
+	Bytes,
+	/// Offsets in UTF-16 code units, as used by JavaScript and most editors.
+	Utf16,
+}
+
+/// A segment of the output of a conversion and the input it came from.
+///
+/// Ranges are given in the [SpanUnit] of the map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+	/// Range of the segment in the input.
+	pub src: Range<usize>,
+	/// Range of the segment in the output.
+	pub out: Range<usize>,
+}
+
+/// Alignment map between the input and output of a conversion, returned by
+/// functions such as [to_romaji_spans](fn.to_romaji_spans.html).
+///
+/// The map contains one [Span] for each converted segment, in order. The
+/// spans cover both the entire input and output without gaps.
+///
+/// Offsets are in bytes by default. Use [SpanMap::with_unit] to get a map
+/// using UTF-16 offsets instead.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpanMap {
+	spans: Vec<Span>,
+	unit: SpanUnit,
+}
+
+impl SpanMap {
+	/// Returns the spans in the map.
+	pub fn spans(&self) -> &[Span] {
+		&self.spans
+	}
+
+	/// Unit for the offsets in the map.
+	pub fn unit(&self) -> SpanUnit {
+		self.unit
+	}
+
+	/// Returns the same map with offsets in the given unit.
+	///
+	/// The `input` and `output` must be the ones from the conversion that
+	/// generated the map.
+	///
+	/// ```
+	/// use kana::*;
+	///
+	/// let input = "😀かな";
+	/// let (output, spans) = to_romaji_spans(input);
+	/// let spans = spans.with_unit(SpanUnit::Utf16, input, &output);
+	/// assert_eq!(spans.unit(), SpanUnit::Utf16);
+	/// assert_eq!(spans.spans()[1], Span { src: 2..3, out: 2..4 });
+	/// ```
+	pub fn with_unit(&self, unit: SpanUnit, input: &str, output: &str) -> SpanMap {
+		if unit == self.unit {
+			return self.clone();
+		}
+		let mut src = OffsetCursor::new(input);
+		let mut out = OffsetCursor::new(output);
+		let mut last = Span {
+			src: 0..0,
+			out: 0..0,
+		};
+		let spans = self
+			.spans
+			.iter()
+			.map(|span| {
+				let src_end = src.convert(span.src.end, self.unit, unit);
+				let out_end = out.convert(span.out.end, self.unit, unit);
+				last = Span {
+					src: last.src.end..src_end,
+					out: last.out.end..out_end,
+				};
+				last.clone()
+			})
+			.collect();
+		SpanMap { spans, unit }
+	}
+
+	/// Maps an offset in the input to the output.
+	///
+	/// Offsets inside a segment map to the end of the segment output, since
+	/// segments cannot be split.
+	pub fn to_output(&self, offset: usize) -> usize {
+		match self.spans.iter().find(|x| x.src.end > offset) {
+			Some(span) if offset <= span.src.start => span.out.start,
+			Some(span) => span.out.end,
+			None => self.spans.last().map(|x| x.out.end).unwrap_or(0),
+		}
+	}
+
+	/// Maps an offset in the output to the input.
+	///
+	/// Offsets inside a segment map to the end of the segment input, since
+	/// segments cannot be split.
+	pub fn to_source(&self, offset: usize) -> usize {
+		match self.spans.iter().find(|x| x.out.end > offset) {
+			Some(span) if offset <= span.out.start => span.src.start,
+			Some(span) => span.src.end,
+			None => self.spans.last().map(|x| x.src.end).unwrap_or(0),
+		}
+	}
+
+	/// Maps a range of the input to the range of the output generated
+	/// from it (e.g. to highlight the converted text).
+	///
+	/// The range is extended to include any segment it partially covers.
+	pub fn output_range(&self, range: Range<usize>) -> Range<usize> {
+		map_range(&self.spans, range, |x| &x.src, |x| &x.out)
+	}
+
+	/// Maps a range of the output to the range of the input it was
+	/// generated from.
+	///
+	/// The range is extended to include any segment it partially covers.
+	pub fn source_range(&self, range: Range<usize>) -> Range<usize> {
+		map_range(&self.spans, range, |x| &x.out, |x| &x.src)
+	}
+
+	/// Adds a converted chunk with the given output length to the map.
+	pub(crate) fn add(&mut self, chunk: &Chunk, out_len: usize) {
+		let start = self.spans.last().map(|x| x.out.end).unwrap_or(0);
+		self.spans.push(Span {
+			src: chunk.src.clone(),
+			out: start..start + out_len,
+		});
+	}
+}
+
+/// Converts increasing offsets in a text between units.
+struct OffsetCursor<'a> {
+	text: &'a str,
+	bytes: usize,
+	utf16: usize,
+}
+
+impl<'a> OffsetCursor<'a> {
+	fn new(text: &'a str) -> OffsetCursor<'a> {
+		OffsetCursor {
+			text,
+			bytes: 0,
+			utf16: 0,
+		}
+	}
+
+	fn offset(&self, unit: SpanUnit) -> usize {
+		match unit {
+			SpanUnit::Bytes => self.bytes,
+			SpanUnit::Utf16 => self.utf16,
+		}
+	}
+
+	/// Converts the offset, which must not be less than the previous one.
+	fn convert(&mut self, offset: usize, from: SpanUnit, to: SpanUnit) -> usize {
+		while self.offset(from) < offset {
+			match self.text[self.bytes..].chars().next() {
+				Some(chr) => {
+					self.bytes += chr.len_utf8();
+					self.utf16 += chr.len_utf16();
+				}
+				None => break,
+			}
+		}
+		self.offset(to)
+	}
+}
+
+fn map_range<F, T>(spans: &[Span], range: Range<usize>, from: F, to: T) -> Range<usize>
+where
+	F: Fn(&Span) -> &Range<usize>,
+	T: Fn(&Span) -> &Range<usize>,
+{
+	let mut overlap = spans
+		.iter()
+		.filter(|x| from(x).start < range.end && range.start < from(x).end);
+	match overlap.next() {
+		Some(first) => {
+			let last = overlap.last().unwrap_or(first);
+			to(first).start..to(last).end
+		}
+		None => {
+			let pos = match spans.iter().find(|x| from(x).start >= range.start) {
+				Some(span) => to(span).start,
+				None => spans.last().map(|x| to(x).end).unwrap_or(0),
+			};
+			pos..pos
+		}
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn span(src: Range<usize>, out: Range<usize>) -> Span {
+		Span { src, out }
+	}
+
+	#[test]
+	fn test_span_map() {
+		// "きょうはa" => "kyouhaa"
+		let map = SpanMap {
+			unit: SpanUnit::Bytes,
+			spans: vec![
+				span(0..6, 0..3),
+				span(6..9, 3..4),
+				span(9..12, 4..6),
+				span(12..13, 6..7),
+			],
+		};
+
+		assert_eq!(map.to_output(0), 0);
+		assert_eq!(map.to_output(3), 3);
+		assert_eq!(map.to_output(6), 3);
+		assert_eq!(map.to_output(12), 6);
+		assert_eq!(map.to_output(13), 7);
+		assert_eq!(map.to_output(100), 7);
+
+		assert_eq!(map.to_source(0), 0);
+		assert_eq!(map.to_source(1), 6);
+		assert_eq!(map.to_source(3), 6);
+		assert_eq!(map.to_source(5), 12);
+		assert_eq!(map.to_source(7), 13);
+
+		assert_eq!(map.output_range(0..6), 0..3);
+		assert_eq!(map.output_range(3..9), 0..4);
+		assert_eq!(map.output_range(9..13), 4..7);
+		assert_eq!(map.output_range(6..6), 3..3);
+		assert_eq!(map.output_range(20..30), 7..7);
+		assert_eq!(map.source_range(1..2), 0..6);
+		assert_eq!(map.source_range(3..5), 6..12);
+
+		let input = "きょうはa";
+		let output = "kyouhaa";
+		let utf16 = map.with_unit(SpanUnit::Utf16, input, output);
+		assert_eq!(utf16.unit(), SpanUnit::Utf16);
+		assert_eq!(
+			utf16.spans(),
+			&[
+				span(0..2, 0..3),
+				span(2..3, 3..4),
+				span(3..4, 4..6),
+				span(4..5, 6..7)
+			][..]
+		);
+		assert_eq!(utf16.to_output(2), 3);
+		assert_eq!(utf16.with_unit(SpanUnit::Bytes, input, output), map);
+		assert_eq!(utf16.with_unit(SpanUnit::Utf16, input, output), utf16);
+
+		let empty = SpanMap::default();
+		assert_eq!(empty.to_output(5), 0);
+		assert_eq!(empty.output_range(0..5), 0..0);
+	}
+}
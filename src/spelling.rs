@@ -0,0 +1,247 @@
+//! Detection of common kana misspellings, such as `つずく` for `つづく`.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use super::is::{is_kana, is_kanji, is_katakana};
+use super::mora::is_mora_glide;
+
+/// Spelling rule broken by a [Misspelling].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SpellingRule {
+	/// A repeated sound is written with `づ` or `ぢ` (e.g. `つづく` and
+	/// `ちぢむ`, not `つずく` and `ちじむ`).
+	RepeatedSound,
+	/// A word does not start with `づ` or `ぢ`, nor a sentence with `を`,
+	/// which are written as `ず`, `じ` and `お` (e.g. `ずっと`, not `づっと`).
+	WordInitial,
+	/// The particle `を` is not used inside a word, where it is written as
+	/// `お` (e.g. `おおきい`, not `おをきい`).
+	WoInWord,
+}
+
+/// A likely misspelling returned by [find_kana_misspellings].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misspelling {
+	/// Byte range of the misspelled character in the input.
+	pub src: Range<usize>,
+	/// The character found in the input.
+	pub found: char,
+	/// The suggested correction, in the same script as the input.
+	pub suggestion: char,
+	/// The rule broken by the character.
+	pub rule: SpellingRule,
+}
+
+/// Returns the hiragana for a full-width katakana, or the character itself.
+fn hiragana(chr: char) -> char {
+	match chr {
+		'ァ'..='ヶ' => std::char::from_u32(chr as u32 - 0x60).unwrap(),
+		_ => chr,
+	}
+}
+
+/// Returns the given hiragana in the same script as `like`.
+fn same_script(chr: char, like: char) -> char {
+	if is_katakana(like) {
+		std::char::from_u32(chr as u32 + 0x60).unwrap()
+	} else {
+		chr
+	}
+}
+
+/// Returns true if the character may be part of a Japanese word, so that
+/// the character following it does not start a word.
+fn is_word_char(chr: char) -> bool {
+	is_kana(chr) || is_kanji(chr) || matches!(chr, '々' | 'ゝ' | 'ゞ' | 'ヽ' | 'ヾ')
+}
+
+/// Returns true for the punctuation that ends a sentence, after which the
+/// particle `を` cannot appear.
+fn is_sentence_end(chr: char) -> bool {
+	matches!(
+		chr,
+		'。' | '．' | '｡' | '.' | '！' | '？' | '!' | '?' | '…' | '\n' | '\r'
+	)
+}
+
+/// Returns true if no word starts with the character, so that a `を` before
+/// it must be part of a word.
+fn is_word_continuation(chr: char) -> bool {
+	is_mora_glide(chr) || matches!(hiragana(chr), 'っ' | 'ん' | 'ー')
+}
+
+/// Returns true if the text starts with the given hiragana, in either
+/// hiragana or katakana.
+fn starts_with_kana(text: &[char], kana: &str) -> bool {
+	let mut chars = text.iter().map(|&chr| hiragana(chr));
+	kana.chars().all(|expected| chars.next() == Some(expected))
+}
+
+/// Returns the likely misspellings of `づ`, `ぢ` and `を` in the input, with
+/// the suggested correction, following the modern kana orthography.
+///
+/// Since the input is not split into words, this only reports the cases
+/// that can be told apart from the neighboring characters:
+///
+/// - `ず` after `つ` and `じ` after `ち` in the words with a repeated sound
+///   (e.g. `つずく` and `ちじむ`). The `ずつ` suffix (e.g. `ひとつずつ`)
+///   is not reported.
+/// - `づ` and `ぢ` at the start of a word, after any character that is not
+///   kana or kanji (e.g. `づっと`).
+/// - `を` followed by kana at the start of the text or of a sentence (e.g.
+///   `をかし`). Since `を` is a particle, it is never reported after other
+///   characters (e.g. `「本」を読む`).
+/// - `を` after `っ` or before a character that cannot start a word, such
+///   as `っ`, `ん` or a small kana (e.g. `ねこをっ`).
+///
+/// Both hiragana and full-width katakana are checked.
+///
+/// ```
+/// use kana::*;
+///
+/// let found = find_kana_misspellings("はなしをつずける");
+/// assert_eq!(found.len(), 1);
+/// assert_eq!(found[0].src, 15..18);
+/// assert_eq!((found[0].found, found[0].suggestion), ('ず', 'づ'));
+/// assert_eq!(found[0].rule, SpellingRule::RepeatedSound);
+/// ```
+pub fn find_kana_misspellings(input: &str) -> Vec<Misspelling> {
+	let chars = input.chars().collect::<Vec<_>>();
+	let mut found = Vec::new();
+	let mut offset = 0;
+	for (index, &chr) in chars.iter().enumerate() {
+		let src = offset..offset + chr.len_utf8();
+		offset = src.end;
+
+		let prev = index.checked_sub(1).map(|index| chars[index]);
+		let next = chars.get(index + 1).copied();
+		let word_start = !prev.map(is_word_char).unwrap_or(false);
+		let sentence_start = prev.map(is_sentence_end).unwrap_or(true);
+		let after = &chars[index + 1..];
+
+		let fix = match (hiragana(chr), prev.map(hiragana)) {
+			('ず', Some('つ')) if !starts_with_kana(after, "つ") => {
+				Some(('づ', SpellingRule::RepeatedSound))
+			}
+			('じ', Some('ち'))
+				if ["む", "ま", "め", "れ", "み", "こま"]
+					.iter()
+					.any(|kana| starts_with_kana(after, kana)) =>
+			{
+				Some(('ぢ', SpellingRule::RepeatedSound))
+			}
+			('づ', _) if word_start => Some(('ず', SpellingRule::WordInitial)),
+			('ぢ', _) if word_start => Some(('じ', SpellingRule::WordInitial)),
+			('を', _) if sentence_start && next.map(is_kana).unwrap_or(false) => {
+				Some(('お', SpellingRule::WordInitial))
+			}
+			('を', prev) => {
+				if prev == Some('っ') || next.map(is_word_continuation).unwrap_or(false) {
+					Some(('お', SpellingRule::WoInWord))
+				} else {
+					None
+				}
+			}
+			_ => None,
+		};
+
+		if let Some((suggestion, rule)) = fix {
+			found.push(Misspelling {
+				src,
+				found: chr,
+				suggestion: same_script(suggestion, chr),
+				rule,
+			});
+		}
+	}
+	found
+}
+
+/// Replaces the misspellings returned by [find_kana_misspellings] by the
+/// suggested correction. Returns the input without allocating if there are
+/// no misspellings.
+///
+/// ```
+/// assert_eq!(kana::fix_kana_misspellings("ツズク"), "ツヅク");
+/// assert_eq!(kana::fix_kana_misspellings("「づっと」"), "「ずっと」");
+/// ```
+pub fn fix_kana_misspellings<'a>(input: &'a str) -> Cow<'a, str> {
+	let found = find_kana_misspellings(input);
+	if found.is_empty() {
+		return Cow::Borrowed(input);
+	}
+	let mut out = String::with_capacity(input.len());
+	let mut last = 0;
+	for misspelling in found {
+		out.push_str(&input[last..misspelling.src.start]);
+		out.push(misspelling.suggestion);
+		last = misspelling.src.end;
+	}
+	out.push_str(&input[last..]);
+	Cow::Owned(out)
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fix(input: &str) -> String {
+		fix_kana_misspellings(input).into_owned()
+	}
+
+	#[test]
+	fn test_find_kana_misspellings() {
+		assert!(find_kana_misspellings("").is_empty());
+		assert_eq!(
+			find_kana_misspellings("ちじむ"),
+			vec![Misspelling {
+				src: 3..6,
+				found: 'じ',
+				suggestion: 'ぢ',
+				rule: SpellingRule::RepeatedSound,
+			}]
+		);
+
+		// Already correct
+		for text in &[
+			"つづく",
+			"ちぢれる",
+			"ひとつずつ",
+			"みっつずつ",
+			"ちじのしごと",
+			"いちじ",
+			"はなぢ",
+			"みかづき",
+			"ずっと",
+			"ほんをよむ",
+			"ごはんを、たべる",
+			"ラーメンを",
+			"ホンヲヨム",
+			"「本」を読む",
+			"Rustを学ぶ",
+			"3を足す",
+			"ほん を よむ",
+			"を",
+			"。を読む",
+		] {
+			assert!(
+				matches!(fix_kana_misspellings(text), Cow::Borrowed(_)),
+				"{}",
+				text
+			);
+		}
+
+		assert_eq!(fix("つずき つずみ"), "つづき つづみ");
+		assert_eq!(fix("ちじこまる"), "ちぢこまる");
+		assert_eq!(fix("チジミ"), "チヂミ");
+		assert_eq!(fix("ぢめん"), "じめん");
+		assert_eq!(fix("をかし"), "おかし");
+		assert_eq!(fix("よむ。をかし"), "よむ。おかし");
+		assert_eq!(fix("かをっ"), "かおっ");
+		assert_eq!(fix("をを"), "おを");
+		assert_eq!(fix("ネコヲッ"), "ネコオッ");
+	}
+}
@@ -0,0 +1,196 @@
+//! Detection of identifiers that mix scripts suspiciously, such as a display
+//! name mixing kanji and Cyrillic letters.
+
+use super::kind::*;
+
+/// Script of a letter for [check_mixed_scripts].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+	/// ASCII letters and the accented Latin letters (e.g. `ō` and `é`).
+	Latin,
+	/// Full-width Latin letters (e.g. `Ａ`).
+	FullWidthLatin,
+	/// Kana, kanji and the Japanese marks (e.g. `ー` and `々`).
+	Japanese,
+	/// Cyrillic letters (e.g. `а`, which looks like the Latin `a`).
+	Cyrillic,
+	/// Greek letters (e.g. `ο`, which looks like the Latin `o`).
+	Greek,
+	/// Korean Hangul.
+	Hangul,
+	/// Letters of any other script.
+	Other,
+}
+
+/// Returns the script of a letter, or `None` for the characters common to
+/// all scripts, such as digits, punctuation and spaces.
+///
+/// ```
+/// use kana::*;
+///
+/// assert_eq!(get_script('a'), Some(Script::Latin));
+/// assert_eq!(get_script('а'), Some(Script::Cyrillic));
+/// assert_eq!(get_script('カ'), Some(Script::Japanese));
+/// assert_eq!(get_script('1'), None);
+/// ```
+pub fn get_script(chr: char) -> Option<Script> {
+	let script = match get_kind(chr) {
+		CharKind::Hiragana
+		| CharKind::Katakana
+		| CharKind::KatakanaHalfWidth
+		| CharKind::Kanji
+		| CharKind::BarLine
+		| CharKind::JapaneseMark => Script::Japanese,
+		CharKind::RomanLetter => Script::FullWidthLatin,
+		CharKind::Hangul => Script::Hangul,
+		_ => match chr {
+			'\u{0370}'..='\u{03FF}' => Script::Greek,
+			'\u{0400}'..='\u{052F}' => Script::Cyrillic,
+			'A'..='Z' | 'a'..='z' | '\u{00C0}'..='\u{024F}' if chr.is_alphabetic() => Script::Latin,
+			_ if chr.is_alphabetic() => Script::Other,
+			_ => return None,
+		},
+	};
+	Some(script)
+}
+
+/// Suspicious mix of scripts returned by [check_mixed_scripts].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptMixIssue {
+	/// Letters from two scripts that are not normally used together, in order
+	/// of first appearance (e.g. kanji and Cyrillic).
+	MixedScripts(Script, Script),
+	/// ASCII and full-width Latin letters (e.g. `Ａdmin`).
+	MixedLatinWidth,
+	/// Full-width and halfwidth katakana (e.g. `ｶタカナ`).
+	MixedKatakanaWidth,
+}
+
+/// Returns true if both scripts are commonly used together, which is the
+/// case for Japanese and Latin letters (e.g. `Tシャツ`).
+fn is_compatible(a: Script, b: Script) -> bool {
+	matches!(
+		(a, b),
+		(Script::Japanese, Script::Latin)
+			| (Script::Latin, Script::Japanese)
+			| (Script::Japanese, Script::FullWidthLatin)
+			| (Script::FullWidthLatin, Script::Japanese)
+	)
+}
+
+/// Checks an identifier, such as a user name, for suspicious mixes of
+/// scripts that may be used for spoofing, returning the issues found.
+///
+/// Japanese text mixed with Latin letters (either ASCII or full-width) is
+/// accepted, as are digits, punctuation and spaces, which are common to all
+/// scripts. Any other mix of scripts is reported, as are ASCII letters
+/// mixed with full-width letters and halfwidth katakana mixed with
+/// full-width katakana.
+///
+/// ```
+/// use kana::*;
+///
+/// assert!(check_mixed_scripts("山田Taro_01").is_empty());
+/// assert_eq!(
+///     check_mixed_scripts("山田Тaro"),
+///     vec![ScriptMixIssue::MixedScripts(Script::Japanese, Script::Cyrillic),
+///          ScriptMixIssue::MixedScripts(Script::Cyrillic, Script::Latin)]
+/// );
+/// assert_eq!(check_mixed_scripts("Ａdmin"), vec![ScriptMixIssue::MixedLatinWidth]);
+/// ```
+pub fn check_mixed_scripts<S: AsRef<str>>(input: S) -> Vec<ScriptMixIssue> {
+	let mut scripts = Vec::new();
+	let mut katakana = false;
+	let mut katakana_half = false;
+	for chr in input.as_ref().chars() {
+		match get_kind(chr) {
+			CharKind::Katakana => katakana = true,
+			CharKind::KatakanaHalfWidth => katakana_half = true,
+			_ => {}
+		}
+		if let Some(script) = get_script(chr) {
+			if !scripts.contains(&script) {
+				scripts.push(script);
+			}
+		}
+	}
+
+	let mut issues = Vec::new();
+	for (index, &a) in scripts.iter().enumerate() {
+		for &b in scripts[index + 1..].iter() {
+			if matches!(
+				(a, b),
+				(Script::Latin, Script::FullWidthLatin) | (Script::FullWidthLatin, Script::Latin)
+			) {
+				issues.push(ScriptMixIssue::MixedLatinWidth);
+			} else if !is_compatible(a, b) {
+				issues.push(ScriptMixIssue::MixedScripts(a, b));
+			}
+		}
+	}
+	if katakana && katakana_half {
+		issues.push(ScriptMixIssue::MixedKatakanaWidth);
+	}
+	issues
+}
+
+/// Returns true if [check_mixed_scripts] finds any issue in the identifier.
+///
+/// ```
+/// assert!(!kana::is_mixed_script_spoof("ラーメン王"));
+/// assert!(kana::is_mixed_script_spoof("pаypal"));
+/// ```
+pub fn is_mixed_script_spoof<S: AsRef<str>>(input: S) -> bool {
+	!check_mixed_scripts(input).is_empty()
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_check_mixed_scripts() {
+		use Script::*;
+		use ScriptMixIssue::*;
+
+		for ok in &[
+			"",
+			"123-_ ",
+			"admin",
+			"Ōsaka",
+			"ＡＤＭＩＮ",
+			"山田太郎",
+			"Tシャツ",
+			"ｶﾀｶﾅ",
+			"ＡＢＣ株式会社",
+			"Ελλάδα",
+			"한국어",
+		] {
+			assert!(check_mixed_scripts(ok).is_empty(), "{}", ok);
+		}
+
+		assert_eq!(
+			check_mixed_scripts("раураl"),
+			vec![MixedScripts(Cyrillic, Latin)]
+		);
+		assert_eq!(
+			check_mixed_scripts("東京ο"),
+			vec![MixedScripts(Japanese, Greek)]
+		);
+		assert_eq!(
+			check_mixed_scripts("한글かな"),
+			vec![MixedScripts(Hangul, Japanese)]
+		);
+		assert_eq!(check_mixed_scripts("ｶタカナ"), vec![MixedKatakanaWidth]);
+		assert_eq!(
+			check_mixed_scripts("ａdminｶナ"),
+			vec![MixedLatinWidth, MixedKatakanaWidth]
+		);
+		assert_eq!(
+			check_mixed_scripts("漢字ا"),
+			vec![MixedScripts(Japanese, Other)]
+		);
+	}
+}
@@ -0,0 +1,306 @@
+//! Statistics about the characters of a text, computed in a single pass.
+
+use std::io::{self, Read};
+
+use super::is::is_kana;
+use super::kind::*;
+use super::mora::{is_mora_glide, is_voiced_mark};
+use super::stream::{invalid_utf8, Utf8Decoder, READ_BUFFER_SIZE};
+
+/// Statistics about a text, returned by [analyze].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TextStats {
+	/// Number of characters of each kind.
+	pub kinds: KindHistogram,
+	/// Number of kana, including the halfwidth katakana and the prolonged
+	/// sound mark.
+	pub kana: usize,
+	/// Number of ASCII letters, not including digits.
+	pub romaji: usize,
+	/// Number of morae, as counted by [count_morae](fn.count_morae.html).
+	pub morae: usize,
+	/// Length in characters of the longest run of kanji, including the `々`
+	/// iteration mark (e.g. 4 for `時々刻々`).
+	pub longest_kanji_run: usize,
+}
+
+impl TextStats {
+	/// Returns the total number of characters.
+	pub fn chars(&self) -> usize {
+		self.kinds.total()
+	}
+
+	/// Returns the fraction of the characters that are kanji, from `0.0` to
+	/// `1.0`, or `0.0` for an empty string.
+	pub fn kanji_density(&self) -> f64 {
+		self.kinds.ratio(CharKind::Kanji)
+	}
+
+	/// Returns the fraction of kana among the kana and romaji letters, from
+	/// `0.0` to `1.0`, or `0.0` if there are neither.
+	pub fn kana_romaji_ratio(&self) -> f64 {
+		match self.kana + self.romaji {
+			0 => 0.0,
+			total => self.kana as f64 / total as f64,
+		}
+	}
+}
+
+/// Returns the statistics for the text, computed in a single pass.
+///
+/// See [StatsAccumulator] to compute the statistics for a text that is
+/// not available at once.
+///
+/// ```
+/// use kana::*;
+///
+/// let stats = analyze("東京都でラーメンを食べた");
+/// assert_eq!(stats.chars(), 12);
+/// assert_eq!(stats.kinds.count(CharKind::Kanji), 4);
+/// assert_eq!(stats.kana, 8);
+/// assert_eq!(stats.morae, 8);
+/// assert_eq!(stats.longest_kanji_run, 3);
+/// ```
+pub fn analyze<S: AsRef<str>>(text: S) -> TextStats {
+	let mut counter = StatsCounter::default();
+	counter.push_str(text.as_ref());
+	counter.stats()
+}
+
+/// Returns the statistics for the UTF-8 text read from the reader, without
+/// holding the entire text in memory.
+///
+/// Returns an error of kind [io::ErrorKind::InvalidData] if the input is not
+/// valid UTF-8.
+///
+/// ```
+/// let stats = kana::analyze_reader("ラーメン".as_bytes()).unwrap();
+/// assert_eq!(stats.morae, 4);
+/// ```
+pub fn analyze_reader<R: Read>(reader: R) -> io::Result<TextStats> {
+	let mut stats = StatsAccumulator::new();
+	stats.read_from(reader)?;
+	stats.finish()
+}
+
+/// Accumulates the [TextStats] for a text given in chunks, such as the
+/// lines of a file or the buffers read from a socket, without holding the
+/// entire text in memory.
+///
+/// Byte chunks may split the UTF-8 sequences at any point.
+///
+/// ```
+/// let text = "東京でラーメン";
+/// let mut stats = kana::StatsAccumulator::new();
+/// for chunk in text.as_bytes().chunks(2) {
+///     stats.push_bytes(chunk).unwrap();
+/// }
+/// assert_eq!(stats.finish().unwrap(), kana::analyze(text));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StatsAccumulator {
+	counter: StatsCounter,
+	decoder: Utf8Decoder,
+	invalid: bool,
+}
+
+impl StatsAccumulator {
+	/// Creates an empty accumulator.
+	pub fn new() -> StatsAccumulator {
+		StatsAccumulator::default()
+	}
+
+	/// Adds a chunk of text.
+	pub fn push_str(&mut self, text: &str) {
+		// A pending incomplete sequence makes this fail, which is reported
+		// by finish.
+		let _ = self.push_bytes(text.as_bytes());
+	}
+
+	/// Adds a chunk of UTF-8 bytes. A sequence that is incomplete at the end
+	/// of the chunk is completed by the next chunk.
+	///
+	/// Returns an error of kind [io::ErrorKind::InvalidData] if the input is
+	/// not valid UTF-8. The invalid chunk is skipped.
+	pub fn push_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+		let counter = &mut self.counter;
+		let result = self.decoder.decode(bytes, |text| counter.push_str(text));
+		self.invalid = self.invalid || result.is_err();
+		result
+	}
+
+	/// Adds all the UTF-8 text from the reader.
+	pub fn read_from<R: Read>(&mut self, mut reader: R) -> io::Result<()> {
+		let mut buffer = vec![0; READ_BUFFER_SIZE];
+		loop {
+			match reader.read(&mut buffer) {
+				Ok(0) => return Ok(()),
+				Ok(len) => self.push_bytes(&buffer[..len])?,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+				Err(err) => return Err(err),
+			}
+		}
+	}
+
+	/// Returns the statistics for all the text added.
+	///
+	/// Returns an error of kind [io::ErrorKind::InvalidData] if any of the
+	/// input was not valid UTF-8, including an incomplete sequence at the end.
+	pub fn finish(mut self) -> io::Result<TextStats> {
+		self.decoder.finish()?;
+		if self.invalid {
+			return Err(invalid_utf8());
+		}
+		Ok(self.counter.stats())
+	}
+}
+
+/// What the current mora can still take, following the same rules as
+/// [morae](fn.morae.html).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum MoraState {
+	/// The next kana starts a new mora.
+	#[default]
+	Closed,
+	/// The mora has a base kana, and can take a voiced mark or a glide.
+	Base,
+	/// The mora has a base kana and a voiced mark, and can take a glide.
+	Voiced,
+}
+
+/// Counts the [TextStats] one character at a time.
+#[derive(Clone, Debug, Default)]
+struct StatsCounter {
+	counts: [usize; ALL_KINDS.len()],
+	kana: usize,
+	romaji: usize,
+	morae: usize,
+	mora: MoraState,
+	kanji_run: usize,
+	longest_kanji_run: usize,
+}
+
+impl StatsCounter {
+	fn push_str(&mut self, text: &str) {
+		for chr in text.chars() {
+			self.push(chr);
+		}
+	}
+
+	fn push(&mut self, chr: char) {
+		let kind = get_kind(chr);
+		self.counts[kind as usize] += 1;
+
+		if is_kana(chr) {
+			self.kana += 1;
+		} else if chr.is_ascii_alphabetic() {
+			self.romaji += 1;
+		}
+
+		if kind == CharKind::Kanji || matches!(chr, '々' | '〻') {
+			self.kanji_run += 1;
+			self.longest_kanji_run = self.longest_kanji_run.max(self.kanji_run);
+		} else {
+			self.kanji_run = 0;
+		}
+
+		self.mora = match self.mora {
+			MoraState::Base if is_voiced_mark(chr) => MoraState::Voiced,
+			MoraState::Base | MoraState::Voiced if is_mora_glide(chr) => MoraState::Closed,
+			_ => {
+				if is_kana(chr) || matches!(chr, 'ゝ' | 'ゞ' | 'ヽ' | 'ヾ') {
+					self.morae += 1;
+				}
+				if is_kana(chr) && !is_mora_glide(chr) {
+					MoraState::Base
+				} else {
+					MoraState::Closed
+				}
+			}
+		};
+	}
+
+	fn stats(&self) -> TextStats {
+		TextStats {
+			kinds: KindHistogram::from_counts(self.counts),
+			kana: self.kana,
+			romaji: self.romaji,
+			morae: self.morae,
+			longest_kanji_run: self.longest_kanji_run,
+		}
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::count::{count_by_kind, count_kana};
+	use crate::mora::count_morae;
+
+	#[test]
+	fn test_analyze() {
+		assert_eq!(analyze(""), TextStats::default());
+		assert_eq!(analyze("").kanji_density(), 0.0);
+		assert_eq!(analyze("").kana_romaji_ratio(), 0.0);
+
+		let stats = analyze("時々刻々と、Tokyo 123 駅");
+		assert_eq!(stats.longest_kanji_run, 4);
+		assert_eq!(stats.romaji, 5);
+		assert_eq!(stats.kana, 1);
+		assert_eq!(stats.kana_romaji_ratio(), 1.0 / 6.0);
+		assert_eq!(stats.kanji_density(), 3.0 / 17.0);
+
+		// Must agree with the multi-pass functions
+		for text in &[
+			"きょうと",
+			"がっこう、コーヒーください",
+			"ｷｮｳﾄ ｶﾞｯｺｳ ｷﾞｮｳ",
+			"か\u{3099}ゃ\u{309A}ゅょぁ",
+			"ゃゃきゃゃ、ゝゞヽヾ",
+			"漢字𠀀とｶﾀｶﾅ、ラーメン abc",
+		] {
+			let stats = analyze(text);
+			assert_eq!(stats.kinds, count_by_kind(text), "{}", text);
+			assert_eq!(stats.kana, count_kana(text), "{}", text);
+			assert_eq!(stats.morae, count_morae(text), "{}", text);
+		}
+	}
+	#[test]
+	fn test_stats_accumulator() {
+		let text = "ｷﾞｮｳ 時々刻々と、きょうはラーメン😀 abc";
+		let expected = analyze(text);
+		for size in 1..=5 {
+			let mut stats = StatsAccumulator::new();
+			for chunk in text.as_bytes().chunks(size) {
+				stats.push_bytes(chunk).unwrap();
+			}
+			assert_eq!(stats.finish().unwrap(), expected, "{}", size);
+		}
+		assert_eq!(analyze_reader(text.as_bytes()).unwrap(), expected);
+
+		let mut stats = StatsAccumulator::new();
+		stats.push_str("きょ");
+		stats.push_str("う");
+		assert_eq!(stats.finish().unwrap(), analyze("きょう"));
+
+		// Invalid and incomplete sequences
+		let mut stats = StatsAccumulator::new();
+		assert!(stats.push_bytes(b"a\xFF").is_err());
+		assert!(stats.push_bytes(b"b").is_ok());
+		assert!(stats.finish().is_err());
+
+		let mut stats = StatsAccumulator::new();
+		stats.push_bytes(&"あ".as_bytes()[..2]).unwrap();
+		assert!(stats.finish().is_err());
+
+		let mut stats = StatsAccumulator::new();
+		stats.push_bytes(&"あ".as_bytes()[..1]).unwrap();
+		stats.push_str("い");
+		assert!(stats.finish().is_err());
+
+		let err = analyze_reader(&b"\xE3\x81"[..]).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+}
@@ -0,0 +1,312 @@
+//! Streaming conversion for [std::io] readers and writers.
+
+use std::io;
+
+use super::converter::*;
+use super::options::*;
+
+/// Size of the buffer used to read from the inner reader.
+pub(crate) const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Decodes UTF-8 input that may be split at any byte.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Utf8Decoder {
+	/// Incomplete UTF-8 sequence at the end of the last input.
+	partial: Vec<u8>,
+}
+
+impl Utf8Decoder {
+	/// Decodes the input, calling `f` with the decoded text. An incomplete
+	/// sequence at the end of the input is kept for the next call.
+	pub(crate) fn decode<F: FnOnce(&str)>(&mut self, input: &[u8], f: F) -> io::Result<()> {
+		let mut buffer;
+		let input = if self.partial.is_empty() {
+			input
+		} else {
+			buffer = std::mem::take(&mut self.partial);
+			buffer.extend_from_slice(input);
+			&buffer[..]
+		};
+
+		let valid = match std::str::from_utf8(input) {
+			Ok(_) => input.len(),
+			Err(err) => {
+				if err.error_len().is_some() {
+					return Err(invalid_utf8());
+				}
+				err.valid_up_to()
+			}
+		};
+
+		let (text, partial) = input.split_at(valid);
+		f(unsafe { std::str::from_utf8_unchecked(text) });
+		self.partial.extend_from_slice(partial);
+		Ok(())
+	}
+
+	/// Checks that there is no incomplete sequence at the end of the input.
+	pub(crate) fn finish(&mut self) -> io::Result<()> {
+		if self.partial.is_empty() {
+			Ok(())
+		} else {
+			self.partial.clear();
+			Err(invalid_utf8())
+		}
+	}
+}
+
+pub(crate) fn invalid_utf8() -> io::Error {
+	io::Error::new(
+		io::ErrorKind::InvalidData,
+		"stream did not contain valid UTF-8",
+	)
+}
+
+/// Writer that converts the UTF-8 text written to it before writing it to the
+/// inner writer.
+///
+/// The input can be split at any point, including in the middle of a UTF-8
+/// sequence or a conversion chunk (e.g. `sh` and `a`). Because of that, the
+/// end of the input is kept pending until more input follows it.
+///
+/// Call [ConversionWriter::finish] once all the input is written to convert
+/// the pending input. Flushing the writer only flushes the output that is
+/// ready.
+///
+/// ```
+/// use std::io::Write;
+/// use kana::*;
+///
+/// let mut writer = ConversionWriter::new(Vec::new(), ConversionTarget::Romaji);
+/// writer.write_all("ひらがなとカタカナ".as_bytes()).unwrap();
+/// let output = writer.finish().unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "hiraganatokatakana");
+/// ```
+pub struct ConversionWriter<W: io::Write> {
+	inner: W,
+	decoder: Utf8Decoder,
+	converter: Converter,
+	output: String,
+}
+
+impl<W: io::Write> ConversionWriter<W> {
+	/// Creates a new writer converting to the given target.
+	pub fn new(inner: W, target: ConversionTarget) -> ConversionWriter<W> {
+		ConversionWriter {
+			inner,
+			decoder: Utf8Decoder::default(),
+			converter: Converter::new(target),
+			output: String::new(),
+		}
+	}
+
+	/// Returns a reference to the inner writer.
+	pub fn get_ref(&self) -> &W {
+		&self.inner
+	}
+
+	/// Converts and writes any pending input, then flushes and returns the
+	/// inner writer.
+	///
+	/// Fails with [io::ErrorKind::InvalidData] if the input ends with an
+	/// incomplete UTF-8 sequence.
+	pub fn finish(mut self) -> io::Result<W> {
+		self.decoder.finish()?;
+		self.converter.finish_to(&mut self.output);
+		self.write_output()?;
+		self.inner.flush()?;
+		Ok(self.inner)
+	}
+
+	fn write_output(&mut self) -> io::Result<()> {
+		self.inner.write_all(self.output.as_bytes())?;
+		self.output.clear();
+		Ok(())
+	}
+}
+
+impl<W: io::Write> io::Write for ConversionWriter<W> {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		let converter = &mut self.converter;
+		let output = &mut self.output;
+		self.decoder
+			.decode(buf, |text| converter.push_str_to(text, output))?;
+		self.write_output()?;
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.inner.flush()
+	}
+}
+
+/// Reader that converts the UTF-8 text read from the inner reader.
+///
+/// ```
+/// use std::io::Read;
+/// use kana::*;
+///
+/// let input = "hiragana".as_bytes();
+/// let mut reader = ConversionReader::new(input, ConversionTarget::Hiragana);
+/// let mut output = String::new();
+/// reader.read_to_string(&mut output).unwrap();
+/// assert_eq!(output, "ひらがな");
+/// ```
+pub struct ConversionReader<R: io::Read> {
+	inner: R,
+	decoder: Utf8Decoder,
+	converter: Converter,
+	buffer: Vec<u8>,
+	output: String,
+	output_pos: usize,
+	done: bool,
+}
+
+impl<R: io::Read> ConversionReader<R> {
+	/// Creates a new reader converting to the given target.
+	pub fn new(inner: R, target: ConversionTarget) -> ConversionReader<R> {
+		ConversionReader {
+			inner,
+			decoder: Utf8Decoder::default(),
+			converter: Converter::new(target),
+			buffer: vec![0; READ_BUFFER_SIZE],
+			output: String::new(),
+			output_pos: 0,
+			done: false,
+		}
+	}
+
+	/// Returns a reference to the inner reader.
+	pub fn get_ref(&self) -> &R {
+		&self.inner
+	}
+
+	/// Reads from the inner reader until there is converted output available
+	/// or the input ends.
+	fn fill_output(&mut self) -> io::Result<()> {
+		self.output.clear();
+		self.output_pos = 0;
+		while self.output.is_empty() && !self.done {
+			let count = match self.inner.read(&mut self.buffer) {
+				Ok(count) => count,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+				Err(err) => return Err(err),
+			};
+			if count == 0 {
+				self.done = true;
+				self.decoder.finish()?;
+				self.converter.finish_to(&mut self.output);
+			} else {
+				let converter = &mut self.converter;
+				let output = &mut self.output;
+				self.decoder.decode(&self.buffer[..count], |text| {
+					converter.push_str_to(text, output)
+				})?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<R: io::Read> io::Read for ConversionReader<R> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		if self.output_pos >= self.output.len() {
+			self.fill_output()?;
+		}
+		let available = &self.output.as_bytes()[self.output_pos..];
+		let count = std::cmp::min(available.len(), buf.len());
+		buf[..count].copy_from_slice(&available[..count]);
+		self.output_pos += count;
+		Ok(count)
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::io::{Read, Write};
+
+	/// Reader returning at most `size` bytes on each read.
+	struct SmallReader<'a> {
+		input: &'a [u8],
+		size: usize,
+	}
+
+	impl<'a> Read for SmallReader<'a> {
+		fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+			let count = std::cmp::min(std::cmp::min(self.size, buf.len()), self.input.len());
+			buf[..count].copy_from_slice(&self.input[..count]);
+			self.input = &self.input[count..];
+			Ok(count)
+		}
+	}
+
+	fn write(input: &str, target: ConversionTarget, size: usize) -> String {
+		let mut writer = ConversionWriter::new(Vec::new(), target);
+		for piece in input.as_bytes().chunks(size) {
+			writer.write_all(piece).unwrap();
+		}
+		String::from_utf8(writer.finish().unwrap()).unwrap()
+	}
+
+	fn read(input: &str, target: ConversionTarget, size: usize) -> String {
+		let reader = SmallReader {
+			input: input.as_bytes(),
+			size,
+		};
+		let mut reader = ConversionReader::new(reader, target);
+		let mut output = String::new();
+		reader.read_to_string(&mut output).unwrap();
+		output
+	}
+
+	#[test]
+	fn test_conversion_stream() {
+		let romaji = "shinbun wo yonda. kitte ha motteru? n'ya, nnya, kon'nichiha! ".repeat(50);
+		let kana = "かっぱゞ こゝろ きょうは いっしょに いこう、ね？ ".repeat(50);
+
+		let cases = [
+			(
+				&romaji,
+				ConversionTarget::Hiragana,
+				super::super::to_hiragana(&romaji),
+			),
+			(
+				&romaji,
+				ConversionTarget::Katakana,
+				super::super::to_katakana(&romaji),
+			),
+			(
+				&kana,
+				ConversionTarget::Romaji,
+				super::super::to_romaji(&kana),
+			),
+		];
+
+		for (input, target, expected) in cases.iter() {
+			for &size in [1, 2, 3, 5, 7, 1000, 100000].iter() {
+				assert_eq!(&write(input, *target, size), expected);
+				assert_eq!(&read(input, *target, size), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn test_conversion_stream_invalid_utf8() {
+		let mut writer = ConversionWriter::new(Vec::new(), ConversionTarget::Romaji);
+		writer.write_all(&"か".as_bytes()[..2]).unwrap();
+		let err = writer.finish().unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+		let mut writer = ConversionWriter::new(Vec::new(), ConversionTarget::Romaji);
+		let err = writer.write_all(b"ka\xFFka").unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+		let input: &[u8] = b"ka\xE3\x81";
+		let mut reader = ConversionReader::new(input, ConversionTarget::Hiragana);
+		let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	}
+}
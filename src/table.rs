@@ -87,10 +87,36 @@ fn insert_all(
 	map.insert(key, val);
 
 	// Generate and insert the katakana version of the key (used in the TO_ROMAJI case)
-	use super::util::hiragana_to_katakana;
+	use super::util::{decompose_voiced, hiragana_to_katakana, push_half_width_katakana};
 	let katakana = key.chars().map(hiragana_to_katakana).collect::<String>();
+	// Generate the halfwidth version of the katakana key, if any
+	let mut half_width = String::new();
+	if katakana
+		.chars()
+		.all(|chr| push_half_width_katakana(&mut half_width, chr))
+	{
+		map.insert(push_str(half_width), val);
+	}
 	if katakana != key {
-		map.insert(push_str(katakana), val);
+		map.insert(push_str(katakana.clone()), val);
+	}
+
+	// Generate the decomposed version of the kana keys (e.g. `か` with the
+	// combining U+3099 mark for `が`)
+	for kana in [key.to_string(), katakana].iter() {
+		let mut decomposed = String::new();
+		for chr in kana.chars() {
+			match decompose_voiced(chr) {
+				Some((base, mark)) => {
+					decomposed.push(base);
+					decomposed.push(mark);
+				}
+				None => decomposed.push(chr),
+			}
+		}
+		if &decomposed != kana {
+			map.insert(push_str(decomposed), val);
+		}
 	}
 
 	// Generate the upper case variants of the romaji key (for the TO_HIRAGANA case)
@@ -113,8 +139,8 @@ lazy_static! {
 
 	/// Internal lookup table for converting Romaji to Hiragana/Katakana.
 	///
-	/// Note that Katakana conversion is derived automatically from the Hiragana
-	/// by the conversion function.
+	/// Note that the Katakana conversion table [TO_KATAKANA] is derived
+	/// automatically from this one.
 	///
 	/// ## Note on multi-char lookup
 	///
@@ -499,6 +525,28 @@ lazy_static! {
 		size
 	};
 
+	/// Proper prefixes of all keys in the [TO_HIRAGANA] table. An input that
+	/// is in this set could still match a longer key with more input.
+	pub static ref TO_HIRAGANA_PREFIXES: FnvHashSet<&'static str> = {
+		let mut set = FnvHashSet::<&'static str>::default();
+		for key in TO_HIRAGANA.keys() {
+			for (pos, _) in key.char_indices().skip(1) {
+				set.insert(&key[..pos]);
+			}
+		}
+		set
+	};
+
+	/// Internal lookup table for converting Romaji to Katakana, with the same
+	/// keys as [TO_HIRAGANA].
+	pub static ref TO_KATAKANA: FnvHashMap<&'static str, String> = {
+		use super::util::hiragana_to_katakana;
+		TO_HIRAGANA
+			.iter()
+			.map(|(&key, &val)| (key, val.chars().map(hiragana_to_katakana).collect()))
+			.collect()
+	};
+
 	/// Internal lookup table for converting from Hiragana/Katakana to Romaji.
 	///
 	/// Note that Katakana keys are derived automatically from the Hiragana
@@ -570,6 +618,61 @@ lazy_static! {
 			("７", "7"),
 			("８", "8"),
 			("９", "9"),
+
+			// Small katakana from the Katakana Phonetic Extensions, used in
+			// Ainu for the syllable final consonants
+			("ㇰ", "k"),
+			("ㇱ", "s"),
+			("ㇲ", "s"),
+			("ㇳ", "t"),
+			("ㇴ", "n"),
+			("ㇵ", "h"),
+			("ㇶ", "h"),
+			("ㇷ", "h"),
+			("ㇸ", "h"),
+			("ㇹ", "h"),
+			("ㇺ", "m"),
+			("ㇻ", "r"),
+			("ㇼ", "r"),
+			("ㇽ", "r"),
+			("ㇾ", "r"),
+			("ㇿ", "r"),
+			("ㇷ\u{309A}", "p"),
+
+			// Roman numerals
+			("Ⅰ", "I"),
+			("Ⅱ", "II"),
+			("Ⅲ", "III"),
+			("Ⅳ", "IV"),
+			("Ⅴ", "V"),
+			("Ⅵ", "VI"),
+			("Ⅶ", "VII"),
+			("Ⅷ", "VIII"),
+			("Ⅸ", "IX"),
+			("Ⅹ", "X"),
+			("Ⅺ", "XI"),
+			("Ⅻ", "XII"),
+			("Ⅼ", "L"),
+			("Ⅽ", "C"),
+			("Ⅾ", "D"),
+			("Ⅿ", "M"),
+
+			("ⅰ", "i"),
+			("ⅱ", "ii"),
+			("ⅲ", "iii"),
+			("ⅳ", "iv"),
+			("ⅴ", "v"),
+			("ⅵ", "vi"),
+			("ⅶ", "vii"),
+			("ⅷ", "viii"),
+			("ⅸ", "ix"),
+			("ⅹ", "x"),
+			("ⅺ", "xi"),
+			("ⅻ", "xii"),
+			("ⅼ", "l"),
+			("ⅽ", "c"),
+			("ⅾ", "d"),
+			("ⅿ", "m"),
 		];
 
 		let mut table = hashmap! {
@@ -3,192 +3,612 @@
 //! This is largely based on https://github.com/PSeitz/wana_kana_rust but
 //! provides an API specifically design for this application.
 
-use super::constants::*;
-use super::table::*;
+use std::borrow::Cow;
+use std::fmt;
+
+use super::chunks::*;
+use super::is::*;
+use super::options::*;
+use super::report::*;
+use super::spans::*;
+use super::table::TO_ROMAJI;
 use super::util::*;
+use super::width::to_halfwidth_ascii;
 
 /// Converts the input string into hiragana. Unknown characters just pass
-/// through unchanged.
+/// through unchanged, unless configured by [set_default_options].
 ///
 /// Supports mapping romaji and katakana.
+///
+/// A `|` between romaji can be used to force the segmentation of ambiguous
+/// input (e.g. `n|ya` is `んや` instead of `にゃ`, and `k|ka` is `kか` instead
+/// of `っか`). The separator is removed when followed by a letter, otherwise
+/// it is kept as is.
+///
+/// ```
+/// assert_eq!(kana::to_hiragana("kin|yuu"), "きんゆう");
+/// assert_eq!(kana::to_hiragana("a | b"), "あ | b");
+/// ```
 pub fn to_hiragana<S: AsRef<str>>(input: S) -> String {
 	let input = input.as_ref();
-	let mut src = input;
-	let mut out = String::with_capacity(src.len());
-
-	while src.len() > 0 {
-		let mut chars = src.char_indices();
-		let (_, next) = chars.next().unwrap(); // next character
-		let (size, _) = chars.next().unwrap_or((src.len(), ' ')); // size of next
-
-		let mut skip = size;
-		let mut done = false;
-
-		if char_in_range(next, KATAKANA_START, KATAKANA_TO_HIRAGANA_END) {
-			// For katakana we can convert directly just by offseting the code
-			let code = (next as u32) - KATAKANA_TO_HIRAGANA_OFFSET_SUB;
-			let hiragana = unsafe { std::char::from_u32_unchecked(code) };
-			out.push(hiragana);
-			done = true;
-		} else if !char_in_range(next, HIRAGANA_START, HIRAGANA_END) {
-			// Handle the double consonant case
-			let b = src.as_bytes();
-			if b.len() >= 2 {
-				let c = b[0] as char;
-				if c != 'n' && c != 'N' && is_consonant(c, true) && b[0] == b[1] {
-					out.push('っ');
-					done = true;
-				}
-			}
+	if let Some(options) = global_options() {
+		return to_hiragana_with(input, &options).unwrap();
+	}
+	let mut out = String::with_capacity(input.len());
+	for chunk in HiraganaChunks::new(input) {
+		out.push_str(chunk.text);
+	}
+	out
+}
 
-			if !done {
-				// Try to convert all chunk sizes, starting from largest down to 1.
-				let max_chunk = if next == ':'
-					|| (next >= 'a' && next <= 'z')
-					|| (next >= 'A' && next <= 'Z')
-				{
-					// Multi-char lookup keys either start with A-Z or `:`
-					*TO_HIRAGANA_MAX_CHUNK
-				} else {
-					1
-				};
-				for len in (1..=max_chunk).rev() {
-					let chunk = get_prefix(src, len);
-					if let Some(kana) = TO_HIRAGANA.get(chunk) {
-						out.push_str(kana);
-						skip = chunk.len();
-						done = true;
-						break;
-					}
-				}
+/// Same as [to_hiragana] but using the given [ConversionOptions].
+///
+/// This only fails if the options use [OnUnknown::Error].
+pub fn to_hiragana_with<S: AsRef<str>>(
+	input: S,
+	options: &ConversionOptions,
+) -> Result<String, ConversionError> {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	let mut run = None;
+	for chunk in HiraganaChunks::new(input) {
+		if preserve_katakana(input, &chunk, options.preserve_katakana, &mut run) {
+			out.push_str(&input[chunk.src]);
+			continue;
+		} else if let Some(kana) = archaic_kana(&chunk, options, false) {
+			out.push_str(kana);
+			continue;
+		} else if chunk.text == "ー" && options.hiragana_prolonged_mark != KanaProlongedMark::Keep
+		{
+			if let Some(vowel) = out.chars().next_back().and_then(hiragana_vowel) {
+				let conventional =
+					options.hiragana_prolonged_mark == KanaProlongedMark::Conventional;
+				out.push_str(match vowel {
+					'a' => "あ",
+					'i' => "い",
+					'u' => "う",
+					'e' if conventional => "い",
+					'e' => "え",
+					_ if conventional => "う",
+					_ => "お",
+				});
+				continue;
 			}
 		}
+		push_chunk(&mut out, &chunk, Target::Kana, options)?;
+	}
+	Ok(out)
+}
 
-		// If could not find a conversion, just pass through the character.
-		if !done {
-			out.push(next);
-		}
-
-		src = &src[skip..];
+/// Same as [to_hiragana] but returns the input unchanged, without allocating,
+/// if it does not require any conversion.
+pub fn to_hiragana_cow<'a>(input: &'a str) -> Cow<'a, str> {
+	if global_options().is_some() {
+		return Cow::Owned(to_hiragana(input));
 	}
+	collect_cow(input, HiraganaChunks::new(input))
+}
 
-	out
+/// Same as [to_hiragana] but writes the output to the given writer.
+pub fn to_hiragana_into<S: AsRef<str>, W: fmt::Write>(input: S, output: &mut W) -> fmt::Result {
+	if global_options().is_some() {
+		return output.write_str(&to_hiragana(input));
+	}
+	for chunk in HiraganaChunks::new(input.as_ref()) {
+		output.write_str(chunk.text)?;
+	}
+	Ok(())
 }
 
 /// Converts the input string into katakana. Unknown characters just pass
-/// through unchanged.
+/// through unchanged, unless configured by [set_default_options].
 ///
 /// Supports mapping romaji and hiragana.
 pub fn to_katakana<S: AsRef<str>>(input: S) -> String {
-	let hiragana = to_hiragana(input);
-	let mut out = String::with_capacity(hiragana.len());
-	for chr in hiragana.chars() {
-		out.push(hiragana_to_katakana(chr));
+	let input = input.as_ref();
+	if let Some(options) = global_options() {
+		return to_katakana_with(input, &options).unwrap();
+	}
+	let mut out = String::with_capacity(input.len());
+	for chunk in KatakanaChunks::new(input) {
+		out.push_str(chunk.text);
 	}
-
 	out
 }
 
-/// Converts any kana in the input to romaji.
+/// Same as [to_katakana] but using the given [ConversionOptions].
+///
+/// This only fails if the options use [OnUnknown::Error].
+pub fn to_katakana_with<S: AsRef<str>>(
+	input: S,
+	options: &ConversionOptions,
+) -> Result<String, ConversionError> {
+	let input = input.as_ref();
+	let input = if options.loanword_long_vowels {
+		expand_long_vowel_marks(input)
+	} else {
+		Cow::Borrowed(input)
+	};
+	let mut out = String::with_capacity(input.len());
+	let mut last_vowel = None;
+	for chunk in KatakanaChunks::new(&input) {
+		if options.loanword_long_vowels {
+			let (key, vowel) = match chunk.kind {
+				ChunkKind::Table(key) => {
+					let vowel = key.chars().last().unwrap().to_ascii_lowercase();
+					(key, Some(vowel).filter(|x| "aiueo".contains(*x)))
+				}
+				_ => ("", None),
+			};
+			if let (Some(last), Some(vowel)) = (last_vowel, vowel) {
+				let long = key.len() == 1
+					&& (last == vowel
+						|| (last, vowel) == ('o', 'u')
+						|| (last, vowel) == ('e', 'i'));
+				if long {
+					out.push('ー');
+					last_vowel = None;
+					continue;
+				}
+			}
+			last_vowel = vowel;
+		}
+		if let Some(kana) = archaic_kana(&chunk, options, true) {
+			out.push_str(kana);
+			continue;
+		}
+		push_chunk(&mut out, &chunk, Target::Kana, options)?;
+	}
+	Ok(out)
+}
+
+/// Replaces the vowels with a macron or circumflex by the vowel followed by a
+/// `-`, so that it is converted to a long vowel even after a consonant.
+///
+/// This keeps the byte offsets of the input, since all those vowels are
+/// encoded with two bytes.
+fn expand_long_vowel_marks<'a>(input: &'a str) -> Cow<'a, str> {
+	if !input.chars().any(|chr| expand_long_vowel(chr).is_some()) {
+		return Cow::Borrowed(input);
+	}
+	let mut out = String::with_capacity(input.len());
+	for chr in input.chars() {
+		match expand_long_vowel(chr) {
+			Some(expanded) => out.push_str(expanded),
+			None => out.push(chr),
+		}
+	}
+	Cow::Owned(out)
+}
+
+/// Returns the vowel followed by a `-` for a vowel with a macron or
+/// circumflex.
+fn expand_long_vowel(chr: char) -> Option<&'static str> {
+	let expanded = match chr {
+		'ā' | 'â' => "a-",
+		'ī' | 'î' => "i-",
+		'ū' | 'û' => "u-",
+		'ē' | 'ê' => "e-",
+		'ō' | 'ô' => "o-",
+		'Ā' | 'Â' => "A-",
+		'Ī' | 'Î' => "I-",
+		'Ū' | 'Û' => "U-",
+		'Ē' | 'Ê' => "E-",
+		'Ō' | 'Ô' => "O-",
+		_ => return None,
+	};
+	Some(expanded)
+}
+
+/// Same as [to_katakana] but writes the output to the given writer.
+pub fn to_katakana_into<S: AsRef<str>, W: fmt::Write>(input: S, output: &mut W) -> fmt::Result {
+	if global_options().is_some() {
+		return output.write_str(&to_katakana(input));
+	}
+	for chunk in KatakanaChunks::new(input.as_ref()) {
+		output.write_str(chunk.text)?;
+	}
+	Ok(())
+}
+
+/// Converts any kana in the input to romaji. Unknown characters just pass
+/// through unchanged, unless configured by [set_default_options].
 ///
 /// Note that this will pass through interpunct (`・`) marks. Other Japanese
 /// punctuation are converted to ASCII variants.
 pub fn to_romaji<S: AsRef<str>>(input: S) -> String {
-	// Representation for a `っ` that is not a double consonant.
-	const SMALL_TSU_REPR: char = '\'';
-	// Representation for an invalid iteration mark.
-	const INVALID_ITERATION_MARK: char = '?';
+	let input = input.as_ref();
+	if let Some(options) = global_options() {
+		return to_romaji_with(input, &options).unwrap();
+	}
+	let mut out = String::with_capacity(input.len());
+	for chunk in RomajiChunks::new(input) {
+		out.push_str(chunk.text);
+	}
+	out
+}
+
+/// Same as [to_romaji] but using the given [ConversionOptions].
+///
+/// This only fails if the options use [OnUnknown::Error].
+pub fn to_romaji_with<S: AsRef<str>>(
+	input: S,
+	options: &ConversionOptions,
+) -> Result<String, ConversionError> {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	for chunk in RomajiChunks::new(input) {
+		if push_long_vowel(&mut out, &chunk, options) {
+			continue;
+		}
+		if options.n_separator != NSeparator::Apostrophe && chunk.text.starts_with("n'") {
+			out.push_str(match options.n_separator {
+				NSeparator::Hyphen => "n-",
+				_ => "nn",
+			});
+			out.push_str(&chunk.text[2..]);
+			continue;
+		}
+		if (options.expand_enclosed_digits || options.ascii_only)
+			&& chunk.kind == ChunkKind::Passthrough
+			&& push_enclosed_digit(&mut out, chunk.text.chars().next().unwrap())
+		{
+			continue;
+		}
+		if options.ascii_only && chunk.kind == ChunkKind::Passthrough {
+			push_ascii(&mut out, &chunk, options)?;
+			continue;
+		}
+		if options.ascii_only && chunk.kind == ChunkKind::Rule && !chunk.text.is_ascii() {
+			// Grapheme extenders following a converted chunk
+			continue;
+		}
+		let start = out.len();
+		push_chunk(&mut out, &chunk, Target::Romaji, options)?;
+		if options.ascii_only && !out[start..].is_ascii() {
+			let text = out.split_off(start);
+			push_ascii_text(&mut out, &text);
+		}
+	}
+	Ok(out)
+}
+
+/// Applies the long vowel options to a romaji chunk, returning true if the
+/// chunk was handled.
+fn push_long_vowel(out: &mut String, chunk: &Chunk, options: &ConversionOptions) -> bool {
+	let last = match out.chars().next_back() {
+		Some(last) => last,
+		None => return false,
+	};
+
+	if options.collapse_long_vowels {
+		if let ChunkKind::Table(_) = chunk.kind {
+			let long = matches!(
+				(last, chunk.text),
+				('o', "u") | ('o', "o") | ('u', "u") | ('a', "a") | ('e', "e") | ('e', "i")
+			);
+			if long {
+				out.pop();
+				if options.ascii_only {
+					out.push(last);
+					out.push(last);
+				} else {
+					out.push(vowel_to_macron(last).unwrap());
+				}
+				return true;
+			}
+		}
+	}
 
-	let mut was_small_tsu = false;
+	let prolonged_mark = match options.prolonged_mark {
+		ProlongedMark::Macron if options.ascii_only => ProlongedMark::Repeat,
+		mark => mark,
+	};
+	if chunk.kind != ChunkKind::Table("ー") || prolonged_mark == ProlongedMark::Hyphen {
+		return false;
+	}
+	match (prolonged_mark, vowel_to_macron(last), macron_to_vowel(last)) {
+		(ProlongedMark::Repeat, Some(_), _) => out.push(last),
+		(ProlongedMark::Repeat, _, Some(vowel)) => out.push(vowel),
+		(ProlongedMark::Macron, Some(macron), _) => {
+			out.pop();
+			out.push(macron);
+		}
+		(ProlongedMark::Macron, _, Some(_)) => {}
+		_ => return false,
+	}
+	true
+}
 
-	let mut last_romaji = "";
+/// Appends a passthrough chunk to the romaji output for the `ascii_only`
+/// option, converting long vowel marks and full-width ASCII.
+fn push_ascii(
+	out: &mut String,
+	chunk: &Chunk,
+	options: &ConversionOptions,
+) -> Result<(), ConversionError> {
+	let chr = chunk.text.chars().next().unwrap();
+	if chr.is_ascii() {
+		// Any grapheme extenders in the chunk are dropped
+		out.push(chr);
+	} else if let Some(expanded) = expand_long_vowel(chr) {
+		let vowel = expanded.chars().next().unwrap();
+		out.push(vowel);
+		out.push(vowel.to_ascii_lowercase());
+	} else if let '\u{FF01}'..='\u{FF5E}' = chr {
+		out.push_str(&to_halfwidth_ascii(chr.to_string()));
+	} else {
+		let on_unknown = match options.on_unknown {
+			OnUnknown::Passthrough => OnUnknown::Skip,
+			OnUnknown::ReplaceWith(replacement) if !replacement.is_ascii() => OnUnknown::Skip,
+			on_unknown => on_unknown,
+		};
+		let options = ConversionOptions {
+			on_unknown,
+			..*options
+		};
+		push_chunk(out, chunk, Target::Romaji, &options)?;
+	}
+	Ok(())
+}
 
-	let mut src = input.as_ref();
-	let mut out = String::with_capacity(src.len());
-	while src.len() > 0 {
-		let mut chars = src.char_indices();
-		let (_, next) = chars.next().unwrap(); // next character
-		let (size, _) = chars.next().unwrap_or((src.len(), ' ')); // size of next
+/// Appends the converted text of a chunk for the `ascii_only` option,
+/// writing the long vowels as doubled vowels and the quotes as ASCII quotes.
+/// Any other non-ASCII character is skipped.
+fn push_ascii_text(out: &mut String, text: &str) {
+	for chr in text.chars() {
+		if chr.is_ascii() {
+			out.push(chr);
+		} else if let Some(expanded) = expand_long_vowel(chr) {
+			let vowel = expanded.chars().next().unwrap();
+			out.push(vowel);
+			out.push(vowel.to_ascii_lowercase());
+		} else if let '‘' | '’' = chr {
+			out.push('\'');
+		} else if let '“' | '”' = chr {
+			out.push('"');
+		}
+	}
+}
 
-		let mut skip = size;
-		let mut done = false;
+/// Same as [to_romaji] but returns the input unchanged, without allocating,
+/// if it does not require any conversion.
+pub fn to_romaji_cow<'a>(input: &'a str) -> Cow<'a, str> {
+	if global_options().is_some() {
+		return Cow::Owned(to_romaji(input));
+	}
+	collect_cow(input, RomajiChunks::new(input))
+}
 
-		if next == 'っ' || next == 'ッ' {
-			if was_small_tsu {
-				out.push(SMALL_TSU_REPR); // Case of repeated `っ`
-			}
-			was_small_tsu = true;
-			done = true;
-		} else if next == 'ヽ' || next == 'ゝ' || next == 'ヾ' || next == 'ゞ' {
-			// Iteration marks repeat the last sillable
-			let voiced = next == 'ヾ' || next == 'ゞ';
-			let repeat = match last_romaji {
-				"yori" => "ri",
-				"koto" => "to",
-				_ => last_romaji,
-			};
-			let repeat = if voiced {
-				let voiced = romaji_to_voiced(repeat);
-				if voiced.len() > 0 {
-					voiced
-				} else {
-					// Even though it is wrong, we accept a voiced mark in a
-					// syllable that has no voiced equivalent.
-					repeat
-				}
-			} else {
-				repeat
-			};
-			if repeat.len() > 0 {
-				out.push_str(repeat);
-				last_romaji = repeat;
-			} else {
-				out.push(INVALID_ITERATION_MARK);
+/// Same as [to_romaji] but writes the output to the given writer.
+pub fn to_romaji_into<S: AsRef<str>, W: fmt::Write>(input: S, output: &mut W) -> fmt::Result {
+	if global_options().is_some() {
+		return output.write_str(&to_romaji(input));
+	}
+	for chunk in RomajiChunks::new(input.as_ref()) {
+		output.write_str(chunk.text)?;
+	}
+	Ok(())
+}
+
+/// Same as [to_romaji] but also returns a [ConversionReport] describing the
+/// conversion.
+///
+/// This can be used to find the parts of the input that could not be
+/// converted (e.g. kanji).
+pub fn to_romaji_report<S: AsRef<str>>(input: S) -> (String, ConversionReport) {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	let mut report = ConversionReport::default();
+	for chunk in RomajiChunks::new(input) {
+		out.push_str(chunk.text);
+		report.add(&chunk);
+	}
+	(out, report)
+}
+
+/// Same as [to_hiragana] but also returns a [SpanMap] aligning each segment
+/// of the output with the input it came from.
+pub fn to_hiragana_spans<S: AsRef<str>>(input: S) -> (String, SpanMap) {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	let mut spans = SpanMap::default();
+	for chunk in HiraganaChunks::new(input) {
+		out.push_str(chunk.text);
+		spans.add(&chunk, chunk.text.len());
+	}
+	(out, spans)
+}
+
+/// Same as [to_katakana] but also returns a [SpanMap] aligning each segment
+/// of the output with the input it came from.
+pub fn to_katakana_spans<S: AsRef<str>>(input: S) -> (String, SpanMap) {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	let mut spans = SpanMap::default();
+	for chunk in KatakanaChunks::new(input) {
+		out.push_str(chunk.text);
+		spans.add(&chunk, chunk.text.len());
+	}
+	(out, spans)
+}
+
+/// Same as [to_romaji] but also returns a [SpanMap] aligning each segment of
+/// the output with the input it came from.
+///
+/// ```
+/// let (romaji, spans) = kana::to_romaji_spans("きょうは晴れ");
+/// assert_eq!(romaji, "kyouha晴re");
+///
+/// // `晴` is at 12..15 in the input
+/// let range = spans.output_range(12..15);
+/// assert_eq!(&romaji[range], "晴");
+/// ```
+pub fn to_romaji_spans<S: AsRef<str>>(input: S) -> (String, SpanMap) {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	let mut spans = SpanMap::default();
+	for chunk in RomajiChunks::new(input) {
+		out.push_str(chunk.text);
+		spans.add(&chunk, chunk.text.len());
+	}
+	(out, spans)
+}
+
+/// Returns the global default options for the functions without options, or
+/// `None` if those are the [ConversionOptions] default.
+#[inline]
+fn global_options() -> Option<ConversionOptions> {
+	let options = infallible_options();
+	if options != ConversionOptions::default() {
+		Some(options)
+	} else {
+		None
+	}
+}
+
+/// Collects the chunks of a conversion into a `Cow`, only allocating once the
+/// output differs from the input.
+fn collect_cow<'a, I: Iterator<Item = Chunk<'a>>>(input: &'a str, chunks: I) -> Cow<'a, str> {
+	let mut out: Option<String> = None;
+	for chunk in chunks {
+		if let Some(ref mut out) = out {
+			out.push_str(chunk.text);
+		} else if chunk.text != &input[chunk.src.clone()] {
+			let mut owned = String::with_capacity(input.len());
+			owned.push_str(&input[..chunk.src.start]);
+			owned.push_str(chunk.text);
+			out = Some(owned);
+		}
+	}
+	match out {
+		Some(out) => Cow::Owned(out),
+		None => Cow::Borrowed(input),
+	}
+}
+
+/// Returns the archaic kana for a `wi` or `we` chunk, if enabled by the
+/// options.
+fn archaic_kana(
+	chunk: &Chunk,
+	options: &ConversionOptions,
+	katakana: bool,
+) -> Option<&'static str> {
+	if !options.archaic_wi_we {
+		return None;
+	}
+	match chunk.kind {
+		ChunkKind::Table(key) if key.eq_ignore_ascii_case("wi") => {
+			Some(if katakana { "ヰ" } else { "ゐ" })
+		}
+		ChunkKind::Table(key) if key.eq_ignore_ascii_case("we") => {
+			Some(if katakana { "ヱ" } else { "ゑ" })
+		}
+		_ => None,
+	}
+}
+
+/// Returns the romaji vowel for a hiragana, if any.
+fn hiragana_vowel(chr: char) -> Option<char> {
+	let romaji = TO_ROMAJI.get(chr.encode_utf8(&mut [0; 4]) as &str)?;
+	romaji.chars().next_back().filter(|x| "aiueo".contains(*x))
+}
+
+/// Returns true if the chunk is a katakana that must be kept as is by the
+/// given policy.
+///
+/// The `run` caches the end of the last katakana run checked for
+/// [PreserveKatakana::InKanjiCompounds] and whether it is preserved.
+fn preserve_katakana(
+	input: &str,
+	chunk: &Chunk,
+	policy: PreserveKatakana,
+	run: &mut Option<(usize, bool)>,
+) -> bool {
+	let start = chunk.src.start;
+	let chr = input[start..].chars().next().unwrap();
+	match policy {
+		PreserveKatakana::Never => false,
+		PreserveKatakana::SmallKe => chr == 'ヶ' || chr == 'ヵ',
+		PreserveKatakana::InKanjiCompounds => {
+			if chr == 'ヶ' || chr == 'ヵ' {
+				return true;
+			} else if !is_katakana(chr) {
+				return false;
 			}
-			done = true;
-		} else if TO_ROMAJI_CHARS.contains(&next) {
-			// Try to convert all chunk sizes down to 1
-			for len in (1..=*TO_ROMAJI_MAX_CHUNK).rev() {
-				let chunk = get_prefix(src, len);
-				if let Some(romaji) = TO_ROMAJI.get(chunk) {
-					if was_small_tsu {
-						if let Some(doubled) = romaji.chars().next() {
-							if is_consonant(doubled, true) {
-								was_small_tsu = false;
-								out.push(doubled);
-							}
-						}
-						if was_small_tsu {
-							out.push(SMALL_TSU_REPR);
-							was_small_tsu = false;
-						}
-					}
-					last_romaji = romaji;
-					out.push_str(romaji);
-					skip = chunk.len();
-					done = true;
-					break;
+			if let Some((end, preserve)) = *run {
+				if start < end {
+					return preserve;
 				}
 			}
+
+			let is_run = |chr: char| is_katakana(chr) || chr == 'ー';
+			let is_compound = |chr: Option<char>| match chr {
+				Some(chr) => is_kanji(chr) || chr == '々',
+				None => false,
+			};
+			let rest = &input[start..];
+			let len = rest.find(|chr| !is_run(chr)).unwrap_or(rest.len());
+			let preserve = is_compound(input[..start].chars().next_back())
+				&& is_compound(rest[len..].chars().next());
+			*run = Some((start + len, preserve));
+			preserve
 		}
+	}
+}
+
+/// Target script of a conversion.
+#[derive(Copy, Clone)]
+enum Target {
+	Kana,
+	Romaji,
+}
 
-		// If could not find a conversion, just pass through the character.
-		if !done {
-			if was_small_tsu {
-				out.push(SMALL_TSU_REPR);
-				was_small_tsu = false;
+impl Target {
+	/// Returns true if the character is valid in the target script, as such
+	/// it is not considered unknown when passed through.
+	fn accepts(self, chr: char) -> bool {
+		match self {
+			Target::Kana => {
+				is_kana(chr)
+					|| is_japanese_mark(chr)
+					|| is_japanese_punctuation(chr)
+					|| chr.is_whitespace()
 			}
-			out.push(next);
+			Target::Romaji => chr.is_ascii(),
 		}
-
-		src = &src[skip..];
 	}
+}
 
-	if was_small_tsu {
-		out.push(SMALL_TSU_REPR);
+/// Appends a converted chunk to the output, applying the [OnUnknown] policy
+/// from the options to unknown characters.
+fn push_chunk(
+	out: &mut String,
+	chunk: &Chunk,
+	target: Target,
+	options: &ConversionOptions,
+) -> Result<(), ConversionError> {
+	if let ChunkKind::Passthrough = chunk.kind {
+		let chr = chunk.text.chars().next().unwrap();
+		if !target.accepts(chr) {
+			match options.on_unknown {
+				OnUnknown::Passthrough => {}
+				OnUnknown::Skip => return Ok(()),
+				OnUnknown::ReplaceWith(replacement) => {
+					out.push(replacement);
+					return Ok(());
+				}
+				OnUnknown::Error => {
+					return Err(ConversionError {
+						chr,
+						offset: chunk.src.start,
+					})
+				}
+			}
+		}
 	}
-
-	out
+	out.push_str(chunk.text);
+	Ok(())
 }
 
 // spell-checker: disable
@@ -222,6 +642,30 @@ mod tests {
 
 		// Iteration marks
 		check("ヽヾ", "ゝゞ");
+
+		// Katakana without a hiragana equivalent
+		check("ヷヸヹヺヿ", "ヷヸヹヺヿ");
+		check("ヷア", "ヷa");
+		check("ッカ・ヴァ", "kka・va");
+		assert_eq!(to_katakana("ヷヸヹヺヿ"), "ヷヸヹヺヿ");
+		assert_eq!(to_katakana("ㇰㇱㇷ゚ㇿ"), "ㇰㇱㇷ゚ㇿ");
+		assert_eq!(to_katakana("kaㇰ"), "カㇰ");
+
+		// Same as converting to hiragana and offsetting to katakana, for the
+		// input without katakana-specific characters
+		for input in &[
+			"kanaカナかな",
+			"toukyou no ラーメン",
+			"kitte・ぎゅうにゅう、ヴァイオリン",
+			"ゝゞ おばあちゃん ﾊﾝｶｸ",
+			"shinnyuu n'ya kyakka",
+		] {
+			let offset = to_hiragana(input)
+				.chars()
+				.map(hiragana_to_katakana)
+				.collect::<String>();
+			assert_eq!(to_katakana(input), offset, "input `{}`", input);
+		}
 	}
 
 	#[test]
@@ -259,6 +703,10 @@ mod tests {
 		const S: &str = "shyagyatsuxtujaannan n'annza xzm";
 		check(D, S);
 
+		// Explicit separator
+		check("んや おお kか", "n|ya o|o k|ka");
+		check("| あ|", "| a|");
+
 		// Pass through punctuation
 		check("・ー～", "・ー～");
 		check("あ：ば", "A: BA"); // `: ` to `：`
@@ -827,4 +1275,518 @@ mod tests {
 		check("ゟゝゝ".to_string(), "yoririri".to_string());
 		check("ゟゞゞ".to_string(), "yoririri".to_string());
 	}
+
+	#[test]
+	fn test_to_romaji_report() {
+		let (out, report) = to_romaji_report("ひらがな");
+		assert_eq!(out, "hiragana");
+		assert!(report.is_complete());
+		assert_eq!(report.converted_chunks, 4);
+		assert_eq!(
+			report.table_entries,
+			vec![("ひ", 1), ("ら", 1), ("が", 1), ("な", 1)]
+		);
+
+		let (out, report) = to_romaji_report("漢字はかんじ、ほんと");
+		assert_eq!(out, "漢字hakanji,honto");
+		assert!(!report.is_complete());
+		assert_eq!(report.unconverted, vec![0..6]);
+		assert_eq!(report.converted_chunks, 8);
+
+		let (out, report) = to_romaji_report("ちょっと 待って");
+		assert_eq!(out, "chotto 待tte");
+		assert_eq!(report.unconverted, vec![12..16]);
+		assert_eq!(report.converted_chunks, 5);
+		assert_eq!(
+			report.table_entries,
+			vec![("ちょ", 1), ("と", 1), ("て", 1)]
+		);
+	}
+
+	#[test]
+	fn test_kana_ligatures() {
+		assert_eq!(to_romaji("㋐㋑㋒"), "aiu");
+		assert_eq!(to_romaji("5㌔"), "5kiro");
+		assert_eq!(to_romaji("㍉ゝ"), "miriri");
+		assert_eq!(to_romaji("っ㋕"), "'ka");
+		assert_eq!(to_hiragana("㋕㌧"), "かとん");
+		assert_eq!(to_katakana("㋕㌧"), "カトン");
+		assert_eq!(to_romaji("㋿"), "㋿");
+	}
+
+	#[test]
+	fn test_to_spans() {
+		fn check(spans: &SpanMap, input: &str, output: &str, expected: &[(&str, &str)]) {
+			let segments = spans
+				.spans()
+				.iter()
+				.map(|x| (&input[x.src.clone()], &output[x.out.clone()]))
+				.collect::<Vec<_>>();
+			assert_eq!(segments, expected);
+		}
+
+		let input = "kitte wo kaimashita";
+		let (output, spans) = to_hiragana_spans(input);
+		assert_eq!(output, to_hiragana(input));
+		#[rustfmt::skip]
+		check(&spans, input, &output, &[
+			("ki", "き"), ("t", "っ"), ("te", "て"), (" ", " "), ("wo", "を"), (" ", " "),
+			("ka", "か"), ("i", "い"), ("ma", "ま"), ("shi", "し"), ("ta", "た"),
+		]);
+
+		let (output, spans) = to_katakana_spans("ko-hi-");
+		assert_eq!(output, "コーヒー");
+		check(
+			&spans,
+			"ko-hi-",
+			&output,
+			&[("ko", "コ"), ("-", "ー"), ("hi", "ヒ"), ("-", "ー")],
+		);
+
+		let input = "がっこうゝ漢字";
+		let (output, spans) = to_romaji_spans(input);
+		assert_eq!(output, to_romaji(input));
+		#[rustfmt::skip]
+		check(&spans, input, &output, &[
+			("が", "ga"), ("っ", "k"), ("こ", "ko"), ("う", "u"), ("ゝ", "u"), ("漢", "漢"), ("字", "字"),
+		]);
+		assert_eq!(spans.output_range(3..6), 2..3);
+		assert_eq!(spans.to_source(5), 9);
+
+		assert_eq!(to_romaji_spans("").1, SpanMap::default());
+	}
+
+	#[test]
+	fn test_on_unknown() {
+		fn opts(on_unknown: OnUnknown) -> ConversionOptions {
+			ConversionOptions {
+				on_unknown,
+				..ConversionOptions::default()
+			}
+		}
+
+		let input = "漢字とkana、123";
+
+		let passthrough = opts(OnUnknown::Passthrough);
+		assert_eq!(
+			to_hiragana_with(input, &passthrough).unwrap(),
+			to_hiragana(input)
+		);
+		assert_eq!(
+			to_katakana_with(input, &passthrough).unwrap(),
+			to_katakana(input)
+		);
+		assert_eq!(
+			to_romaji_with(input, &passthrough).unwrap(),
+			to_romaji(input)
+		);
+
+		let skip = opts(OnUnknown::Skip);
+		assert_eq!(to_hiragana_with(input, &skip).unwrap(), "とかな、");
+		assert_eq!(to_katakana_with(input, &skip).unwrap(), "トカナ、");
+		assert_eq!(to_romaji_with(input, &skip).unwrap(), "tokana,123");
+
+		let replace = opts(OnUnknown::ReplaceWith('?'));
+		assert_eq!(to_hiragana_with(input, &replace).unwrap(), "??とかな、???");
+		assert_eq!(to_katakana_with(input, &replace).unwrap(), "??トカナ、???");
+		assert_eq!(to_romaji_with(input, &replace).unwrap(), "??tokana,123");
+
+		let error = opts(OnUnknown::Error);
+		let err = ConversionError {
+			chr: '漢',
+			offset: 0,
+		};
+		assert_eq!(to_hiragana_with(input, &error), Err(err));
+		assert_eq!(to_katakana_with(input, &error), Err(err));
+		assert_eq!(to_romaji_with(input, &error), Err(err));
+		assert_eq!(
+			to_hiragana_with("かな 1", &error),
+			Err(ConversionError {
+				chr: '1',
+				offset: 7
+			})
+		);
+		assert_eq!(to_romaji_with("かな カナ", &error).unwrap(), "kana kana");
+		assert_eq!(err.to_string(), "cannot convert `漢` (U+6F22) at offset 0");
+	}
+
+	#[test]
+	fn test_loanword_long_vowels() {
+		let options = ConversionOptions {
+			loanword_long_vowels: true,
+			..ConversionOptions::default()
+		};
+		let check = |input: &str, expected: &str| {
+			let output = to_katakana_with(input, &options).unwrap();
+			assert_eq!(output, expected, "input `{}`", input);
+		};
+
+		check("koohii", "コーヒー");
+		check("kōhī", "コーヒー");
+		check("KÔHÎ", "コーヒー");
+		check("rāmen", "ラーメン");
+		check("bouru", "ボール");
+		check("keeki", "ケーキ");
+		check("aaa", "アーア");
+		check("ramen", "ラメン");
+		check("kakao", "カカオ");
+		check("とうきょう", "トウキョウ");
+		assert_eq!(to_katakana("koohii"), "コオヒイ");
+	}
+
+	#[test]
+	fn test_prolonged_mark() {
+		fn check(mark: ProlongedMark, input: &str, expected: &str) {
+			let options = ConversionOptions {
+				prolonged_mark: mark,
+				..ConversionOptions::default()
+			};
+			let output = to_romaji_with(input, &options).unwrap();
+			assert_eq!(output, expected, "input `{}` with {:?}", input, mark);
+		}
+
+		check(ProlongedMark::Hyphen, "ビール", "bi-ru");
+		check(ProlongedMark::Repeat, "ビール", "biiru");
+		check(ProlongedMark::Macron, "ビール", "bīru");
+		check(ProlongedMark::Repeat, "コーヒー", "koohii");
+		check(ProlongedMark::Macron, "コーヒー", "kōhī");
+		check(ProlongedMark::Repeat, "ラーー", "raaa");
+		check(ProlongedMark::Macron, "ラーー", "rā");
+		check(ProlongedMark::Macron, "ー ンー", "- n-");
+	}
+
+	#[test]
+	fn test_collapse_long_vowels() {
+		let options = ConversionOptions {
+			collapse_long_vowels: true,
+			..ConversionOptions::default()
+		};
+		let check = |input: &str, expected: &str| {
+			let output = to_romaji_with(input, &options).unwrap();
+			assert_eq!(output, expected, "input `{}`", input);
+		};
+
+		check("とうきょう", "tōkyō");
+		check("おおさか", "ōsaka");
+		check("くうき", "kūki");
+		check("おかあさん", "okāsan");
+		check("せんせい", "sensē");
+		check("おねえさん", "onēsan");
+		check("いいえ", "iie");
+		check("こおう", "kōu");
+		check("ケイタイ", "kētai");
+		assert_eq!(to_romaji("とうきょう"), "toukyou");
+	}
+
+	#[test]
+	fn test_hiragana_prolonged_mark() {
+		fn check(mark: KanaProlongedMark, input: &str, expected: &str) {
+			let options = ConversionOptions {
+				hiragana_prolonged_mark: mark,
+				..ConversionOptions::default()
+			};
+			let output = to_hiragana_with(input, &options).unwrap();
+			assert_eq!(output, expected, "input `{}` with {:?}", input, mark);
+		}
+
+		check(KanaProlongedMark::Keep, "とーきょー", "とーきょー");
+		check(KanaProlongedMark::Conventional, "とーきょー", "とうきょう");
+		check(KanaProlongedMark::Repeat, "とーきょー", "とおきょお");
+		check(KanaProlongedMark::Conventional, "ケーキ", "けいき");
+		check(KanaProlongedMark::Repeat, "ケーキ", "けえき");
+		check(KanaProlongedMark::Conventional, "ra-men", "らあめん");
+		check(KanaProlongedMark::Conventional, "スーパー", "すうぱあ");
+		check(KanaProlongedMark::Conventional, "ー ンー", "ー んー");
+	}
+
+	#[test]
+	fn test_archaic_wi_we() {
+		let options = ConversionOptions {
+			archaic_wi_we: true,
+			..ConversionOptions::default()
+		};
+		assert_eq!(to_hiragana_with("wiwe", &options).unwrap(), "ゐゑ");
+		assert_eq!(to_hiragana_with("WiWE", &options).unwrap(), "ゐゑ");
+		assert_eq!(to_katakana_with("wiwe", &options).unwrap(), "ヰヱ");
+		assert_eq!(to_hiragana_with("wawo", &options).unwrap(), "わを");
+		assert_eq!(to_hiragana("wiwe"), "うぃうぇ");
+		assert_eq!(
+			to_romaji(to_hiragana_with("wiwe", &options).unwrap()),
+			"wiwe"
+		);
+	}
+
+	#[test]
+	fn test_n_separator() {
+		let check = |n_separator: NSeparator, expected: &str| {
+			let options = ConversionOptions {
+				n_separator,
+				..ConversionOptions::default()
+			};
+			let input = "きんようび ほんや ぜんいん かんな シンエイ";
+			assert_eq!(to_romaji_with(input, &options).unwrap(), expected);
+		};
+		check(
+			NSeparator::Apostrophe,
+			"kin'youbi hon'ya zen'in kanna shin'ei",
+		);
+		check(NSeparator::Hyphen, "kin-youbi hon-ya zen-in kanna shin-ei");
+		check(NSeparator::DoubleN, "kinnyoubi honnya zennin kanna shinnei");
+	}
+
+	#[test]
+	fn test_ascii_only() {
+		let options = ConversionOptions {
+			ascii_only: true,
+			..ConversionOptions::default()
+		};
+		let check = |input: &str, options: &ConversionOptions, expected: &str| {
+			let result = to_romaji_with(input, options).unwrap();
+			assert_eq!(result, expected, "input `{}`", input);
+			assert!(result.is_ascii(), "input `{}`", input);
+		};
+		check("東京タワー", &options, "tawa-");
+		check("tōkyō Ōsaka kâ", &options, "tookyoo Oosaka kaa");
+		check("ＡＢＣ！　かな・カナ", &options, "ABC! kana/kana");
+		check("ウー ウウ", &options, "uu uu");
+		check("「あ」『い』", &options, "'a'\"i\"");
+		check("「」『』", &options, "''\"\"");
+		for key in TO_ROMAJI.keys() {
+			let result = to_romaji_with(key, &options).unwrap();
+			assert!(result.is_ascii(), "input `{}`: `{}`", key, result);
+		}
+		check(
+			"東京",
+			&ConversionOptions {
+				on_unknown: OnUnknown::ReplaceWith('_'),
+				..options
+			},
+			"__",
+		);
+		check(
+			"東京",
+			&ConversionOptions {
+				on_unknown: OnUnknown::ReplaceWith('〓'),
+				..options
+			},
+			"",
+		);
+		check(
+			"ビール とうきょう",
+			&ConversionOptions {
+				prolonged_mark: ProlongedMark::Macron,
+				collapse_long_vowels: true,
+				..options
+			},
+			"biiru tookyoo",
+		);
+
+		let options = ConversionOptions {
+			on_unknown: OnUnknown::Error,
+			..options
+		};
+		assert_eq!(
+			to_romaji_with("かな漢字", &options),
+			Err(ConversionError {
+				chr: '漢',
+				offset: 6
+			})
+		);
+	}
+
+	#[test]
+	fn test_enclosed_digits() {
+		let options = ConversionOptions {
+			expand_enclosed_digits: true,
+			..ConversionOptions::default()
+		};
+		assert_eq!(to_romaji("だい①しょう"), "dai①shou");
+		assert_eq!(
+			to_romaji_with("だい①しょう ⑴ ⒛", &options).unwrap(),
+			"dai1shou (1) 20."
+		);
+		let options = ConversionOptions {
+			ascii_only: true,
+			..ConversionOptions::default()
+		};
+		assert_eq!(to_romaji_with("⑳ばん", &options).unwrap(), "20ban");
+	}
+
+	#[test]
+	fn test_roman_numerals() {
+		assert_eq!(to_romaji("ファイナルファンタジーⅦ"), "fainarufantaji-VII");
+		assert_eq!(
+			to_romaji("ⅠⅡⅢⅣⅤⅥⅦⅧⅨⅩⅪⅫⅬⅭⅮⅯ"),
+			"IIIIIIIVVVIVIIVIIIIXXXIXIILCDM"
+		);
+		assert_eq!(
+			to_romaji("ⅰⅱⅲⅳⅴⅵⅶⅷⅸⅹⅺⅻⅼⅽⅾⅿ"),
+			"iiiiiiivvviviiviiiixxxixiilcdm"
+		);
+		assert_eq!(to_romaji("第Ⅱ章"), "第II章");
+	}
+
+	#[test]
+	fn test_ainu_katakana() {
+		assert_eq!(to_romaji("アイヌ イタㇰ"), "ainu itak");
+		assert_eq!(to_romaji("カㇺイ チャㇷ゚"), "kami chap");
+		assert_eq!(
+			to_romaji("ㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿ"),
+			"ksstnhhhhhmrrrrr"
+		);
+		assert_eq!(to_hiragana("イタㇰ チャㇷ゚"), "いたく ちゃぷ");
+		assert_eq!(
+			to_hiragana("ㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿ"),
+			"くしすとぬはひふへほむらりるれろ"
+		);
+		assert_eq!(to_katakana("イタㇰ チャㇷ゚"), "イタㇰ チャㇷ゚");
+	}
+
+	#[test]
+	fn test_grapheme_clusters() {
+		const VS: char = '\u{FE0F}';
+		const IVS: char = '\u{E0100}';
+
+		assert_eq!(to_romaji(format!("か{}き", VS)), format!("ka{}ki", VS));
+		assert_eq!(to_romaji(format!("っ{}た", VS)), format!("t{}ta", VS));
+		assert_eq!(to_romaji(format!("ッ{}", VS)), format!("'{}", VS));
+		assert_eq!(to_hiragana(format!("カ{}ー", VS)), format!("か{}ー", VS));
+		assert_eq!(
+			to_katakana(format!("は\u{309A}{}", VS)),
+			format!("パ{}", VS)
+		);
+		assert_eq!(to_katakana(format!("{}か", VS)), format!("{}カ", VS));
+		assert_eq!(to_hiragana("ka\u{301}"), "か\u{301}");
+
+		let input = format!("葛{}城", IVS);
+		let options = ConversionOptions {
+			on_unknown: OnUnknown::ReplaceWith('?'),
+			..ConversionOptions::default()
+		};
+		assert_eq!(to_romaji(&input), input);
+		assert_eq!(to_romaji_with(&input, &options).unwrap(), "??");
+		assert_eq!(
+			to_romaji_with(format!("か{}", VS), &options).unwrap(),
+			format!("ka{}", VS)
+		);
+
+		let options = ConversionOptions {
+			on_unknown: OnUnknown::Error,
+			..ConversionOptions::default()
+		};
+		assert_eq!(
+			to_romaji_with(&input, &options),
+			Err(ConversionError {
+				chr: '葛',
+				offset: 0,
+			})
+		);
+		assert!(to_hiragana_with(format!("ka{}", VS), &options).is_ok());
+
+		let options = ConversionOptions {
+			ascii_only: true,
+			..ConversionOptions::default()
+		};
+		assert_eq!(
+			to_romaji_with(format!("っ{}た e\u{301}", VS), &options).unwrap(),
+			"tta e"
+		);
+	}
+
+	#[test]
+	fn test_half_width() {
+		assert_eq!(to_hiragana("ﾗｰﾒﾝ"), "らーめん");
+		assert_eq!(to_katakana("ﾗｰﾒﾝ"), "ラーメン");
+		assert_eq!(to_romaji("ﾗｰﾒﾝ"), "ra-men");
+		assert_eq!(to_hiragana("ｧｨｩｪｫｬｭｮｯｦ"), "ぁぃぅぇぉゃゅょっを");
+		assert_eq!(to_romaji("ｷｬｯﾄ ｼｮｯｸ"), "kyatto shokku");
+		assert_eq!(to_romaji("ｷｮｳ ｲｯ ﾃｨｰ"), "kyou i' thi-");
+		assert_eq!(to_romaji("ﾁｮｯﾄ"), "chotto");
+		assert_eq!(to_romaji("ｱｰ"), to_romaji("アー"));
+
+		// Voiced and semi-voiced marks
+		assert_eq!(to_hiragana("ｶﾞｷﾞﾊﾟﾎﾟｳﾞ"), "がぎぱぽゔ");
+		assert_eq!(to_katakana("ｶﾞｷﾞﾊﾟﾎﾟｳﾞﾜﾞ"), "ガギパポヴヷ");
+		assert_eq!(to_romaji("ｼｮｯﾌﾟ ｷﾞｮｳｻﾞ ｳﾞｧ"), "shoppu gyouza va");
+		assert_eq!(to_hiragana("ｱﾞﾟﾞ"), "あﾞﾟﾞ");
+		assert_eq!(to_romaji("ｱﾞ"), "aﾞ");
+	}
+
+	#[test]
+	fn test_preserve_katakana() {
+		fn check(policy: PreserveKatakana, input: &str, expected: &str) {
+			let options = ConversionOptions {
+				preserve_katakana: policy,
+				..ConversionOptions::default()
+			};
+			let output = to_hiragana_with(input, &options).unwrap();
+			assert_eq!(output, expected, "input `{}` with {:?}", input, policy);
+		}
+
+		check(PreserveKatakana::Never, "霞ヶ関のケーキ", "霞ゖ関のけーき");
+		check(
+			PreserveKatakana::SmallKe,
+			"霞ヶ関のケーキ",
+			"霞ヶ関のけーき",
+		);
+		check(PreserveKatakana::SmallKe, "一ヵ月 ヶ", "一ヵ月 ヶ");
+		check(PreserveKatakana::SmallKe, "竹ノ塚", "竹の塚");
+		check(
+			PreserveKatakana::InKanjiCompounds,
+			"竹ノ塚のカレー、霞ヶ関",
+			"竹ノ塚のかれー、霞ヶ関",
+		);
+		check(
+			PreserveKatakana::InKanjiCompounds,
+			"日本アルプス山",
+			"日本アルプス山",
+		);
+		check(PreserveKatakana::InKanjiCompounds, "ノ塚 竹ノ", "の塚 竹の");
+		check(PreserveKatakana::InKanjiCompounds, "noノ", "のの");
+	}
+
+	#[test]
+	fn test_to_cow() {
+		fn is_borrowed(s: Cow<str>) -> bool {
+			match s {
+				Cow::Borrowed(_) => true,
+				Cow::Owned(_) => false,
+			}
+		}
+
+		assert!(is_borrowed(to_hiragana_cow("")));
+		assert!(is_borrowed(to_hiragana_cow("ひらがな、漢字")));
+		assert!(!is_borrowed(to_hiragana_cow("ひらがなカタカナ")));
+		assert_eq!(to_hiragana_cow("ひらがなカタカナ"), "ひらがなかたかな");
+		assert_eq!(to_hiragana_cow("ひらがな kana"), "ひらがな かな");
+
+		assert!(is_borrowed(to_romaji_cow("")));
+		assert!(is_borrowed(to_romaji_cow("romaji 123!")));
+		assert!(!is_borrowed(to_romaji_cow("romaji ローマ字")));
+		assert_eq!(to_romaji_cow("romaji ローマ字"), "romaji ro-ma字");
+		assert_eq!(to_romaji_cow("きって"), "kitte");
+	}
+
+	#[test]
+	fn test_to_into() {
+		use std::fmt::Write;
+
+		let mut out = String::from("> ");
+		to_hiragana_into("kana カナ", &mut out).unwrap();
+		write!(out, " / ").unwrap();
+		to_katakana_into("kana かな", &mut out).unwrap();
+		write!(out, " / ").unwrap();
+		to_romaji_into("かな カナ", &mut out).unwrap();
+		assert_eq!(out, "> かな かな / カナ カナ / kana kana");
+
+		struct Display;
+
+		impl std::fmt::Display for Display {
+			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+				to_romaji_into("ろーまじ", f)
+			}
+		}
+
+		assert_eq!(Display.to_string(), "ro-maji");
+	}
 }
@@ -0,0 +1,186 @@
+//! Splitting of text into word-like tokens at script transitions.
+
+use std::ops::Range;
+
+use super::kind::*;
+
+/// Kind of a [Token], based on the script of its characters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+	/// Kanji, including the kanji iteration marks (e.g. `々`), followed by
+	/// any hiragana okurigana (e.g. `食べる`).
+	Kanji,
+	/// Hiragana, including the iteration marks and prolonged sound mark.
+	Hiragana,
+	/// Full-width and halfwidth katakana, including the iteration marks and
+	/// prolonged sound mark.
+	Katakana,
+	/// ASCII and full-width roman letters, including romaji with long vowel
+	/// marks (e.g. `ō`).
+	Roman,
+	/// ASCII and full-width digits.
+	Number,
+	/// ASCII, full-width, Japanese and CJK punctuation.
+	Punctuation,
+	/// Whitespace, including the U+3000 Ideographic Space.
+	Space,
+	/// Any other character, such as symbols, emoji and Hangul.
+	Other,
+}
+
+/// A word-like token, returned by [tokenize].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+	/// Kind of the token.
+	pub kind: TokenKind,
+	/// Text of the token.
+	pub text: &'a str,
+	/// Byte range of the token in the input.
+	pub src: Range<usize>,
+}
+
+/// Returns an iterator over the tokens of the input, splitting it at the
+/// script transitions.
+///
+/// Each run of characters of the same [TokenKind] is a token, except that
+/// kanji keep the hiragana that follow them. This keeps okurigana together
+/// with their kanji (e.g. `食べる`), but also any particle following a
+/// kanji, since telling them apart needs a dictionary.
+///
+/// ```
+/// use kana::*;
+///
+/// let tokens = tokenize("猫がラーメンを食べた！ ok").collect::<Vec<_>>();
+/// let tokens = tokens.iter().map(|x| x.text).collect::<Vec<_>>();
+/// assert_eq!(tokens, vec!["猫が", "ラーメン", "を", "食べた", "！", " ", "ok"]);
+/// ```
+pub fn tokenize<'a>(input: &'a str) -> Tokens<'a> {
+	Tokens { input, offset: 0 }
+}
+
+/// Iterator returned by [tokenize].
+#[derive(Clone, Debug)]
+pub struct Tokens<'a> {
+	input: &'a str,
+	offset: usize,
+}
+
+/// Returns the token kind for a character, given the kind of the previous
+/// character in the same token, if any.
+fn token_kind(chr: char, last: Option<TokenKind>) -> TokenKind {
+	match get_kind(chr) {
+		CharKind::Kanji => TokenKind::Kanji,
+		CharKind::Hiragana => TokenKind::Hiragana,
+		CharKind::Katakana | CharKind::KatakanaHalfWidth => TokenKind::Katakana,
+		CharKind::BarLine => match last {
+			Some(TokenKind::Hiragana) => TokenKind::Hiragana,
+			_ => TokenKind::Katakana,
+		},
+		CharKind::JapaneseMark => match chr {
+			'々' | '〻' => TokenKind::Kanji,
+			'ゝ' | 'ゞ' => TokenKind::Hiragana,
+			'ヽ' | 'ヾ' | 'ﾞ' | 'ﾟ' => TokenKind::Katakana,
+			_ => last.unwrap_or(TokenKind::Other),
+		},
+		CharKind::RomanDigit => TokenKind::Number,
+		CharKind::Romaji if chr.is_ascii_digit() => TokenKind::Number,
+		CharKind::Romaji | CharKind::RomanLetter => TokenKind::Roman,
+		CharKind::PunctuationASCII
+		| CharKind::RomanPunctuation
+		| CharKind::JapanesePunctuation
+		| CharKind::CJKPunctuation => TokenKind::Punctuation,
+		CharKind::Space => TokenKind::Space,
+		_ => TokenKind::Other,
+	}
+}
+
+impl<'a> Iterator for Tokens<'a> {
+	type Item = Token<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.offset;
+		let mut chars = self.input[start..].chars();
+		let first = chars.next()?;
+		let kind = token_kind(first, None);
+		let mut last = kind;
+		let mut end = start + first.len_utf8();
+		for chr in chars {
+			let next = token_kind(chr, Some(last));
+			let okurigana = kind == TokenKind::Kanji && next == TokenKind::Hiragana;
+			let same = next == kind && !(kind == TokenKind::Kanji && last == TokenKind::Hiragana);
+			if !same && !okurigana {
+				break;
+			}
+			last = next;
+			end += chr.len_utf8();
+		}
+		self.offset = end;
+		Some(Token {
+			kind,
+			text: &self.input[start..end],
+			src: start..end,
+		})
+	}
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn tokens(input: &str) -> Vec<(TokenKind, &str)> {
+		tokenize(input)
+			.map(|x| {
+				assert_eq!(&input[x.src.clone()], x.text);
+				(x.kind, x.text)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_tokenize() {
+		use TokenKind::*;
+
+		assert!(tokens("").is_empty());
+		assert_eq!(
+			tokens("人々は食べ物をたべる"),
+			vec![(Kanji, "人々は"), (Kanji, "食べ"), (Kanji, "物をたべる")]
+		);
+		assert_eq!(
+			tokens("すーぱーとスーパーとｽｰﾊﾟｰ"),
+			vec![
+				(Hiragana, "すーぱーと"),
+				(Katakana, "スーパー"),
+				(Hiragana, "と"),
+				(Katakana, "ｽｰﾊﾟｰ"),
+			]
+		);
+		assert_eq!(
+			tokens("Tōkyō 2020年、ＡＢＣ１２３"),
+			vec![
+				(Roman, "Tōkyō"),
+				(Space, " "),
+				(Number, "2020"),
+				(Kanji, "年"),
+				(Punctuation, "、"),
+				(Roman, "ＡＢＣ"),
+				(Number, "１２３"),
+			]
+		);
+		assert_eq!(
+			tokens("「はい」。\u{3000}😀한"),
+			vec![
+				(Punctuation, "「"),
+				(Hiragana, "はい"),
+				(Punctuation, "」。"),
+				(Space, "\u{3000}"),
+				(Other, "😀한"),
+			]
+		);
+		assert_eq!(
+			tokens("ーゝヽ"),
+			vec![(Katakana, "ー"), (Hiragana, "ゝ"), (Katakana, "ヽ")]
+		);
+	}
+}
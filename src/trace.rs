@@ -0,0 +1,142 @@
+//! Step by step trace of the conversions.
+//!
+//! This is meant for debugging and teaching, showing how the input is split
+//! into chunks and how each chunk is converted.
+
+use std::fmt;
+use std::ops::Range;
+
+use super::chunks::*;
+
+/// A single conversion decision, returned by [trace_hiragana] and
+/// [trace_romaji].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep<'a> {
+	/// Chunk of the input converted by this step.
+	pub input: &'a str,
+	/// Byte range of the chunk in the input.
+	pub src: Range<usize>,
+	/// How the chunk was converted.
+	pub kind: ChunkKind,
+	/// Output for the chunk.
+	pub output: &'a str,
+}
+
+impl<'a> TraceStep<'a> {
+	/// Key of the conversion table entry matched by this step, if any.
+	pub fn table_key(&self) -> Option<&'static str> {
+		match self.kind {
+			ChunkKind::Table(key) => Some(key),
+			_ => None,
+		}
+	}
+}
+
+impl<'a> From<(&'a str, Chunk<'a>)> for TraceStep<'a> {
+	fn from((input, chunk): (&'a str, Chunk<'a>)) -> TraceStep<'a> {
+		TraceStep {
+			input: &input[chunk.src.clone()],
+			src: chunk.src,
+			kind: chunk.kind,
+			output: chunk.text,
+		}
+	}
+}
+
+impl<'a> fmt::Display for TraceStep<'a> {
+	/// Formats the step as `input -> output` followed by how it was
+	/// converted (e.g. `sha -> しゃ (table)`).
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} -> {}", self.input, self.output)?;
+		match self.kind {
+			ChunkKind::Table(_) => write!(f, " (table)"),
+			ChunkKind::Rule => write!(f, " (rule)"),
+			ChunkKind::Passthrough => write!(f, " (unchanged)"),
+		}
+	}
+}
+
+/// Returns the steps for the hiragana conversion of the input.
+///
+/// ```
+/// let steps = kana::trace_hiragana("shasshin");
+/// let steps = steps.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+/// assert_eq!(steps, vec![
+///     "sha -> しゃ (table)",
+///     "s -> っ (rule)",
+///     "shi -> し (table)",
+///     "n -> ん (table)",
+/// ]);
+/// ```
+pub fn trace_hiragana<'a>(input: &'a str) -> Vec<TraceStep<'a>> {
+	HiraganaChunks::new(input)
+		.map(|chunk| TraceStep::from((input, chunk)))
+		.collect()
+}
+
+/// Returns the steps for the romaji conversion of the input.
+pub fn trace_romaji<'a>(input: &'a str) -> Vec<TraceStep<'a>> {
+	RomajiChunks::new(input)
+		.map(|chunk| TraceStep::from((input, chunk)))
+		.collect()
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_trace() {
+		let steps = trace_hiragana("Kitte!");
+		assert_eq!(
+			steps,
+			vec![
+				TraceStep {
+					input: "Ki",
+					src: 0..2,
+					kind: ChunkKind::Table("Ki"),
+					output: "き",
+				},
+				TraceStep {
+					input: "t",
+					src: 2..3,
+					kind: ChunkKind::Rule,
+					output: "っ",
+				},
+				TraceStep {
+					input: "te",
+					src: 3..5,
+					kind: ChunkKind::Table("te"),
+					output: "て",
+				},
+				TraceStep {
+					input: "!",
+					src: 5..6,
+					kind: ChunkKind::Table("!"),
+					output: "！",
+				},
+			]
+		);
+		assert_eq!(steps[0].table_key(), Some("Ki"));
+		assert_eq!(steps[1].table_key(), None);
+
+		let steps = trace_romaji("がっこゝ漢")
+			.iter()
+			.map(|x| x.to_string())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			steps,
+			vec![
+				"が -> ga (table)",
+				"っ -> k (rule)",
+				"こ -> ko (table)",
+				"ゝ -> ko (rule)",
+				"漢 -> 漢 (unchanged)",
+			]
+		);
+
+		assert!(trace_romaji("").is_empty());
+	}
+}
@@ -0,0 +1,219 @@
+//! Suggestions for romaji typos that cannot be converted into kana.
+
+use std::ops::Range;
+
+use super::table::*;
+use super::to::to_hiragana;
+
+/// Maximum edit distance between a word and its suggestions.
+const MAX_DISTANCE: usize = 2;
+
+/// Maximum number of suggestions returned for each word.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Number of partial suggestions kept for each position of a word.
+const BEAM_SIZE: usize = 32;
+
+lazy_static! {
+	/// Romaji keys used to build the suggestions.
+	///
+	/// These are the lowercase keys of the [TO_HIRAGANA] table, without the
+	/// explicit small kana (e.g. `xa` and `ltu`), plus the double consonants
+	/// (e.g. `kka`).
+	static ref FIX_KEYS: Vec<String> = {
+		let mut keys = TO_HIRAGANA
+			.keys()
+			.filter(|key| key.chars().all(|chr| chr.is_ascii_lowercase()))
+			.filter(|key| !key.starts_with('x') && !key.starts_with('l'))
+			.map(|key| key.to_string())
+			.collect::<Vec<_>>();
+		let doubled = keys
+			.iter()
+			.filter(|key| key.len() > 1 && !key.starts_with(|chr| "aiueon".contains(chr)))
+			.map(|key| format!("{}{}", &key[..1], key))
+			.collect::<Vec<_>>();
+		keys.extend(doubled);
+		keys.sort();
+		keys
+	};
+}
+
+/// Suggested fixes for a romaji word, returned by [suggest_romaji_fixes].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RomajiFix {
+	/// Byte range of the word in the input.
+	pub src: Range<usize>,
+	/// Suggested romaji for the word, from the most to the least likely.
+	pub suggestions: Vec<String>,
+}
+
+/// Returns the edit distance between two strings, counting the
+/// transposition of two adjacent characters as a single edit.
+fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+	let mut rows = vec![(0..=b.len()).collect::<Vec<_>>()];
+	for i in 1..=a.len() {
+		let mut row = vec![i; b.len() + 1];
+		for j in 1..=b.len() {
+			let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+			row[j] = (rows[i - 1][j] + 1)
+				.min(row[j - 1] + 1)
+				.min(rows[i - 1][j - 1] + cost);
+			if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+				row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+			}
+		}
+		rows.push(row);
+	}
+	rows[a.len()][b.len()]
+}
+
+/// Returns the number of letters that are not shared by both strings,
+/// regardless of their order.
+fn letter_difference(a: &str, b: &str) -> usize {
+	let mut counts = [0isize; 26];
+	for chr in a.bytes() {
+		counts[(chr - b'a') as usize] += 1;
+	}
+	for chr in b.bytes() {
+		counts[(chr - b'a') as usize] -= 1;
+	}
+	counts.iter().map(|count| count.unsigned_abs()).sum()
+}
+
+/// Returns true if the romaji converts entirely into kana.
+fn is_valid_romaji(romaji: &str) -> bool {
+	!to_hiragana(romaji)
+		.chars()
+		.any(|chr| chr.is_ascii_alphabetic())
+}
+
+/// Returns the suggestions for a lowercase word, by splitting it into
+/// segments and replacing each segment with the nearest romaji keys.
+fn suggestions(word: &str) -> Vec<String> {
+	let max_key = FIX_KEYS.iter().map(|key| key.len()).max().unwrap_or(0);
+	let bytes = word.as_bytes();
+
+	// Best partial suggestions for the prefix of the word up to each
+	// position, with their distance.
+	let mut best: Vec<Vec<(usize, String)>> = vec![Vec::new(); bytes.len() + 1];
+	best[0].push((0, String::new()));
+	for end in 1..=bytes.len() {
+		let mut next = Vec::new();
+		for start in end.saturating_sub(max_key + MAX_DISTANCE)..end {
+			if best[start].is_empty() {
+				continue;
+			}
+			for key in FIX_KEYS.iter() {
+				let distance = edit_distance(&bytes[start..end], key.as_bytes());
+				for (prefix_distance, prefix) in best[start].iter() {
+					if prefix_distance + distance <= MAX_DISTANCE {
+						next.push((prefix_distance + distance, format!("{}{}", prefix, key)));
+					}
+				}
+			}
+		}
+		next.sort();
+		next.dedup_by(|a, b| a.1 == b.1);
+		next.truncate(BEAM_SIZE);
+		best[end] = next;
+	}
+
+	let mut found = std::mem::take(&mut best[bytes.len()])
+		.into_iter()
+		.filter(|(_, romaji)| is_valid_romaji(romaji))
+		.map(|(distance, romaji)| {
+			let len = (romaji.len() as isize - word.len() as isize).unsigned_abs();
+			(distance, letter_difference(word, &romaji), len, romaji)
+		})
+		.collect::<Vec<_>>();
+	found.sort();
+
+	let mut out: Vec<String> = Vec::new();
+	for (.., romaji) in found {
+		if !out.contains(&romaji) {
+			out.push(romaji);
+		}
+	}
+	out.truncate(MAX_SUGGESTIONS);
+	out
+}
+
+/// Returns suggested fixes for the romaji words in the input that cannot be
+/// entirely converted into kana (e.g. `tusnami` for `tsunami`).
+///
+/// Words are the runs of ASCII letters. The suggestions for each word are
+/// the concatenations of romaji table keys nearest to the word, up to an
+/// edit distance of 2, with the transposition of two letters counting as a
+/// single edit. Suggestions with the same distance are ranked first by how
+/// many of the typed letters they keep, so swapped letters are preferred.
+///
+/// Suggestions are always lowercase. Words without any suggestion are not
+/// returned.
+///
+/// ```
+/// let fixes = kana::suggest_romaji_fixes("tusnami ga kita");
+/// assert_eq!(fixes.len(), 1);
+/// assert_eq!(fixes[0].src, 0..7);
+/// assert_eq!(fixes[0].suggestions[0], "tsunami");
+/// ```
+pub fn suggest_romaji_fixes(input: &str) -> Vec<RomajiFix> {
+	let mut fixes = Vec::new();
+	let mut start = None;
+	for (offset, chr) in input.char_indices().chain(Some((input.len(), ' '))) {
+		match (start, chr.is_ascii_alphabetic()) {
+			(None, true) => start = Some(offset),
+			(Some(word_start), false) => {
+				start = None;
+				let word = input[word_start..offset].to_ascii_lowercase();
+				if is_valid_romaji(&word) {
+					continue;
+				}
+				let suggestions = suggestions(&word);
+				if !suggestions.is_empty() {
+					fixes.push(RomajiFix {
+						src: word_start..offset,
+						suggestions,
+					});
+				}
+			}
+			_ => {}
+		}
+	}
+	fixes
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_suggest_romaji_fixes() {
+		assert!(suggest_romaji_fixes("").is_empty());
+		assert!(suggest_romaji_fixes("tsunami ga kita, 123").is_empty());
+		assert!(suggest_romaji_fixes("kitte gakkou").is_empty());
+
+		let fixes = suggest_romaji_fixes("Tusnami to jishnin!");
+		assert_eq!(fixes.len(), 2);
+		assert_eq!(fixes[0].src, 0..7);
+		assert_eq!(fixes[0].suggestions[0], "tsunami");
+		assert_eq!(fixes[1].src, 11..18);
+		assert!(fixes[1].suggestions.contains(&"jishin".to_string()));
+
+		let fixes = suggest_romaji_fixes("shcool");
+		assert_eq!(fixes.len(), 1);
+		assert_eq!(fixes[0].suggestions.len(), MAX_SUGGESTIONS);
+		for romaji in fixes[0].suggestions.iter() {
+			assert!(is_valid_romaji(romaji), "{}", romaji);
+			assert!(edit_distance(b"shcool", romaji.as_bytes()) <= MAX_DISTANCE);
+		}
+
+		// Too far from any valid romaji
+		assert!(suggest_romaji_fixes("bcdfgh").is_empty());
+
+		assert_eq!(edit_distance(b"", b"abc"), 3);
+		assert_eq!(edit_distance(b"tusnami", b"tsunami"), 1);
+		assert_eq!(edit_distance(b"kitten", b"sitting"), 3);
+	}
+}
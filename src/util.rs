@@ -14,6 +14,16 @@ pub fn get_prefix(s: &str, n: usize) -> &str {
 	&s[..end]
 }
 
+/// Returns the length in bytes of the common prefix between both strings.
+#[inline]
+pub fn common_prefix_len(a: &str, b: &str) -> usize {
+	a.char_indices()
+		.zip(b.chars())
+		.find(|&((_, x), y)| x != y)
+		.map(|((pos, _), _)| pos)
+		.unwrap_or(std::cmp::min(a.len(), b.len()))
+}
+
 /// Returns true if the character is a Romaji consonant.
 #[inline]
 pub fn is_consonant(c: char, include_y: bool) -> bool {
@@ -49,6 +59,275 @@ pub fn hiragana_to_katakana(c: char) -> char {
 	}
 }
 
+/// Simple conversion of Katakana to Hiragana. Unknown characters just pass
+/// through.
+#[inline]
+pub fn katakana_to_hiragana(c: char) -> char {
+	use super::constants::*;
+
+	if char_in_range(c, KATAKANA_START, KATAKANA_TO_HIRAGANA_END) {
+		let code = (c as u32) - KATAKANA_TO_HIRAGANA_OFFSET_SUB;
+		unsafe { std::char::from_u32_unchecked(code) }
+	} else {
+		match c {
+			'ヽ' => 'ゝ',
+			'ヾ' => 'ゞ',
+			_ => c,
+		}
+	}
+}
+
+/// Converts a Halfwidth Katakana to the full-width Katakana, returning `None`
+/// for other characters.
+///
+/// The halfwidth prolonged sound mark `ｰ` is converted to `ー`.
+#[inline]
+pub fn half_width_to_katakana(c: char) -> Option<&'static str> {
+	use super::constants::*;
+
+	if char_in_range(c, HALF_WIDTH_KATAKANA_START, HALF_WIDTH_KATAKANA_END) {
+		let index = (c as u32 - HALF_WIDTH_KATAKANA_START) as usize * KANA_CHAR_LEN;
+		Some(&HALF_WIDTH_TO_KATAKANA[index..index + KANA_CHAR_LEN])
+	} else {
+		None
+	}
+}
+
+/// Converts a small katakana used in Ainu (e.g. `ㇰ`) to the full size
+/// hiragana, since there are no small hiragana for those, returning `None`
+/// for other characters.
+///
+/// The small `ㇷ` followed by the combining semi-voiced mark is `ぷ`, which is
+/// returned with the length of the input used.
+pub fn ainu_katakana_to_hiragana(src: &str) -> Option<(&'static str, usize)> {
+	use super::constants::*;
+
+	let mut chars = src.chars();
+	let c = chars.next()?;
+	if !char_in_range(c, AINU_KATAKANA_START, AINU_KATAKANA_END) {
+		return None;
+	}
+	if c == 'ㇷ' && chars.next() == Some(COMBINING_SEMI_VOICED_MARK) {
+		return Some(("ぷ", c.len_utf8() + COMBINING_SEMI_VOICED_MARK.len_utf8()));
+	}
+	let index = (c as u32 - AINU_KATAKANA_START) as usize * KANA_CHAR_LEN;
+	Some((
+		&AINU_KATAKANA_TO_HIRAGANA[index..index + KANA_CHAR_LEN],
+		c.len_utf8(),
+	))
+}
+
+/// Full-width Katakana that are written in halfwidth with a voiced mark, in
+/// the same order as `HALF_WIDTH_VOICED`.
+const HALF_WIDTH_VOICED_BASE: &str = "カキクケコサシスセソタチツテトハヒフヘホウワヲ";
+
+/// Voiced Katakana for each of `HALF_WIDTH_VOICED_BASE`.
+const HALF_WIDTH_VOICED: &str = "ガギグゲゴザジズゼゾダヂヅデドバビブベボヴヷヺ";
+
+/// Full-width Katakana that are written in halfwidth with a semi-voiced mark,
+/// in the same order as `HALF_WIDTH_SEMI_VOICED`.
+const HALF_WIDTH_SEMI_VOICED_BASE: &str = "ハヒフヘホ";
+
+/// Semi-voiced Katakana for each of `HALF_WIDTH_SEMI_VOICED_BASE`.
+const HALF_WIDTH_SEMI_VOICED: &str = "パピプペポ";
+
+/// Halfwidth voiced sound mark.
+pub const HALF_WIDTH_VOICED_MARK: char = 'ﾞ';
+
+/// Halfwidth semi-voiced sound mark.
+pub const HALF_WIDTH_SEMI_VOICED_MARK: char = 'ﾟ';
+
+/// Converts a Halfwidth Katakana followed by a halfwidth voiced or semi-voiced
+/// mark (e.g. `ｶﾞ` or `ﾊﾟ`) to the full-width Katakana.
+///
+/// Returns `None` if the pair cannot be combined.
+pub fn half_width_voiced_to_katakana(c: char, mark: char) -> Option<&'static str> {
+	use super::constants::*;
+
+	let base = half_width_to_katakana(c)?;
+	let (bases, voiced) = match mark {
+		HALF_WIDTH_VOICED_MARK => (HALF_WIDTH_VOICED_BASE, HALF_WIDTH_VOICED),
+		HALF_WIDTH_SEMI_VOICED_MARK => (HALF_WIDTH_SEMI_VOICED_BASE, HALF_WIDTH_SEMI_VOICED),
+		_ => return None,
+	};
+	let index = bases.find(base)?;
+	Some(&voiced[index..index + KANA_CHAR_LEN])
+}
+
+/// Converts a full-width Katakana to the Halfwidth Katakana, returning `None`
+/// if there is no single halfwidth character for it.
+pub fn katakana_to_half_width(c: char) -> Option<char> {
+	use super::constants::*;
+
+	let index = HALF_WIDTH_TO_KATAKANA.chars().position(|x| x == c)?;
+	std::char::from_u32(HALF_WIDTH_KATAKANA_START + index as u32)
+}
+
+/// Appends the Halfwidth Katakana for the full-width Katakana to the output,
+/// including the voiced and semi-voiced marks (e.g. `ｶﾞ` for `ガ`).
+///
+/// Returns false, without changing the output, if there is no halfwidth
+/// version of the character.
+pub fn push_half_width_katakana(out: &mut String, c: char) -> bool {
+	if let Some(half) = katakana_to_half_width(c) {
+		out.push(half);
+		return true;
+	}
+	let pairs = [
+		(
+			HALF_WIDTH_VOICED,
+			HALF_WIDTH_VOICED_BASE,
+			HALF_WIDTH_VOICED_MARK,
+		),
+		(
+			HALF_WIDTH_SEMI_VOICED,
+			HALF_WIDTH_SEMI_VOICED_BASE,
+			HALF_WIDTH_SEMI_VOICED_MARK,
+		),
+	];
+	for (voiced, bases, mark) in pairs.iter() {
+		if let Some(index) = voiced.chars().position(|x| x == c) {
+			let base = bases.chars().nth(index).unwrap();
+			out.push(katakana_to_half_width(base).unwrap());
+			out.push(*mark);
+			return true;
+		}
+	}
+	false
+}
+
+/// Combining voiced sound mark (dakuten), as found in NFD normalized text.
+pub const COMBINING_VOICED_MARK: char = '\u{3099}';
+
+/// Combining semi-voiced sound mark (handakuten), as found in NFD normalized
+/// text.
+pub const COMBINING_SEMI_VOICED_MARK: char = '\u{309A}';
+
+/// Kana that can be combined with the voiced mark, in the same order as
+/// `VOICED_KANA`.
+const VOICED_KANA_BASE: &str = concat!(
+	"かきくけこさしすせそたちつてとはひふへほうゝ",
+	"カキクケコサシスセソタチツテトハヒフヘホウワヰヱヲヽ",
+);
+
+/// Voiced kana for each of `VOICED_KANA_BASE`.
+const VOICED_KANA: &str = concat!(
+	"がぎぐげござじずぜぞだぢづでどばびぶべぼゔゞ",
+	"ガギグゲゴザジズゼゾダヂヅデドバビブベボヴヷヸヹヺヾ",
+);
+
+/// Kana that can be combined with the semi-voiced mark, in the same order as
+/// `SEMI_VOICED_KANA`.
+const SEMI_VOICED_KANA_BASE: &str = "はひふへほハヒフヘホ";
+
+/// Semi-voiced kana for each of `SEMI_VOICED_KANA_BASE`.
+const SEMI_VOICED_KANA: &str = "ぱぴぷぺぽパピプペポ";
+
+/// Composes a kana with a combining voiced or semi-voiced mark (e.g. `か` and
+/// U+3099 to `が`), returning `None` if those cannot be combined.
+pub fn compose_voiced(c: char, mark: char) -> Option<&'static str> {
+	use super::constants::*;
+
+	let (bases, voiced) = match mark {
+		COMBINING_VOICED_MARK => (VOICED_KANA_BASE, VOICED_KANA),
+		COMBINING_SEMI_VOICED_MARK => (SEMI_VOICED_KANA_BASE, SEMI_VOICED_KANA),
+		_ => return None,
+	};
+	let index = bases.find(c)?;
+	Some(&voiced[index..index + KANA_CHAR_LEN])
+}
+
+/// Decomposes a voiced or semi-voiced kana into the base kana and the
+/// combining mark (e.g. `が` to `か` and U+3099).
+pub fn decompose_voiced(c: char) -> Option<(char, char)> {
+	let pairs = [
+		(VOICED_KANA, VOICED_KANA_BASE, COMBINING_VOICED_MARK),
+		(
+			SEMI_VOICED_KANA,
+			SEMI_VOICED_KANA_BASE,
+			COMBINING_SEMI_VOICED_MARK,
+		),
+	];
+	for (voiced, bases, mark) in pairs.iter() {
+		if let Some(index) = voiced.chars().position(|x| x == c) {
+			return Some((bases.chars().nth(index).unwrap(), *mark));
+		}
+	}
+	None
+}
+
+/// Returns true for the characters that extend the preceding grapheme
+/// cluster without being a character on their own: combining marks, variation
+/// selectors, zero width joiners, emoji modifiers and tags.
+pub fn is_grapheme_extend(c: char) -> bool {
+	matches!(
+		c,
+		'\u{0300}'..='\u{036F}'
+			| '\u{1AB0}'..='\u{1AFF}'
+			| '\u{1DC0}'..='\u{1DFF}'
+			| '\u{20D0}'..='\u{20FF}'
+			| '\u{FE20}'..='\u{FE2F}'
+			| '\u{3099}'
+			| '\u{309A}'
+			| '\u{200C}'
+			| '\u{200D}'
+			| '\u{FE00}'..='\u{FE0F}'
+			| '\u{1F3FB}'..='\u{1F3FF}'
+			| '\u{E0020}'..='\u{E007F}'
+			| '\u{E0100}'..='\u{E01EF}'
+	)
+}
+
+/// Returns the length in bytes of the grapheme extenders at the start of the
+/// input.
+pub fn grapheme_extend_len(src: &str) -> usize {
+	src.find(|c| !is_grapheme_extend(c)).unwrap_or(src.len())
+}
+
+/// Returns true if the kana has a voiced or semi-voiced counterpart (e.g. `か`
+/// and `は`).
+pub fn has_voiced_kana(c: char) -> bool {
+	VOICED_KANA_BASE.contains(c) || SEMI_VOICED_KANA_BASE.contains(c)
+}
+
+/// Appends the plain digits for a circled (e.g. `①` to `1`), parenthesized
+/// (e.g. `⑴` to `(1)`) or full stop digit (e.g. `⒈` to `1.`), returning false
+/// for other characters.
+pub fn push_enclosed_digit(out: &mut String, c: char) -> bool {
+	let code = c as u32;
+	match code {
+		0x2460..=0x2473 => out.push_str(&(code - 0x245F).to_string()),
+		0x2474..=0x2487 => out.push_str(&format!("({})", code - 0x2473)),
+		0x2488..=0x249B => out.push_str(&format!("{}.", code - 0x2487)),
+		_ => return false,
+	}
+	true
+}
+
+/// Returns the lower case romaji vowel with a macron (e.g. `ō` for `o`).
+pub fn vowel_to_macron(c: char) -> Option<char> {
+	match c {
+		'a' => Some('ā'),
+		'i' => Some('ī'),
+		'u' => Some('ū'),
+		'e' => Some('ē'),
+		'o' => Some('ō'),
+		_ => None,
+	}
+}
+
+/// Returns the lower case romaji vowel without the macron (e.g. `o` for `ō`).
+pub fn macron_to_vowel(c: char) -> Option<char> {
+	match c {
+		'ā' => Some('a'),
+		'ī' => Some('i'),
+		'ū' => Some('u'),
+		'ē' => Some('e'),
+		'ō' => Some('o'),
+		_ => None,
+	}
+}
+
 /// Converts a romaji syllable to the voiced equivalent.
 pub fn romaji_to_voiced(input: &str) -> &'static str {
 	match input {
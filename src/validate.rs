@@ -0,0 +1,369 @@
+//! Validation of constrained kana fields.
+
+use std::fmt;
+
+use super::kind::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Kind of kana field for validation with [validate_kana_field].
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KanaField {
+	/// Modern hiragana only. This excludes the archaic characters (`ゐ`, `ゑ`,
+	/// `ゟ`), the hiragana not used in modern orthography (`ゔ`, `ゕ`, `ゖ`),
+	/// iteration marks and the prolonged sound mark.
+	ModernHiragana,
+
+	/// Full-width katakana and the prolonged sound mark `ー` only. This is the
+	/// common restriction for furigana form fields.
+	KatakanaWithLongMark,
+
+	/// Small kana only (e.g. `ぁ`, `ゃ`, `ッ`, `ㇰ`).
+	SmallKana,
+
+	/// Historic kana only (e.g. `ゐ`, `ゑ`, `ヰ`, `ヱ`, `ヷ`, `ゟ`, `ヿ`).
+	HistoricKana,
+}
+
+impl KanaField {
+	/// Returns true if the character is valid for the field.
+	pub fn accepts(self, chr: char) -> bool {
+		match self {
+			KanaField::ModernHiragana => {
+				// The hiragana range up to `ん`, minus the archaic `ゐ` and `ゑ`
+				matches!(chr, 'ぁ'..='ん') && chr != 'ゐ' && chr != 'ゑ'
+			}
+			KanaField::KatakanaWithLongMark => matches!(chr, 'ー' | katakana_range!()),
+			KanaField::SmallKana => matches!(
+				chr,
+				'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'っ' | 'ゃ' | 'ゅ' | 'ょ' | 'ゎ' | 'ゕ' | 'ゖ'
+					| 'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ' | 'ッ' | 'ャ' | 'ュ' | 'ョ' | 'ヮ' | 'ヵ' | 'ヶ'
+					| 'ｧ'..='ｯ' | '\u{31F0}'..='\u{31FF}'
+			),
+			KanaField::HistoricKana => matches!(
+				chr,
+				'ゐ' | 'ゑ' | 'ヰ' | 'ヱ' | 'ヷ' | 'ヸ' | 'ヹ' | 'ヺ' | 'ゟ' | 'ヿ' | '𛀁'
+			),
+		}
+	}
+}
+
+/// Error returned by [validate_kana_field] with the first invalid character
+/// in the input.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InvalidChar {
+	/// The invalid character.
+	pub chr: char,
+	/// Byte offset of the character in the input.
+	pub offset: usize,
+	/// Offset of the character in the input counted in characters.
+	pub index: usize,
+}
+
+impl fmt::Display for InvalidChar {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"invalid character `{}` (U+{:04X}) at position {}",
+			self.chr, self.chr as u32, self.index
+		)
+	}
+}
+
+impl std::error::Error for InvalidChar {}
+
+/// Validates that all characters in the input are valid for the given kind
+/// of field, returning the first invalid character otherwise.
+///
+/// An empty input is always valid.
+pub fn validate_kana_field<S: AsRef<str>>(input: S, field: KanaField) -> Result<(), InvalidChar> {
+	for (index, (offset, chr)) in input.as_ref().char_indices().enumerate() {
+		if !field.accepts(chr) {
+			return Err(InvalidChar { chr, offset, index });
+		}
+	}
+	Ok(())
+}
+
+/// Suggested fix for a [FieldError].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FieldSuggestion {
+	/// Convert the hiragana to katakana (e.g. with
+	/// [to_katakana](fn.to_katakana.html)).
+	ConvertFromHiragana,
+	/// Convert the katakana to hiragana (e.g. with
+	/// [to_hiragana](fn.to_hiragana.html)).
+	ConvertFromKatakana,
+	/// Convert the halfwidth katakana to full-width (e.g. with
+	/// [to_katakana](fn.to_katakana.html)).
+	ConvertFromHalfWidth,
+	/// Convert the romaji to kana.
+	ConvertFromRomaji,
+	/// Enter the reading of the kanji in kana.
+	EnterReading,
+	/// Remove the character.
+	Remove,
+}
+
+impl fmt::Display for FieldSuggestion {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let text = match self {
+			FieldSuggestion::ConvertFromHiragana => "convert from hiragana to katakana",
+			FieldSuggestion::ConvertFromKatakana => "convert from katakana to hiragana",
+			FieldSuggestion::ConvertFromHalfWidth => {
+				"convert from halfwidth to full-width katakana"
+			}
+			FieldSuggestion::ConvertFromRomaji => "convert from romaji to kana",
+			FieldSuggestion::EnterReading => "enter the reading in kana",
+			FieldSuggestion::Remove => "remove the character",
+		};
+		f.write_str(text)
+	}
+}
+
+/// Error returned by the field validators such as [validate_katakana_name],
+/// with the first invalid character and a suggested fix.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FieldError {
+	/// The invalid character, with its position in the input.
+	pub invalid: InvalidChar,
+	/// Kind of the invalid character.
+	pub kind: CharKind,
+	/// Suggested fix for the input.
+	pub suggestion: FieldSuggestion,
+}
+
+impl fmt::Display for FieldError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} ({})", self.invalid, self.suggestion)
+	}
+}
+
+impl std::error::Error for FieldError {}
+
+/// Script expected by a field validator, used for the suggestions.
+#[derive(Copy, Clone, PartialEq)]
+enum FieldScript {
+	Hiragana,
+	Katakana,
+	Kana,
+}
+
+/// Validates the input with the function, returning the first character it
+/// does not accept with a suggestion for the expected script.
+fn validate_field<F: Fn(char) -> bool>(
+	input: &str,
+	script: FieldScript,
+	accepts: F,
+) -> Result<(), FieldError> {
+	for (index, (offset, chr)) in input.char_indices().enumerate() {
+		if accepts(chr) {
+			continue;
+		}
+		let kind = get_kind(chr);
+		let suggestion = match kind {
+			CharKind::Hiragana if script == FieldScript::Katakana => {
+				FieldSuggestion::ConvertFromHiragana
+			}
+			CharKind::Katakana if script == FieldScript::Hiragana => {
+				FieldSuggestion::ConvertFromKatakana
+			}
+			CharKind::KatakanaHalfWidth => FieldSuggestion::ConvertFromHalfWidth,
+			CharKind::BarLine if chr == 'ｰ' => FieldSuggestion::ConvertFromHalfWidth,
+			CharKind::Romaji | CharKind::RomanLetter if chr.is_alphabetic() => {
+				FieldSuggestion::ConvertFromRomaji
+			}
+			CharKind::Kanji => FieldSuggestion::EnterReading,
+			_ => FieldSuggestion::Remove,
+		};
+		return Err(FieldError {
+			invalid: InvalidChar { chr, offset, index },
+			kind,
+			suggestion,
+		});
+	}
+	Ok(())
+}
+
+/// Validates a name written in full-width katakana, as commonly required for
+/// the reading of a name in forms (e.g. `ヤマダ　タロウ`).
+///
+/// This accepts the [KatakanaWithLongMark](KanaField::KatakanaWithLongMark)
+/// characters, the middle dot `・` used in foreign names and spaces
+/// separating the family and given names.
+///
+/// ```
+/// use kana::*;
+///
+/// assert!(validate_katakana_name("ヤマダ　タロウ").is_ok());
+/// assert!(validate_katakana_name("ジョン・スミス").is_ok());
+///
+/// let err = validate_katakana_name("ヤマだ").unwrap_err();
+/// assert_eq!(err.invalid.offset, 6);
+/// assert_eq!(err.kind, CharKind::Hiragana);
+/// assert_eq!(err.suggestion, FieldSuggestion::ConvertFromHiragana);
+/// ```
+pub fn validate_katakana_name<S: AsRef<str>>(input: S) -> Result<(), FieldError> {
+	validate_field(input.as_ref(), FieldScript::Katakana, |chr| {
+		KanaField::KatakanaWithLongMark.accepts(chr) || matches!(chr, '・' | ' ' | '\u{3000}')
+	})
+}
+
+/// Validates that the input has only the
+/// [ModernHiragana](KanaField::ModernHiragana) characters.
+///
+/// ```
+/// use kana::*;
+///
+/// assert!(validate_hiragana_only("やまだ").is_ok());
+///
+/// let err = validate_hiragana_only("やまダ").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid character `ダ` (U+30C0) at position 2 (convert from katakana to hiragana)"
+/// );
+/// ```
+pub fn validate_hiragana_only<S: AsRef<str>>(input: S) -> Result<(), FieldError> {
+	validate_field(input.as_ref(), FieldScript::Hiragana, |chr| {
+		KanaField::ModernHiragana.accepts(chr)
+	})
+}
+
+/// Validates a furigana field, with the reading of a word in either
+/// hiragana or full-width katakana, including the prolonged sound mark.
+///
+/// ```
+/// use kana::*;
+///
+/// assert!(validate_furigana_field("とうきょう").is_ok());
+/// assert!(validate_furigana_field("トーキョー").is_ok());
+///
+/// let err = validate_furigana_field("東京").unwrap_err();
+/// assert_eq!(err.suggestion, FieldSuggestion::EnterReading);
+/// ```
+pub fn validate_furigana_field<S: AsRef<str>>(input: S) -> Result<(), FieldError> {
+	validate_field(input.as_ref(), FieldScript::Kana, |chr| {
+		KanaField::ModernHiragana.accepts(chr) || KanaField::KatakanaWithLongMark.accepts(chr)
+	})
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_kana_field() {
+		fn check(input: &str, field: KanaField, invalid: Option<(char, usize, usize)>) {
+			let expected = match invalid {
+				Some((chr, offset, index)) => Err(InvalidChar { chr, offset, index }),
+				None => Ok(()),
+			};
+			assert_eq!(
+				validate_kana_field(input, field),
+				expected,
+				"input `{}` as {:?}",
+				input,
+				field
+			);
+		}
+
+		check("", KanaField::ModernHiragana, None);
+		check("やまだ", KanaField::ModernHiragana, None);
+		check(
+			"やまだ たろう",
+			KanaField::ModernHiragana,
+			Some((' ', 9, 3)),
+		);
+		check("ゐど", KanaField::ModernHiragana, Some(('ゐ', 0, 0)));
+		check("らーめん", KanaField::ModernHiragana, Some(('ー', 3, 1)));
+		check("ヤマダ", KanaField::ModernHiragana, Some(('ヤ', 0, 0)));
+		check("ぁっゃん", KanaField::ModernHiragana, None);
+		check("ゔぁ", KanaField::ModernHiragana, Some(('ゔ', 0, 0)));
+		check("いっゕげつ", KanaField::ModernHiragana, Some(('ゕ', 6, 2)));
+		check("ゖ", KanaField::ModernHiragana, Some(('ゖ', 0, 0)));
+
+		check("ラーメン", KanaField::KatakanaWithLongMark, None);
+		check(
+			"ヤマダ・タロウ",
+			KanaField::KatakanaWithLongMark,
+			Some(('・', 9, 3)),
+		);
+		check("ﾔﾏﾀﾞ", KanaField::KatakanaWithLongMark, Some(('ﾔ', 0, 0)));
+		check(
+			"やまだ",
+			KanaField::KatakanaWithLongMark,
+			Some(('や', 0, 0)),
+		);
+
+		check("ぁゃッｯㇰ", KanaField::SmallKana, None);
+		check("ぁあ", KanaField::SmallKana, Some(('あ', 3, 1)));
+
+		check("ゐゑヰヱヷゟヿ", KanaField::HistoricKana, None);
+		check("ゐい", KanaField::HistoricKana, Some(('い', 3, 1)));
+
+		let err = validate_kana_field("かなa", KanaField::ModernHiragana).unwrap_err();
+		assert_eq!(
+			err.to_string(),
+			"invalid character `a` (U+0061) at position 2"
+		);
+	}
+
+	#[test]
+	fn test_field_validators() {
+		fn check(
+			result: Result<(), FieldError>,
+			expected: Result<(), (usize, CharKind, FieldSuggestion)>,
+		) {
+			let result = result.map_err(|err| (err.invalid.index, err.kind, err.suggestion));
+			assert_eq!(result, expected);
+		}
+
+		use CharKind::*;
+		use FieldSuggestion::*;
+
+		check(validate_katakana_name(""), Ok(()));
+		check(validate_katakana_name("ヤマダ タロウ"), Ok(()));
+		check(
+			validate_katakana_name("ﾔﾏﾀﾞ"),
+			Err((0, KatakanaHalfWidth, ConvertFromHalfWidth)),
+		);
+		check(
+			validate_katakana_name("ラｰメン"),
+			Err((1, BarLine, ConvertFromHalfWidth)),
+		);
+		check(
+			validate_katakana_name("Yamada"),
+			Err((0, Romaji, ConvertFromRomaji)),
+		);
+		check(
+			validate_katakana_name("ヤマダ１"),
+			Err((3, RomanDigit, Remove)),
+		);
+
+		check(validate_hiragana_only("やまだ"), Ok(()));
+		check(
+			validate_hiragana_only("やまだ　たろう"),
+			Err((3, Space, Remove)),
+		);
+		check(
+			validate_hiragana_only("山だ"),
+			Err((0, Kanji, EnterReading)),
+		);
+		check(
+			validate_hiragana_only("ｙａｍａ"),
+			Err((0, RomanLetter, ConvertFromRomaji)),
+		);
+
+		check(validate_furigana_field("らーめん"), Ok(()));
+		check(validate_furigana_field("ラーメン"), Ok(()));
+		check(
+			validate_furigana_field("らーめん屋"),
+			Err((4, Kanji, EnterReading)),
+		);
+		check(validate_furigana_field("ゐど"), Err((0, Hiragana, Remove)));
+	}
+}
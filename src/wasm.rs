@@ -33,6 +33,11 @@ pub fn to_katakana(input: String) -> String {
 	super::to_katakana(input)
 }
 
+#[wasm_bindgen]
+pub fn to_katakana_half_width(input: String) -> String {
+	super::to_katakana_half_width(input)
+}
+
 #[wasm_bindgen]
 pub fn to_hiragana(input: String) -> String {
 	super::to_hiragana(input)
@@ -77,3 +82,110 @@ pub fn is_japanese_mark(input: String) -> bool {
 pub fn is_japanese_punctuation(input: String) -> bool {
 	input.chars().all(|c| super::is_japanese_punctuation(c))
 }
+
+#[wasm_bindgen]
+pub fn is_japanese_symbol(input: String) -> bool {
+	input.chars().all(|c| super::is_japanese_symbol(c))
+}
+
+/// Returns the UTF-16 offset of the first character in the input that is not
+/// valid for the field, or `undefined` if the input is valid.
+#[wasm_bindgen]
+pub fn validate_kana_field(input: String, field: super::KanaField) -> Option<u32> {
+	match super::validate_kana_field(&input, field) {
+		Ok(_) => None,
+		Err(err) => Some(input[..err.offset].encode_utf16().count() as u32),
+	}
+}
+
+/// Incremental conversion for a text input, meant to be updated on every
+/// change of the input (e.g. from an `onChange` handler).
+///
+/// Each update returns only the changed suffix of the output, which replaces
+/// the output after `unchanged_length()`.
+#[wasm_bindgen]
+pub struct ConversionSession {
+	inner: super::ConversionSession,
+	unchanged: u32,
+}
+
+#[wasm_bindgen]
+impl ConversionSession {
+	#[wasm_bindgen(constructor)]
+	pub fn new(target: super::ConversionTarget) -> ConversionSession {
+		ConversionSession {
+			inner: super::ConversionSession::new(target),
+			unchanged: 0,
+		}
+	}
+
+	/// Updates the input and returns the output after `unchanged_length()`.
+	pub fn update(&mut self, input: String) -> String {
+		let changed = self.inner.update(input);
+		let output = self.inner.output();
+		self.unchanged = output[..changed].encode_utf16().count() as u32;
+		output[changed..].to_string()
+	}
+
+	/// Length in UTF-16 code units of the output prefix that was not changed by
+	/// the last update.
+	pub fn unchanged_length(&self) -> u32 {
+		self.unchanged
+	}
+
+	/// Full output for the current input.
+	pub fn output(&self) -> String {
+		self.inner.output().to_string()
+	}
+}
+
+/// Returns the annotation for each character of the text as a JSON array,
+/// with the character kind, its suggested conversion and a bit mask of the
+/// `KanaField` that accept it. Offsets are in UTF-16 code units.
+///
+/// This is meant to be called on every edit, with a single `JSON.parse` of
+/// the result on the JavaScript side.
+#[wasm_bindgen]
+pub fn annotate(text: String) -> String {
+	let annotations = super::annotate(&text);
+	super::annotations_to_json(&text, &annotations)
+}
+
+#[wasm_bindgen]
+pub fn export_tables(format: super::TableFormat) -> String {
+	super::export_tables(format)
+}
+
+/// Returns the spans for the romaji conversion of the input as a flat array
+/// of UTF-16 offsets, with four offsets per span: the start and end in the
+/// input followed by the start and end in the output.
+#[wasm_bindgen]
+pub fn to_romaji_spans(input: String) -> Vec<u32> {
+	let (output, spans) = super::to_romaji_spans(&input);
+	flatten_spans(spans.with_unit(super::SpanUnit::Utf16, &input, &output))
+}
+
+/// Same as `to_romaji_spans` for the hiragana conversion.
+#[wasm_bindgen]
+pub fn to_hiragana_spans(input: String) -> Vec<u32> {
+	let (output, spans) = super::to_hiragana_spans(&input);
+	flatten_spans(spans.with_unit(super::SpanUnit::Utf16, &input, &output))
+}
+
+/// Same as `to_romaji_spans` for the katakana conversion.
+#[wasm_bindgen]
+pub fn to_katakana_spans(input: String) -> Vec<u32> {
+	let (output, spans) = super::to_katakana_spans(&input);
+	flatten_spans(spans.with_unit(super::SpanUnit::Utf16, &input, &output))
+}
+
+fn flatten_spans(spans: super::SpanMap) -> Vec<u32> {
+	let mut out = Vec::with_capacity(spans.spans().len() * 4);
+	for span in spans.spans() {
+		out.push(span.src.start as u32);
+		out.push(span.src.end as u32);
+		out.push(span.out.start as u32);
+		out.push(span.out.end as u32);
+	}
+	out
+}
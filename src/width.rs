@@ -0,0 +1,250 @@
+//! Conversion between character forms, such as full-width and halfwidth.
+
+use super::chunks::*;
+use super::util::*;
+
+/// Converts the input string into halfwidth katakana, as required by some
+/// legacy formats.
+///
+/// The input is converted as in [to_katakana](fn.to_katakana.html), with the
+/// voiced katakana written using the halfwidth voiced marks (e.g. `ガ` to
+/// `ｶﾞ`). Japanese punctuation that has a halfwidth form (e.g. `。` and `「`)
+/// is also converted. Katakana without a halfwidth form (e.g. `ヶ`) are kept
+/// in full-width.
+///
+/// ```
+/// assert_eq!(kana::to_katakana_half_width("こんにちは"), "ｺﾝﾆﾁﾊ");
+/// assert_eq!(kana::to_katakana_half_width("ginkou。"), "ｷﾞﾝｺｳ｡");
+/// ```
+pub fn to_katakana_half_width<S: AsRef<str>>(input: S) -> String {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	for chunk in KatakanaChunks::new(input) {
+		for chr in chunk.text.chars() {
+			if !push_half_width_katakana(&mut out, chr) {
+				out.push(match chr {
+					'。' => '｡',
+					'「' => '｢',
+					'」' => '｣',
+					'、' => '､',
+					'・' => '･',
+					_ => chr,
+				});
+			}
+		}
+	}
+	out
+}
+
+/// Offset between the full-width forms of the ASCII characters (U+FF01 to
+/// U+FF5E) and the ASCII characters.
+const FULL_WIDTH_ASCII_OFFSET: u32 = 0xFEE0;
+
+/// Converts the full-width roman letters, digits and punctuation (e.g. `Ａ`,
+/// `１` and `！`) and the ideographic space to ASCII, keeping any other
+/// characters unchanged.
+///
+/// ```
+/// assert_eq!(kana::to_halfwidth_ascii("ＡＢＣ１２３！　かな"), "ABC123! かな");
+/// ```
+pub fn to_halfwidth_ascii<S: AsRef<str>>(input: S) -> String {
+	input.as_ref().chars().map(halfwidth_ascii_char).collect()
+}
+
+/// Converts a character as in [to_halfwidth_ascii].
+#[inline]
+pub(crate) fn halfwidth_ascii_char(chr: char) -> char {
+	match chr {
+		'\u{FF01}'..='\u{FF5E}' => {
+			std::char::from_u32(chr as u32 - FULL_WIDTH_ASCII_OFFSET).unwrap()
+		}
+		'\u{3000}' => ' ',
+		_ => chr,
+	}
+}
+
+/// Options for [to_fullwidth_ascii_with].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FullWidthOptions {
+	/// Keep URLs (e.g. `https://example.com`) unchanged.
+	pub skip_urls: bool,
+	/// Keep code spans delimited by backticks (e.g. `` `x + 1` ``) unchanged,
+	/// including the backticks.
+	pub skip_code: bool,
+}
+
+/// Converts the ASCII roman letters, digits and punctuation to their
+/// full-width forms, keeping any other characters unchanged.
+///
+/// This is the inverse of [to_halfwidth_ascii], except that spaces are kept
+/// unchanged.
+///
+/// ```
+/// assert_eq!(kana::to_fullwidth_ascii("ABC 123!"), "ＡＢＣ １２３！");
+/// ```
+pub fn to_fullwidth_ascii<S: AsRef<str>>(input: S) -> String {
+	to_fullwidth_ascii_with(input, &FullWidthOptions::default())
+}
+
+/// Same as [to_fullwidth_ascii] but with the given options.
+///
+/// ```
+/// let options = kana::FullWidthOptions {
+///     skip_urls: true,
+///     skip_code: true,
+/// };
+/// assert_eq!(
+///     kana::to_fullwidth_ascii_with("see http://a.jp and `f(x)`", &options),
+///     "ｓｅｅ http://a.jp ａｎｄ `f(x)`"
+/// );
+/// ```
+pub fn to_fullwidth_ascii_with<S: AsRef<str>>(input: S, options: &FullWidthOptions) -> String {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len() * 3);
+	let mut cursor = 0;
+	while let Some(chr) = input[cursor..].chars().next() {
+		let skip = if options.skip_urls {
+			url_len(&input[cursor..])
+		} else {
+			0
+		};
+		let skip = if skip == 0 && options.skip_code && chr == '`' {
+			code_span_len(&input[cursor..])
+		} else {
+			skip
+		};
+		if skip > 0 {
+			out.push_str(&input[cursor..cursor + skip]);
+			cursor += skip;
+			continue;
+		}
+
+		out.push(match chr {
+			'!'..='~' => std::char::from_u32(chr as u32 + FULL_WIDTH_ASCII_OFFSET).unwrap(),
+			_ => chr,
+		});
+		cursor += chr.len_utf8();
+	}
+	out
+}
+
+/// Returns the length of the URL at the start of the text, which extends up
+/// to the first whitespace or non-ASCII character, or zero if the text does
+/// not start with a URL.
+fn url_len(text: &str) -> usize {
+	let prefix = ["http://", "https://", "www."].iter().find(|prefix| {
+		text.len() > prefix.len()
+			&& text.is_char_boundary(prefix.len())
+			&& text[..prefix.len()].eq_ignore_ascii_case(prefix)
+	});
+	if prefix.is_none() {
+		return 0;
+	}
+	text.find(|chr: char| !chr.is_ascii() || chr.is_ascii_whitespace())
+		.unwrap_or(text.len())
+}
+
+/// Returns the length of the code span at the start of the text, including
+/// the backticks, or zero if the text does not start with a closed span.
+fn code_span_len(text: &str) -> usize {
+	match text[1..].find('`') {
+		Some(end) => end + 2,
+		None => 0,
+	}
+}
+
+/// Expands the circled, parenthesized and full stop digits to plain digits,
+/// keeping any other characters unchanged.
+///
+/// ```
+/// assert_eq!(kana::expand_enclosed_digits("①と⑳、⑴と⒈"), "1と20、(1)と1.");
+/// ```
+pub fn expand_enclosed_digits<S: AsRef<str>>(input: S) -> String {
+	let input = input.as_ref();
+	let mut out = String::with_capacity(input.len());
+	for chr in input.chars() {
+		if !push_enclosed_digit(&mut out, chr) {
+			out.push(chr);
+		}
+	}
+	out
+}
+
+// spell-checker: disable
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_to_katakana_half_width() {
+		assert_eq!(to_katakana_half_width(""), "");
+		assert_eq!(to_katakana_half_width("こんにちは"), "ｺﾝﾆﾁﾊ");
+		assert_eq!(to_katakana_half_width("がぱゔ"), "ｶﾞﾊﾟｳﾞ");
+		assert_eq!(to_katakana_half_width("ラーメン・チャーハン"), "ﾗｰﾒﾝ･ﾁｬｰﾊﾝ");
+		assert_eq!(to_katakana_half_width("「ヴァ、ヷ」。"), "｢ｳﾞｧ､ﾜﾞ｣｡");
+		assert_eq!(to_katakana_half_width("一ヶ月 ヵ"), "一ヶ月 ヵ");
+		assert_eq!(to_katakana_half_width("ｶﾞｯｺｳ"), "ｶﾞｯｺｳ");
+	}
+
+	#[test]
+	fn test_to_halfwidth_ascii() {
+		assert_eq!(to_halfwidth_ascii(""), "");
+		assert_eq!(
+			to_halfwidth_ascii("ＡＢＣＸＹＺａｂｃｘｙｚ０１２３４５６７８９"),
+			"ABCXYZabcxyz0123456789"
+		);
+		assert_eq!(
+			to_halfwidth_ascii("！＂＃＄％＆＇（）＊＋，－．／：；＜＝＞？＠［＼］＾＿｀｛｜｝～"),
+			"!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~"
+		);
+		assert_eq!(to_halfwidth_ascii("漢字　カナ、ｶﾅ。"), "漢字 カナ、ｶﾅ。");
+	}
+
+	#[test]
+	fn test_expand_enclosed_digits() {
+		assert_eq!(expand_enclosed_digits(""), "");
+		assert_eq!(expand_enclosed_digits("①②⑨⑩⑪⑳"), "129101120");
+		assert_eq!(expand_enclosed_digits("⑴⑵⒇"), "(1)(2)(20)");
+		assert_eq!(expand_enclosed_digits("⒈⒉⒛"), "1.2.20.");
+		assert_eq!(expand_enclosed_digits("㉑㊿ⅫA"), "㉑㊿ⅫA");
+	}
+
+	#[test]
+	fn test_to_fullwidth_ascii() {
+		assert_eq!(to_fullwidth_ascii(""), "");
+		assert_eq!(
+			to_fullwidth_ascii("ABCXYZabcxyz0123456789"),
+			"ＡＢＣＸＹＺａｂｃｘｙｚ０１２３４５６７８９"
+		);
+		assert_eq!(
+			to_fullwidth_ascii("!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~"),
+			"！＂＃＄％＆＇（）＊＋，－．／：；＜＝＞？＠［＼］＾＿｀｛｜｝～"
+		);
+		assert_eq!(to_fullwidth_ascii("漢字 カナ\tｶﾅ"), "漢字 カナ\tｶﾅ");
+		assert_eq!(
+			to_fullwidth_ascii("https://a.jp `x`"),
+			"ｈｔｔｐｓ：／／ａ．ｊｐ ｀ｘ｀"
+		);
+
+		let options = FullWidthOptions {
+			skip_urls: true,
+			..FullWidthOptions::default()
+		};
+		assert_eq!(
+			to_fullwidth_ascii_with("URL:HTTPS://a.jp/x?y=1です www.a.jp", &options),
+			"ＵＲＬ：HTTPS://a.jp/x?y=1です www.a.jp"
+		);
+		assert_eq!(
+			to_fullwidth_ascii_with("http://", &options),
+			"ｈｔｔｐ：／／"
+		);
+
+		let options = FullWidthOptions {
+			skip_code: true,
+			..FullWidthOptions::default()
+		};
+		assert_eq!(to_fullwidth_ascii_with("a`b`c`d", &options), "ａ`b`ｃ｀ｄ");
+		assert_eq!(to_fullwidth_ascii_with("`漢字 x`", &options), "`漢字 x`");
+	}
+}